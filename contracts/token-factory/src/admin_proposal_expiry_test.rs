@@ -0,0 +1,174 @@
+//! Admin Proposal Expiry Tests
+//!
+//! Tests for expiring and cancellable two-step admin handover offers
+
+use soroban_sdk::{testutils::Address as _, Address};
+
+use crate::test_helpers::{TestEnv, TimeController};
+use crate::types::Error;
+use crate::ADMIN_PROPOSAL_TTL;
+
+#[test]
+fn test_pending_admin_exposes_deadline() {
+    let TestEnv {
+        env, client, admin, ..
+    } = TestEnv::initialized();
+    let new_admin = Address::generate(&env);
+    let now = env.ledger().timestamp();
+
+    client.propose_admin(&admin, &new_admin);
+
+    let offer = client.get_pending_admin().unwrap();
+    assert_eq!(offer.proposed_admin, new_admin);
+    assert_eq!(offer.proposed_by, admin);
+    assert_eq!(offer.proposed_at, now);
+    assert_eq!(offer.expires_at, now + ADMIN_PROPOSAL_TTL);
+}
+
+#[test]
+fn test_accept_at_deadline_succeeds() {
+    let TestEnv {
+        env, client, admin, ..
+    } = TestEnv::initialized();
+    let new_admin = Address::generate(&env);
+
+    client.propose_admin(&admin, &new_admin);
+    TimeController::new(&env).advance(ADMIN_PROPOSAL_TTL);
+
+    client.accept_admin(&new_admin);
+    assert_eq!(client.get_state().admin, new_admin);
+    assert!(client.get_pending_admin().is_none());
+}
+
+#[test]
+fn test_accept_after_expiry_fails() {
+    let TestEnv {
+        env, client, admin, ..
+    } = TestEnv::initialized();
+    let new_admin = Address::generate(&env);
+
+    client.propose_admin(&admin, &new_admin);
+    TimeController::new(&env).advance(ADMIN_PROPOSAL_TTL + 1);
+
+    let result = client.try_accept_admin(&new_admin);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    assert_eq!(client.get_state().admin, admin);
+}
+
+#[test]
+fn test_cancel_admin_proposal() {
+    let TestEnv {
+        env, client, admin, ..
+    } = TestEnv::initialized();
+    let new_admin = Address::generate(&env);
+
+    client.propose_admin(&admin, &new_admin);
+    client.cancel_admin_proposal(&admin);
+
+    assert!(client.get_pending_admin().is_none());
+    assert_eq!(
+        client.try_accept_admin(&new_admin),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_cancel_admin_proposal_requires_admin() {
+    let TestEnv {
+        env, client, admin, ..
+    } = TestEnv::initialized();
+    let new_admin = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    client.propose_admin(&admin, &new_admin);
+
+    assert_eq!(
+        client.try_cancel_admin_proposal(&stranger),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert!(client.get_pending_admin().is_some());
+}
+
+#[test]
+fn test_cancel_without_proposal_fails() {
+    let TestEnv { client, admin, .. } = TestEnv::initialized();
+
+    assert_eq!(
+        client.try_cancel_admin_proposal(&admin),
+        Err(Ok(Error::InvalidParameters))
+    );
+}
+
+#[test]
+fn test_expire_admin_proposal() {
+    let TestEnv {
+        env, client, admin, ..
+    } = TestEnv::initialized();
+    let new_admin = Address::generate(&env);
+
+    client.propose_admin(&admin, &new_admin);
+
+    assert_eq!(
+        client.try_expire_admin_proposal(),
+        Err(Ok(Error::InvalidStateTransition))
+    );
+
+    TimeController::new(&env).advance(ADMIN_PROPOSAL_TTL + 1);
+    client.expire_admin_proposal();
+
+    assert!(client.get_pending_admin().is_none());
+    assert_eq!(
+        client.try_expire_admin_proposal(),
+        Err(Ok(Error::InvalidParameters))
+    );
+}
+
+#[test]
+fn test_offer_from_previous_admin_is_void() {
+    let TestEnv {
+        env, client, admin, ..
+    } = TestEnv::initialized();
+    let proposed = Address::generate(&env);
+    let successor = Address::generate(&env);
+
+    client.propose_admin(&admin, &proposed);
+
+    client.transfer_admin(&admin, &successor);
+
+    assert_eq!(
+        client.try_accept_admin(&proposed),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(client.get_state().admin, successor);
+}
+
+#[test]
+fn test_offer_pending_before_upgrade_decodes_as_expired() {
+    let TestEnv {
+        env, client, admin, ..
+    } = TestEnv::initialized();
+    let proposed = Address::generate(&env);
+    TimeController::new(&env).advance(100);
+
+    // Offers used to be stored as a bare address
+    env.as_contract(&client.address, || {
+        env.storage()
+            .instance()
+            .set(&crate::types::DataKey::PendingAdmin, &proposed);
+    });
+
+    let offer = client.get_pending_admin().unwrap();
+    assert_eq!(offer.proposed_admin, proposed);
+    assert_eq!(offer.proposed_by, admin);
+    assert_eq!(
+        client.try_accept_admin(&proposed),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    client.expire_admin_proposal();
+    assert!(client.get_pending_admin().is_none());
+
+    client.propose_admin(&admin, &proposed);
+    client.accept_admin(&proposed);
+    assert_eq!(client.get_state().admin, proposed);
+}
@@ -0,0 +1,198 @@
+use crate::merkle;
+use crate::storage;
+use crate::types::{AirdropCampaign, Error, PAUSE_TRANSFER};
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+/// Create a Merkle airdrop campaign
+///
+/// Moves `total_amount` from the creator's balance into the campaign
+/// escrow. Airdrops move balances like transfers, so they honour the
+/// transfer pause flag.
+///
+/// # Returns
+/// * `Ok(campaign_id)` - Campaign created
+/// * `Err(Error::ContractPaused)` - Transfers are paused
+/// * `Err(Error::TokenNotFound)` - Token doesn't exist
+/// * `Err(Error::Unauthorized)` - Caller is not the token creator
+/// * `Err(Error::TokenPaused)` - Token is paused
+/// * `Err(Error::InvalidAmount)` - Amount is zero or negative
+/// * `Err(Error::InvalidTimeWindow)` - Expiry is not in the future
+/// * `Err(Error::InsufficientBalance)` - Creator balance is less than amount
+pub fn create_airdrop(
+    env: &Env,
+    creator: &Address,
+    token_index: u32,
+    merkle_root: &BytesN<32>,
+    total_amount: i128,
+    expires_at: u64,
+) -> Result<u32, Error> {
+    creator.require_auth();
+
+    if storage::is_operation_paused(env, PAUSE_TRANSFER) {
+        return Err(Error::ContractPaused);
+    }
+
+    let token_info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+    if token_info.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+
+    if storage::is_token_paused(env, token_index) {
+        return Err(Error::TokenPaused);
+    }
+
+    if total_amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    if expires_at <= env.ledger().timestamp() {
+        return Err(Error::InvalidTimeWindow);
+    }
+
+    let balance = storage::get_balance(env, token_index, creator);
+    if storage::get_transferable_balance(env, token_index, creator) < total_amount {
+        return Err(Error::InsufficientBalance);
+    }
+    storage::set_balance(env, token_index, creator, balance - total_amount);
+    storage::adjust_escrowed_supply(env, token_index, total_amount)?;
+
+    let campaign_id = storage::get_airdrop_count(env);
+    let next_id = campaign_id.checked_add(1).ok_or(Error::ArithmeticError)?;
+
+    storage::set_airdrop(
+        env,
+        campaign_id,
+        &AirdropCampaign {
+            token_index,
+            creator: creator.clone(),
+            merkle_root: merkle_root.clone(),
+            total_amount,
+            claimed_amount: 0,
+            expires_at,
+            reclaimed: false,
+        },
+    );
+    storage::set_airdrop_count(env, next_id);
+
+    crate::events::emit_airdrop_created(
+        env,
+        campaign_id,
+        token_index,
+        merkle_root,
+        total_amount,
+        expires_at,
+    );
+
+    Ok(campaign_id)
+}
+
+/// Claim an airdrop leaf
+///
+/// The claimant must be the address committed in the leaf. Each leaf
+/// index can be claimed once.
+///
+/// # Returns
+/// * `Ok(())` - Amount credited to the claimant
+/// * `Err(Error::ContractPaused)` - Transfers are paused
+/// * `Err(Error::InvalidParameters)` - Campaign doesn't exist
+/// * `Err(Error::TokenPaused)` - Token is paused
+/// * `Err(Error::InvalidTimeWindow)` - Campaign has expired
+/// * `Err(Error::InvalidAmount)` - Amount is zero or negative
+/// * `Err(Error::NothingToClaim)` - Leaf already claimed
+/// * `Err(Error::Unauthorized)` - Proof does not match the root
+/// * `Err(Error::RecipientNotAllowed)` - Claimant fails the token's compliance mode
+/// * `Err(Error::InsufficientBalance)` - Claim exceeds the escrowed amount
+pub fn claim_airdrop(
+    env: &Env,
+    claimant: &Address,
+    campaign_id: u32,
+    index: u32,
+    amount: i128,
+    proof: &Vec<BytesN<32>>,
+) -> Result<(), Error> {
+    claimant.require_auth();
+
+    if storage::is_operation_paused(env, PAUSE_TRANSFER) {
+        return Err(Error::ContractPaused);
+    }
+
+    let mut campaign = storage::get_airdrop(env, campaign_id).ok_or(Error::InvalidParameters)?;
+
+    if storage::is_token_paused(env, campaign.token_index) {
+        return Err(Error::TokenPaused);
+    }
+
+    if env.ledger().timestamp() >= campaign.expires_at {
+        return Err(Error::InvalidTimeWindow);
+    }
+
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    if storage::is_airdrop_claimed(env, campaign_id, index) {
+        return Err(Error::NothingToClaim);
+    }
+
+    let leaf = merkle::leaf_hash(env, index, claimant, amount);
+    if !merkle::verify_proof(env, &campaign.merkle_root, &leaf, proof) {
+        return Err(Error::Unauthorized);
+    }
+
+    crate::compliance::require_eligible(env, campaign.token_index, claimant)?;
+
+    // Guard against roots that commit to more than was escrowed
+    let claimed = campaign
+        .claimed_amount
+        .checked_add(amount)
+        .ok_or(Error::ArithmeticError)?;
+    if claimed > campaign.total_amount {
+        return Err(Error::InsufficientBalance);
+    }
+
+    let balance = storage::get_balance(env, campaign.token_index, claimant);
+    let new_balance = balance.checked_add(amount).ok_or(Error::ArithmeticError)?;
+    storage::set_balance(env, campaign.token_index, claimant, new_balance);
+    storage::adjust_escrowed_supply(env, campaign.token_index, -amount)?;
+
+    campaign.claimed_amount = claimed;
+    storage::set_airdrop(env, campaign_id, &campaign);
+    storage::set_airdrop_claimed(env, campaign_id, index);
+
+    crate::events::emit_airdrop_claimed(env, campaign_id, index, claimant, amount);
+
+    Ok(())
+}
+
+/// Return the unclaimed remainder of an expired campaign to its creator
+///
+/// # Returns
+/// * `Ok(amount)` - Amount returned to the creator
+/// * `Err(Error::InvalidParameters)` - Campaign doesn't exist
+/// * `Err(Error::Unauthorized)` - Caller is not the campaign creator
+/// * `Err(Error::InvalidStateTransition)` - Campaign not expired yet or already reclaimed
+pub fn reclaim_airdrop(env: &Env, creator: &Address, campaign_id: u32) -> Result<i128, Error> {
+    creator.require_auth();
+
+    let mut campaign = storage::get_airdrop(env, campaign_id).ok_or(Error::InvalidParameters)?;
+    if campaign.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+
+    if env.ledger().timestamp() < campaign.expires_at || campaign.reclaimed {
+        return Err(Error::InvalidStateTransition);
+    }
+
+    let remainder = campaign.total_amount - campaign.claimed_amount;
+    let balance = storage::get_balance(env, campaign.token_index, creator);
+    let new_balance = balance.checked_add(remainder).ok_or(Error::ArithmeticError)?;
+    storage::set_balance(env, campaign.token_index, creator, new_balance);
+    storage::adjust_escrowed_supply(env, campaign.token_index, -remainder)?;
+
+    campaign.reclaimed = true;
+    storage::set_airdrop(env, campaign_id, &campaign);
+
+    crate::events::emit_airdrop_reclaimed(env, campaign_id, creator, remainder);
+
+    Ok(remainder)
+}
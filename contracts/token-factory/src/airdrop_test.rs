@@ -0,0 +1,194 @@
+//! Airdrop Tests
+//!
+//! Tests for Merkle-root airdrop campaigns: escrow, claims with proofs,
+//! double-claim protection and reclaiming after expiry
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, BytesN, Env, Vec,
+};
+
+use crate::merkle::MerkleTree;
+use crate::test_helpers::{create_token, TestEnv};
+use crate::types::{Error, PAUSE_TRANSFER};
+use crate::TokenFactoryClient;
+
+const EXPIRY: u64 = 1_000;
+
+struct Setup {
+    env: Env,
+    client: TokenFactoryClient<'static>,
+    admin: Address,
+    creator: Address,
+    recipients: Vec<(Address, i128)>,
+    tree: MerkleTree,
+}
+
+fn setup() -> Setup {
+    let TestEnv {
+        env, client, admin, ..
+    } = TestEnv::initialized();
+
+    let creator = Address::generate(&env);
+    create_token(&client, &creator, "DROP", 1_000_000);
+
+    let recipients = vec![
+        &env,
+        (Address::generate(&env), 100i128),
+        (Address::generate(&env), 250i128),
+        (Address::generate(&env), 400i128),
+    ];
+    let tree = MerkleTree::build(&env, &recipients).unwrap();
+    client.create_airdrop(&creator, &0, &tree.root(), &750, &EXPIRY);
+
+    Setup {
+        env,
+        client,
+        admin,
+        creator,
+        recipients,
+        tree,
+    }
+}
+
+#[test]
+fn test_create_escrows_amount() {
+    let s = setup();
+
+    let campaign = s.client.get_airdrop(&0).unwrap();
+    assert_eq!(campaign.total_amount, 750);
+    assert_eq!(campaign.merkle_root, s.tree.root());
+    assert_eq!(s.client.get_balance(&0, &s.creator), 1_000_000 - 750);
+}
+
+#[test]
+fn test_claim_with_valid_proof() {
+    let s = setup();
+    let (bob, amount) = s.recipients.get(1).unwrap();
+
+    s.client
+        .claim_airdrop(&bob, &0, &1, &amount, &s.tree.proof(&s.env, 1));
+
+    assert_eq!(s.client.get_balance(&0, &bob), 250);
+    assert!(s.client.is_airdrop_claimed(&0, &1));
+    assert!(!s.client.is_airdrop_claimed(&0, &0));
+    assert_eq!(s.client.get_airdrop(&0).unwrap().claimed_amount, 250);
+}
+
+#[test]
+fn test_double_claim_rejected() {
+    let s = setup();
+    let (alice, amount) = s.recipients.get(0).unwrap();
+    let proof = s.tree.proof(&s.env, 0);
+
+    s.client.claim_airdrop(&alice, &0, &0, &amount, &proof);
+    assert_eq!(
+        s.client.try_claim_airdrop(&alice, &0, &0, &amount, &proof),
+        Err(Ok(Error::NothingToClaim))
+    );
+}
+
+#[test]
+fn test_invalid_proof_rejected() {
+    let s = setup();
+    let (alice, amount) = s.recipients.get(0).unwrap();
+    let proof = s.tree.proof(&s.env, 0);
+
+    // Inflated amount
+    assert_eq!(
+        s.client
+            .try_claim_airdrop(&alice, &0, &0, &(amount + 1), &proof),
+        Err(Ok(Error::Unauthorized))
+    );
+    // Someone else presenting alice's leaf
+    assert_eq!(
+        s.client
+            .try_claim_airdrop(&Address::generate(&s.env), &0, &0, &amount, &proof),
+        Err(Ok(Error::Unauthorized))
+    );
+    // Garbage proof
+    let garbage = vec![&s.env, BytesN::from_array(&s.env, &[9u8; 32])];
+    assert_eq!(
+        s.client
+            .try_claim_airdrop(&alice, &0, &0, &amount, &garbage),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_claim_after_expiry_rejected() {
+    let s = setup();
+    let (alice, amount) = s.recipients.get(0).unwrap();
+
+    s.env.ledger().with_mut(|li| li.timestamp = EXPIRY);
+    assert_eq!(
+        s.client
+            .try_claim_airdrop(&alice, &0, &0, &amount, &s.tree.proof(&s.env, 0)),
+        Err(Ok(Error::InvalidTimeWindow))
+    );
+}
+
+#[test]
+fn test_reclaim_remainder_after_expiry() {
+    let s = setup();
+    let (carol, amount) = s.recipients.get(2).unwrap();
+    s.client
+        .claim_airdrop(&carol, &0, &2, &amount, &s.tree.proof(&s.env, 2));
+
+    assert_eq!(
+        s.client.try_reclaim_airdrop(&s.creator, &0),
+        Err(Ok(Error::InvalidStateTransition))
+    );
+
+    s.env.ledger().with_mut(|li| li.timestamp = EXPIRY);
+    assert_eq!(
+        s.client.try_reclaim_airdrop(&Address::generate(&s.env), &0),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(s.client.reclaim_airdrop(&s.creator, &0), 350);
+    assert_eq!(s.client.get_balance(&0, &s.creator), 1_000_000 - 400);
+
+    assert_eq!(
+        s.client.try_reclaim_airdrop(&s.creator, &0),
+        Err(Ok(Error::InvalidStateTransition))
+    );
+}
+
+#[test]
+fn test_create_validation() {
+    let s = setup();
+    let root = s.tree.root();
+
+    assert_eq!(
+        s.client
+            .try_create_airdrop(&Address::generate(&s.env), &0, &root, &10, &EXPIRY),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.client
+            .try_create_airdrop(&s.creator, &0, &root, &0, &EXPIRY),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        s.client.try_create_airdrop(&s.creator, &0, &root, &10, &0),
+        Err(Ok(Error::InvalidTimeWindow))
+    );
+    assert_eq!(
+        s.client
+            .try_create_airdrop(&s.creator, &0, &root, &10_000_000, &EXPIRY),
+        Err(Ok(Error::InsufficientBalance))
+    );
+}
+
+#[test]
+fn test_claims_respect_transfer_pause() {
+    let s = setup();
+    let (alice, amount) = s.recipients.get(0).unwrap();
+
+    s.client.set_pause_flags(&s.admin, &PAUSE_TRANSFER);
+    assert_eq!(
+        s.client
+            .try_claim_airdrop(&alice, &0, &0, &amount, &s.tree.proof(&s.env, 0)),
+        Err(Ok(Error::ContractPaused))
+    );
+}
@@ -1,5 +1,6 @@
+use crate::clawback;
 use crate::storage;
-use crate::types::Error;
+use crate::types::{Error, PAUSE_BURN};
 use soroban_sdk::{symbol_short, Address, Env};
 
 const MAX_BATCH_BURN: u32 = 100;
@@ -10,13 +11,16 @@ pub fn burn(env: &Env, caller: Address, token_index: u32, amount: i128) -> Resul
 
     let mut info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
 
-    // Token-level pause check
+    // Operation-level and token-level pause checks
+    if storage::is_operation_paused(env, PAUSE_BURN) {
+        return Err(Error::ContractPaused);
+    }
     if storage::is_token_paused(env, token_index) {
         return Err(Error::TokenPaused);
     }
 
     let balance = storage::get_balance(env, token_index, &caller);
-    if balance < amount {
+    if storage::get_transferable_balance(env, token_index, &caller) < amount {
         return Err(Error::InsufficientBalance);
     }
 
@@ -52,6 +56,7 @@ pub fn admin_burn(
     token_index: u32,
     holder: Address,
     amount: i128,
+    reason_code: u32,
 ) -> Result<(), Error> {
     admin.require_auth();
 
@@ -64,14 +69,18 @@ pub fn admin_burn(
     validate_address(&holder)?;
 
     let mut info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+    clawback::require_active(env, token_index, reason_code)?;
 
-    // Token-level pause check
+    // Operation-level and token-level pause checks
+    if storage::is_operation_paused(env, PAUSE_BURN) {
+        return Err(Error::ContractPaused);
+    }
     if storage::is_token_paused(env, token_index) {
         return Err(Error::TokenPaused);
     }
 
     let balance = storage::get_balance(env, token_index, &holder);
-    if balance < amount {
+    if storage::get_transferable_balance(env, token_index, &holder) < amount {
         return Err(Error::InsufficientBalance);
     }
 
@@ -97,6 +106,7 @@ pub fn admin_burn(
     storage::increment_burn_count(env, token_index);
     storage::add_total_burned(env, token_index, amount);
 
+    clawback::record(env, token_index, &holder, amount, &admin, reason_code)?;
     emit_admin_burn_event(env, token_index, &admin, &holder, amount, new_supply);
     Ok(())
 }
@@ -106,6 +116,7 @@ pub fn batch_burn(
     admin: Address,
     token_index: u32,
     burns: soroban_sdk::Vec<(Address, i128)>,
+    reason_code: u32,
 ) -> Result<(), Error> {
     admin.require_auth();
 
@@ -122,8 +133,12 @@ pub fn batch_burn(
     }
 
     let mut info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+    clawback::require_active(env, token_index, reason_code)?;
 
-    // Token-level pause check
+    // Operation-level and token-level pause checks
+    if storage::is_operation_paused(env, PAUSE_BURN) {
+        return Err(Error::ContractPaused);
+    }
     if storage::is_token_paused(env, token_index) {
         return Err(Error::TokenPaused);
     }
@@ -135,8 +150,7 @@ pub fn batch_burn(
         validate_amount(amount)?;
         validate_address(holder)?;
 
-        let balance = storage::get_balance(env, token_index, holder);
-        if balance < amount {
+        if storage::get_transferable_balance(env, token_index, holder) < amount {
             return Err(Error::InsufficientBalance);
         }
         total_burn = total_burn
@@ -154,6 +168,7 @@ pub fn batch_burn(
         let balance = storage::get_balance(env, token_index, holder);
         let new_balance = balance.checked_sub(amount).ok_or(Error::ArithmeticError)?;
         storage::set_balance(env, token_index, holder, new_balance);
+        clawback::record(env, token_index, holder, amount, &admin, reason_code)?;
     }
 
     let new_supply = info
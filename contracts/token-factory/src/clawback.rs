@@ -0,0 +1,171 @@
+//! Clawback Controls and Audit Log
+//!
+//! A token creator opts into clawback with `set_clawback`. When the admin
+//! has configured a notice period, enabling clawback only announces it:
+//! admin burns from holders are rejected until the notice has elapsed,
+//! giving holders time to react. Disabling takes effect immediately.
+//!
+//! Tokens that enabled clawback before the notice period existed have no
+//! activation time; their stored `clawback_enabled` flag keeps clawback
+//! active. Token views report `clawback_enabled` as `is_active`.
+//!
+//! Every clawback is appended to a per-token log (holder, amount, actor,
+//! reason code, timestamp) that can be read back page by page.
+
+use crate::events;
+use crate::storage;
+use crate::types::{ClawbackRecord, Error, PAUSE_TOKEN_CONTROLS};
+use soroban_sdk::{Address, Env};
+
+/// Maximum clawback notice period in seconds (30 days)
+pub const MAX_CLAWBACK_NOTICE: u64 = 2_592_000;
+
+/// Enable or disable clawback for a token (token creator only)
+///
+/// Enabling while clawback is already enabled or announced keeps the
+/// original activation time, so re-announcing cannot shorten the notice.
+///
+/// # Returns
+/// * `Ok(())` - Setting updated
+/// * `Err(Error::ContractPaused)` - Token controls are paused
+/// * `Err(Error::TokenNotFound)` - Token address not found
+/// * `Err(Error::Unauthorized)` - Caller is not the token creator
+pub fn set_clawback(
+    env: &Env,
+    token_address: &Address,
+    admin: &Address,
+    enabled: bool,
+) -> Result<(), Error> {
+    if storage::is_operation_paused(env, PAUSE_TOKEN_CONTROLS) {
+        return Err(Error::ContractPaused);
+    }
+
+    admin.require_auth();
+
+    let mut token_info =
+        storage::get_token_info_by_address(env, token_address).ok_or(Error::TokenNotFound)?;
+    if token_info.creator != *admin {
+        return Err(Error::Unauthorized);
+    }
+    let token_index =
+        storage::get_token_index_by_address(env, token_address).ok_or(Error::TokenNotFound)?;
+
+    if enabled {
+        if storage::get_clawback_active_from(env, token_index).is_none() {
+            if token_info.clawback_enabled {
+                // Enabled before the notice period existed and already active
+                storage::set_clawback_active_from(env, token_index, 0);
+            } else {
+                let active_from = env
+                    .ledger()
+                    .timestamp()
+                    .checked_add(storage::get_clawback_notice_period(env))
+                    .ok_or(Error::ArithmeticError)?;
+                storage::set_clawback_active_from(env, token_index, active_from);
+                events::emit_clawback_announced(env, token_index, active_from);
+            }
+        }
+    } else {
+        storage::remove_clawback_active_from(env, token_index);
+    }
+
+    token_info.clawback_enabled = enabled;
+    storage::set_token_info_by_address(env, token_address, &token_info);
+
+    events::emit_clawback_toggled(env, token_address, admin, enabled);
+    Ok(())
+}
+
+/// Set the notice period applied to future clawback enablements (admin only)
+///
+/// # Returns
+/// * `Ok(())` - Notice period updated
+/// * `Err(Error::Unauthorized)` - Caller is not the admin
+/// * `Err(Error::InvalidParameters)` - Notice period exceeds 30 days
+pub fn set_notice_period(env: &Env, admin: &Address, seconds: u64) -> Result<(), Error> {
+    admin.require_auth();
+
+    if *admin != storage::get_admin(env) {
+        return Err(Error::Unauthorized);
+    }
+
+    if seconds > MAX_CLAWBACK_NOTICE {
+        return Err(Error::InvalidParameters);
+    }
+
+    storage::set_clawback_notice_period(env, seconds);
+    events::emit_clawback_notice_updated(env, seconds);
+    Ok(())
+}
+
+/// Whether clawback is enabled and past its notice period
+///
+/// Without an activation time, falls back to the token's stored flag.
+pub fn is_active(env: &Env, token_index: u32) -> bool {
+    match storage::get_clawback_active_from(env, token_index) {
+        Some(active_from) => env.ledger().timestamp() >= active_from,
+        None => storage::get_token_info(env, token_index)
+            .and_then(|info| storage::get_token_info_by_address(env, &info.address))
+            .is_some_and(|info| info.clawback_enabled),
+    }
+}
+
+/// Fail with `ClawbackDisabled` unless clawback is active, and reject the
+/// reserved reason code 0
+pub fn require_active(env: &Env, token_index: u32, reason_code: u32) -> Result<(), Error> {
+    if reason_code == 0 {
+        return Err(Error::InvalidParameters);
+    }
+    if !is_active(env, token_index) {
+        return Err(Error::ClawbackDisabled);
+    }
+    Ok(())
+}
+
+/// Append a clawback to the token's audit log
+pub fn record(
+    env: &Env,
+    token_index: u32,
+    holder: &Address,
+    amount: i128,
+    actor: &Address,
+    reason_code: u32,
+) -> Result<(), Error> {
+    let record = ClawbackRecord {
+        holder: holder.clone(),
+        amount,
+        actor: actor.clone(),
+        reason_code,
+        timestamp: env.ledger().timestamp(),
+    };
+    let position = storage::append_clawback_record(env, token_index, &record)?;
+
+    events::emit_clawback_recorded(env, token_index, position, holder, amount, reason_code);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Ledger;
+
+    #[test]
+    fn test_active_only_after_notice() {
+        let env = Env::default();
+        let contract_id = env.register(crate::TokenFactory, ());
+
+        env.as_contract(&contract_id, || {
+            assert!(!is_active(&env, 0));
+            assert_eq!(require_active(&env, 0, 1), Err(Error::ClawbackDisabled));
+
+            storage::set_clawback_active_from(&env, 0, 100);
+            env.ledger().with_mut(|li| li.timestamp = 99);
+            assert!(!is_active(&env, 0));
+
+            env.ledger().with_mut(|li| li.timestamp = 100);
+            assert!(is_active(&env, 0));
+            assert_eq!(require_active(&env, 0, 1), Ok(()));
+            assert_eq!(require_active(&env, 0, 0), Err(Error::InvalidParameters));
+        });
+    }
+}
@@ -0,0 +1,198 @@
+//! Clawback Tests
+//!
+//! Tests for the clawback notice period, reason codes and the per-token
+//! clawback audit log
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Env,
+};
+
+use crate::storage;
+use crate::test_helpers::{create_token, TestEnv};
+use crate::types::Error;
+use crate::TokenFactoryClient;
+
+const COURT_ORDER: u32 = 7;
+
+struct Setup {
+    env: Env,
+    client: TokenFactoryClient<'static>,
+    admin: Address,
+    creator: Address,
+    token_address: Address,
+    alice: Address,
+    bob: Address,
+}
+
+/// Token 0 with alice and bob holding 1_000 each
+fn setup() -> Setup {
+    let TestEnv {
+        env, client, admin, ..
+    } = TestEnv::initialized();
+
+    let creator = Address::generate(&env);
+    create_token(&client, &creator, "REG", 1_000_000);
+    let token_address = client.get_token_info(&0).address;
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.transfer(&creator, &0, &alice, &1_000);
+    client.transfer(&creator, &0, &bob, &1_000);
+
+    Setup {
+        env,
+        client,
+        admin,
+        creator,
+        token_address,
+        alice,
+        bob,
+    }
+}
+
+#[test]
+fn test_clawback_requires_enablement() {
+    let s = setup();
+
+    assert!(!s.client.is_clawback_active(&0));
+    assert_eq!(
+        s.client
+            .try_admin_burn(&s.admin, &0, &s.alice, &100, &COURT_ORDER),
+        Err(Ok(Error::ClawbackDisabled))
+    );
+
+    // Without a notice period enabling is immediate
+    s.client.set_clawback(&s.token_address, &s.creator, &true);
+    assert!(s.client.is_clawback_active(&0));
+    s.client
+        .admin_burn(&s.admin, &0, &s.alice, &100, &COURT_ORDER);
+    assert_eq!(s.client.get_balance(&0, &s.alice), 900);
+
+    // Disabling is immediate
+    s.client.set_clawback(&s.token_address, &s.creator, &false);
+    assert_eq!(s.client.get_clawback_active_from(&0), None);
+    assert_eq!(
+        s.client
+            .try_admin_burn(&s.admin, &0, &s.alice, &100, &COURT_ORDER),
+        Err(Ok(Error::ClawbackDisabled))
+    );
+}
+
+#[test]
+fn test_notice_period_delays_activation() {
+    let s = setup();
+    s.env.ledger().with_mut(|li| li.timestamp = 1_000);
+    s.client.set_clawback_notice_period(&s.admin, &600);
+
+    s.client.set_clawback(&s.token_address, &s.creator, &true);
+    assert_eq!(s.client.get_clawback_active_from(&0), Some(1_600));
+    assert!(
+        !s.client
+            .get_token_info_by_address(&s.token_address)
+            .clawback_enabled
+    );
+
+    s.env.ledger().with_mut(|li| li.timestamp = 1_599);
+    assert_eq!(
+        s.client
+            .try_admin_burn(&s.admin, &0, &s.alice, &100, &COURT_ORDER),
+        Err(Ok(Error::ClawbackDisabled))
+    );
+    assert!(!s.client.get_token_stats(&0).clawback_enabled);
+
+    // Re-announcing does not restart or shorten the notice
+    s.client.set_clawback(&s.token_address, &s.creator, &true);
+    assert_eq!(s.client.get_clawback_active_from(&0), Some(1_600));
+
+    s.env.ledger().with_mut(|li| li.timestamp = 1_600);
+    s.client
+        .admin_burn(&s.admin, &0, &s.alice, &100, &COURT_ORDER);
+    assert!(s.client.get_token_stats(&0).clawback_enabled);
+    assert!(s.client.get_token_info(&0).clawback_enabled);
+}
+
+#[test]
+fn test_clawback_enabled_before_upgrade_stays_active() {
+    let s = setup();
+    s.client.set_clawback_notice_period(&s.admin, &600);
+
+    // Enabled by an older version: flag set, no activation time
+    s.env.as_contract(&s.client.address, || {
+        let mut info = storage::get_token_info_by_address(&s.env, &s.token_address).unwrap();
+        info.clawback_enabled = true;
+        storage::set_token_info_by_address(&s.env, &s.token_address, &info);
+    });
+    assert_eq!(s.client.get_clawback_active_from(&0), None);
+    assert!(s.client.is_clawback_active(&0));
+    s.client
+        .admin_burn(&s.admin, &0, &s.alice, &100, &COURT_ORDER);
+
+    // Re-enabling keeps it active instead of starting a notice period
+    s.client.set_clawback(&s.token_address, &s.creator, &true);
+    assert_eq!(s.client.get_clawback_active_from(&0), Some(0));
+    assert!(s.client.is_clawback_active(&0));
+
+    s.client.set_clawback(&s.token_address, &s.creator, &false);
+    assert!(!s.client.is_clawback_active(&0));
+}
+
+#[test]
+fn test_clawbacks_are_logged_with_reason() {
+    let s = setup();
+    s.client.set_clawback(&s.token_address, &s.creator, &true);
+    s.env.ledger().with_mut(|li| li.timestamp = 42);
+
+    assert_eq!(
+        s.client.try_admin_burn(&s.admin, &0, &s.alice, &100, &0),
+        Err(Ok(Error::InvalidParameters))
+    );
+
+    s.client
+        .admin_burn(&s.admin, &0, &s.alice, &100, &COURT_ORDER);
+    s.client.batch_burn(
+        &s.admin,
+        &0,
+        &vec![&s.env, (s.alice.clone(), 50), (s.bob.clone(), 25)],
+        &3,
+    );
+
+    let first = s.client.get_clawback_log(&0, &0, &2);
+    assert_eq!(first.records.len(), 2);
+    assert_eq!(first.next_cursor, Some(2));
+
+    let record = first.records.get(0).unwrap();
+    assert_eq!(record.holder, s.alice);
+    assert_eq!(record.amount, 100);
+    assert_eq!(record.actor, s.admin);
+    assert_eq!(record.reason_code, COURT_ORDER);
+    assert_eq!(record.timestamp, 42);
+    assert_eq!(first.records.get(1).unwrap().reason_code, 3);
+
+    let second = s.client.get_clawback_log(&0, &2, &2);
+    assert_eq!(second.records.len(), 1);
+    assert_eq!(second.records.get(0).unwrap().holder, s.bob);
+    assert_eq!(second.next_cursor, None);
+}
+
+#[test]
+fn test_notice_period_validation() {
+    let s = setup();
+
+    assert_eq!(
+        s.client.try_set_clawback_notice_period(&s.creator, &600),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.client
+            .try_set_clawback_notice_period(&s.admin, &2_592_001),
+        Err(Ok(Error::InvalidParameters))
+    );
+    s.client.set_clawback_notice_period(&s.admin, &2_592_000);
+    assert_eq!(s.client.get_clawback_notice_period(), 2_592_000);
+
+    assert_eq!(
+        s.client.try_set_clawback(&s.token_address, &s.alice, &true),
+        Err(Ok(Error::Unauthorized))
+    );
+}
@@ -0,0 +1,142 @@
+use crate::storage;
+use crate::types::{ComplianceMode, Error, HolderList};
+use soroban_sdk::{Address, Env, Vec};
+
+const MAX_HOLDER_BATCH: u32 = 100;
+
+/// Set the compliance mode of a token
+///
+/// Only the token creator can change the mode. Existing holder list
+/// entries are kept so switching back restores the previous policy.
+///
+/// # Returns
+/// * `Ok(())` - Mode updated
+/// * `Err(Error::TokenNotFound)` - Token doesn't exist
+/// * `Err(Error::Unauthorized)` - Caller is not the token creator
+pub fn set_compliance_mode(
+    env: &Env,
+    creator: &Address,
+    token_index: u32,
+    mode: ComplianceMode,
+) -> Result<(), Error> {
+    require_creator(env, creator, token_index)?;
+
+    storage::set_compliance_mode(env, token_index, mode);
+
+    crate::events::emit_compliance_mode_updated(env, token_index, mode);
+
+    Ok(())
+}
+
+/// Add holders to the token's allowlist or denylist
+///
+/// A holder already on the other list is moved.
+///
+/// # Returns
+/// * `Ok(())` - Holders listed
+/// * `Err(Error::TokenNotFound)` - Token doesn't exist
+/// * `Err(Error::Unauthorized)` - Caller is not the token creator
+/// * `Err(Error::InvalidParameters)` - Batch is empty
+/// * `Err(Error::BatchTooLarge)` - More than 100 holders
+pub fn add_holders(
+    env: &Env,
+    creator: &Address,
+    token_index: u32,
+    list: HolderList,
+    holders: &Vec<Address>,
+) -> Result<(), Error> {
+    require_creator(env, creator, token_index)?;
+    validate_batch(holders)?;
+
+    for holder in holders.iter() {
+        storage::set_holder_list_entry(env, token_index, &holder, list);
+    }
+
+    crate::events::emit_holders_listed(env, token_index, list, holders.len());
+
+    Ok(())
+}
+
+/// Remove holders from the token's allowlist or denylist
+///
+/// Holders that are not on `list` are skipped.
+///
+/// # Returns
+/// * `Ok(())` - Holders unlisted
+/// * `Err(Error::TokenNotFound)` - Token doesn't exist
+/// * `Err(Error::Unauthorized)` - Caller is not the token creator
+/// * `Err(Error::InvalidParameters)` - Batch is empty
+/// * `Err(Error::BatchTooLarge)` - More than 100 holders
+pub fn remove_holders(
+    env: &Env,
+    creator: &Address,
+    token_index: u32,
+    list: HolderList,
+    holders: &Vec<Address>,
+) -> Result<(), Error> {
+    require_creator(env, creator, token_index)?;
+    validate_batch(holders)?;
+
+    for holder in holders.iter() {
+        if storage::get_holder_list_entry(env, token_index, &holder) == Some(list) {
+            storage::remove_holder_list_entry(env, token_index, &holder);
+        }
+    }
+
+    crate::events::emit_holders_unlisted(env, token_index, list, holders.len());
+
+    Ok(())
+}
+
+/// Check whether a holder may receive the token
+///
+/// The token creator is always eligible.
+pub fn is_eligible(env: &Env, token_index: u32, holder: &Address) -> bool {
+    let entry = storage::get_holder_list_entry(env, token_index, holder);
+    match storage::get_compliance_mode(env, token_index) {
+        ComplianceMode::Open => true,
+        ComplianceMode::AllowlistOnly => {
+            entry == Some(HolderList::Allowlist) || is_creator(env, token_index, holder)
+        }
+        ComplianceMode::DenylistOnly => {
+            entry != Some(HolderList::Denylist) || is_creator(env, token_index, holder)
+        }
+    }
+}
+
+/// Fail with `RecipientNotAllowed` if the holder may not receive the token
+///
+/// Called from every path that credits a balance.
+pub fn require_eligible(env: &Env, token_index: u32, holder: &Address) -> Result<(), Error> {
+    if !is_eligible(env, token_index, holder) {
+        return Err(Error::RecipientNotAllowed);
+    }
+    Ok(())
+}
+
+fn is_creator(env: &Env, token_index: u32, holder: &Address) -> bool {
+    storage::get_token_info(env, token_index)
+        .map(|info| info.creator == *holder)
+        .unwrap_or(false)
+}
+
+fn require_creator(env: &Env, creator: &Address, token_index: u32) -> Result<(), Error> {
+    creator.require_auth();
+
+    let token_info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+    if token_info.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+
+    Ok(())
+}
+
+fn validate_batch(holders: &Vec<Address>) -> Result<(), Error> {
+    if holders.is_empty() {
+        return Err(Error::InvalidParameters);
+    }
+    if holders.len() > MAX_HOLDER_BATCH {
+        return Err(Error::BatchTooLarge);
+    }
+    Ok(())
+}
@@ -0,0 +1,174 @@
+//! Compliance Mode Tests
+//!
+//! Tests for per-token allowlist / denylist holder eligibility
+
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, Vec};
+
+use crate::test_helpers::{create_tokens, token_params, TestEnv};
+use crate::types::{ComplianceMode, Error, HolderList, TokenCreationParams};
+use crate::TokenFactoryClient;
+
+fn setup(mode: ComplianceMode) -> (Env, TokenFactoryClient<'static>, Address) {
+    let TestEnv { env, client, .. } = TestEnv::initialized();
+
+    let creator = Address::generate(&env);
+    let params = TokenCreationParams {
+        compliance_mode: mode,
+        ..token_params(&env, "SEC", 1_000)
+    };
+    create_tokens(&client, &creator, vec![&env, params]);
+
+    (env, client, creator)
+}
+
+#[test]
+fn test_mode_chosen_at_creation() {
+    let (_env, client, _creator) = setup(ComplianceMode::AllowlistOnly);
+
+    assert_eq!(
+        client.get_compliance_mode(&0),
+        ComplianceMode::AllowlistOnly
+    );
+}
+
+#[test]
+fn test_open_mode_allows_everyone() {
+    let (env, client, creator) = setup(ComplianceMode::Open);
+    let holder = Address::generate(&env);
+
+    assert!(client.is_holder_eligible(&0, &holder));
+    client.mint(&creator, &0, &holder, &100);
+}
+
+#[test]
+fn test_allowlist_blocks_unlisted_mint() {
+    let (env, client, creator) = setup(ComplianceMode::AllowlistOnly);
+    let holder = Address::generate(&env);
+
+    assert_eq!(
+        client.try_mint(&creator, &0, &holder, &100),
+        Err(Ok(Error::RecipientNotAllowed))
+    );
+
+    client.add_holders(
+        &creator,
+        &0,
+        &HolderList::Allowlist,
+        &vec![&env, holder.clone()],
+    );
+    client.mint(&creator, &0, &holder, &100);
+    assert_eq!(client.get_token_info(&0).total_supply, 1_100);
+}
+
+#[test]
+fn test_denylist_blocks_listed_mint() {
+    let (env, client, creator) = setup(ComplianceMode::DenylistOnly);
+    let holder = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    client.add_holders(
+        &creator,
+        &0,
+        &HolderList::Denylist,
+        &vec![&env, holder.clone()],
+    );
+
+    assert_eq!(
+        client.try_mint(&creator, &0, &holder, &100),
+        Err(Ok(Error::RecipientNotAllowed))
+    );
+    client.mint(&creator, &0, &other, &100);
+
+    client.remove_holders(
+        &creator,
+        &0,
+        &HolderList::Denylist,
+        &vec![&env, holder.clone()],
+    );
+    client.mint(&creator, &0, &holder, &100);
+}
+
+#[test]
+fn test_creator_is_always_eligible() {
+    let (_env, client, creator) = setup(ComplianceMode::AllowlistOnly);
+
+    assert!(client.is_holder_eligible(&0, &creator));
+    client.mint(&creator, &0, &creator, &100);
+}
+
+#[test]
+fn test_switching_mode_keeps_lists() {
+    let (env, client, creator) = setup(ComplianceMode::Open);
+    let holder = Address::generate(&env);
+
+    client.add_holders(
+        &creator,
+        &0,
+        &HolderList::Allowlist,
+        &vec![&env, holder.clone()],
+    );
+    client.set_compliance_mode(&creator, &0, &ComplianceMode::AllowlistOnly);
+
+    assert!(client.is_holder_eligible(&0, &holder));
+    assert!(!client.is_holder_eligible(&0, &Address::generate(&env)));
+}
+
+#[test]
+fn test_adding_to_denylist_replaces_allowlist_entry() {
+    let (env, client, creator) = setup(ComplianceMode::AllowlistOnly);
+    let holder = Address::generate(&env);
+
+    client.add_holders(
+        &creator,
+        &0,
+        &HolderList::Allowlist,
+        &vec![&env, holder.clone()],
+    );
+    client.add_holders(
+        &creator,
+        &0,
+        &HolderList::Denylist,
+        &vec![&env, holder.clone()],
+    );
+
+    assert!(!client.is_holder_eligible(&0, &holder));
+}
+
+#[test]
+fn test_only_creator_manages_compliance() {
+    let (env, client, _creator) = setup(ComplianceMode::Open);
+    let stranger = Address::generate(&env);
+
+    assert_eq!(
+        client.try_set_compliance_mode(&stranger, &0, &ComplianceMode::DenylistOnly),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_add_holders(
+            &stranger,
+            &0,
+            &HolderList::Allowlist,
+            &vec![&env, stranger.clone()]
+        ),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_holder_batch_limits() {
+    let (env, client, creator) = setup(ComplianceMode::Open);
+
+    assert_eq!(
+        client.try_add_holders(&creator, &0, &HolderList::Allowlist, &Vec::new(&env)),
+        Err(Ok(Error::InvalidParameters))
+    );
+
+    let mut holders = Vec::new(&env);
+    for _ in 0..101 {
+        holders.push_back(Address::generate(&env));
+    }
+    assert_eq!(
+        client.try_add_holders(&creator, &0, &HolderList::Allowlist, &holders),
+        Err(Ok(Error::BatchTooLarge))
+    );
+}
@@ -0,0 +1,241 @@
+use crate::storage;
+use crate::types::{Distribution, Error, PAUSE_TRANSFER};
+use soroban_sdk::{token, Address, Env};
+
+/// Deposit a SEP-41 asset for pro-rata distribution to a token's holders
+///
+/// Takes a fresh balance snapshot of the token and pulls `amount` of
+/// `asset` from the creator into the factory. Shares are measured against
+/// the circulating supply, leaving out tokens held in contract escrow.
+///
+/// # Returns
+/// * `Ok(distribution_id)` - Distribution created
+/// * `Err(Error::ContractPaused)` - Transfers are paused
+/// * `Err(Error::TokenNotFound)` - Token doesn't exist
+/// * `Err(Error::Unauthorized)` - Caller is not the token creator
+/// * `Err(Error::InvalidAmount)` - Amount is zero or negative
+/// * `Err(Error::InvalidTimeWindow)` - Deadline is not in the future
+/// * `Err(Error::InvalidParameters)` - Token has no circulating supply to distribute against
+pub fn deposit(
+    env: &Env,
+    creator: &Address,
+    token_index: u32,
+    asset: &Address,
+    amount: i128,
+    claim_deadline: u64,
+) -> Result<u32, Error> {
+    creator.require_auth();
+
+    if storage::is_operation_paused(env, PAUSE_TRANSFER) {
+        return Err(Error::ContractPaused);
+    }
+
+    let token_info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+    if token_info.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    if claim_deadline <= env.ledger().timestamp() {
+        return Err(Error::InvalidTimeWindow);
+    }
+
+    let circulating = token_info
+        .total_supply
+        .checked_sub(storage::get_escrowed_supply(env, token_index))
+        .ok_or(Error::ArithmeticError)?;
+    if circulating <= 0 {
+        return Err(Error::InvalidParameters);
+    }
+
+    let snapshot_id = crate::snapshot::take_snapshot(env, token_index)?;
+
+    token::Client::new(env, asset).transfer(creator, env.current_contract_address(), &amount);
+
+    let distribution_id = storage::get_distribution_count(env);
+    let next_id = distribution_id
+        .checked_add(1)
+        .ok_or(Error::ArithmeticError)?;
+
+    storage::set_distribution(
+        env,
+        distribution_id,
+        &Distribution {
+            token_index,
+            creator: creator.clone(),
+            asset: asset.clone(),
+            amount,
+            snapshot_id,
+            supply_at: circulating,
+            claimed_amount: 0,
+            claim_deadline,
+            swept: false,
+        },
+    );
+    storage::set_distribution_count(env, next_id);
+
+    crate::events::emit_distribution_deposited(
+        env,
+        distribution_id,
+        token_index,
+        asset,
+        amount,
+        snapshot_id,
+    );
+
+    Ok(distribution_id)
+}
+
+/// Compute a holder's share of a distribution
+///
+/// `share = amount * balance_at / supply_at`, rounded down so the sum of
+/// all shares never exceeds the deposit.
+///
+/// # Returns
+/// * `Ok(share)` - Amount the holder can claim
+/// * `Err(Error::ArithmeticError)` - Overflow in calculation
+pub fn compute_share(amount: i128, balance_at: i128, supply_at: i128) -> Result<i128, Error> {
+    if balance_at <= 0 || supply_at <= 0 {
+        return Ok(0);
+    }
+
+    crate::math::mul_div(amount, balance_at, supply_at)
+}
+
+/// Get the share `holder` can still claim (0 once claimed)
+pub fn claimable(env: &Env, distribution_id: u32, holder: &Address) -> Result<i128, Error> {
+    let distribution =
+        storage::get_distribution(env, distribution_id).ok_or(Error::InvalidParameters)?;
+    if distribution.swept || storage::has_claimed_distribution(env, distribution_id, holder) {
+        return Ok(0);
+    }
+
+    let balance_at = crate::snapshot::balance_of_at(
+        env,
+        distribution.token_index,
+        holder,
+        distribution.snapshot_id,
+    )?;
+    compute_share(distribution.amount, balance_at, distribution.supply_at)
+}
+
+/// Claim a holder's share of a distribution
+///
+/// # Returns
+/// * `Ok(share)` - Amount transferred to the holder
+/// * `Err(Error::ContractPaused)` - Transfers are paused
+/// * `Err(Error::InvalidParameters)` - Distribution doesn't exist
+/// * `Err(Error::InvalidTimeWindow)` - Claim deadline has passed
+/// * `Err(Error::NothingToClaim)` - Already claimed or no balance at the snapshot
+pub fn claim(env: &Env, holder: &Address, distribution_id: u32) -> Result<i128, Error> {
+    holder.require_auth();
+
+    if storage::is_operation_paused(env, PAUSE_TRANSFER) {
+        return Err(Error::ContractPaused);
+    }
+
+    let mut distribution =
+        storage::get_distribution(env, distribution_id).ok_or(Error::InvalidParameters)?;
+
+    if env.ledger().timestamp() >= distribution.claim_deadline {
+        return Err(Error::InvalidTimeWindow);
+    }
+
+    let share = claimable(env, distribution_id, holder)?;
+    if share == 0 {
+        return Err(Error::NothingToClaim);
+    }
+
+    distribution.claimed_amount = distribution
+        .claimed_amount
+        .checked_add(share)
+        .ok_or(Error::ArithmeticError)?;
+    storage::set_distribution(env, distribution_id, &distribution);
+    storage::set_distribution_claimed(env, distribution_id, holder);
+
+    token::Client::new(env, &distribution.asset).transfer(
+        &env.current_contract_address(),
+        holder,
+        &share,
+    );
+
+    crate::events::emit_distribution_claimed(env, distribution_id, holder, share);
+
+    Ok(share)
+}
+
+/// Return the unclaimed remainder to the creator after the deadline
+///
+/// # Returns
+/// * `Ok(amount)` - Amount returned to the creator
+/// * `Err(Error::InvalidParameters)` - Distribution doesn't exist
+/// * `Err(Error::Unauthorized)` - Caller is not the distribution creator
+/// * `Err(Error::InvalidStateTransition)` - Deadline not reached or already swept
+pub fn sweep(env: &Env, creator: &Address, distribution_id: u32) -> Result<i128, Error> {
+    creator.require_auth();
+
+    let mut distribution =
+        storage::get_distribution(env, distribution_id).ok_or(Error::InvalidParameters)?;
+    if distribution.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+
+    if env.ledger().timestamp() < distribution.claim_deadline || distribution.swept {
+        return Err(Error::InvalidStateTransition);
+    }
+
+    let remainder = distribution
+        .amount
+        .checked_sub(distribution.claimed_amount)
+        .ok_or(Error::ArithmeticError)?;
+
+    distribution.swept = true;
+    storage::set_distribution(env, distribution_id, &distribution);
+
+    if remainder > 0 {
+        token::Client::new(env, &distribution.asset).transfer(
+            &env.current_contract_address(),
+            creator,
+            &remainder,
+        );
+    }
+
+    crate::events::emit_distribution_swept(env, distribution_id, creator, remainder);
+
+    Ok(remainder)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_share_rounds_down() {
+        // 1000 * 1 / 3 = 333.33 -> 333
+        assert_eq!(compute_share(1_000, 1, 3), Ok(333));
+        assert_eq!(compute_share(1_000, 3, 3), Ok(1_000));
+    }
+
+    #[test]
+    fn test_share_without_balance_is_zero() {
+        assert_eq!(compute_share(1_000, 0, 3), Ok(0));
+    }
+
+    #[test]
+    fn test_share_of_18_decimal_amounts() {
+        // 1e6 of an 18-decimal asset to a holder of 1e8 out of 1e9 tokens
+        let e18 = 1_000_000_000_000_000_000i128;
+        assert_eq!(
+            compute_share(1_000_000 * e18, 100_000_000 * e18, 1_000_000_000 * e18),
+            Ok(100_000 * e18)
+        );
+    }
+
+    #[test]
+    fn test_share_overflow() {
+        assert_eq!(compute_share(i128::MAX, 3, 2), Err(Error::ArithmeticError));
+    }
+}
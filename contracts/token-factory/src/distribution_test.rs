@@ -0,0 +1,185 @@
+//! Distribution Tests
+//!
+//! Tests for pro-rata distribution of a SEP-41 asset to token holders
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+use crate::test_helpers::{create_token, TestEnv};
+use crate::types::Error;
+use crate::TokenFactoryClient;
+
+const DEADLINE: u64 = 1_000;
+
+struct Setup {
+    env: Env,
+    client: TokenFactoryClient<'static>,
+    creator: Address,
+    alice: Address,
+    bob: Address,
+    asset: token::Client<'static>,
+}
+
+/// Token with supply 1_000 split creator 700 / alice 200 / bob 100, and a
+/// SEP-41 asset minted to the creator
+fn setup() -> Setup {
+    let TestEnv { env, client, .. } = TestEnv::initialized();
+
+    let creator = Address::generate(&env);
+    create_token(&client, &creator, "REV", 1_000);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.transfer(&creator, &0, &alice, &200);
+    client.transfer(&creator, &0, &bob, &100);
+
+    let asset_contract = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    token::StellarAssetClient::new(&env, &asset_contract.address()).mint(&creator, &10_000);
+    let asset = token::Client::new(&env, &asset_contract.address());
+
+    Setup {
+        env,
+        client,
+        creator,
+        alice,
+        bob,
+        asset,
+    }
+}
+
+#[test]
+fn test_deposit_pulls_asset_and_snapshots() {
+    let s = setup();
+
+    let id = s
+        .client
+        .deposit_distribution(&s.creator, &0, &s.asset.address, &1_000, &DEADLINE);
+
+    let distribution = s.client.get_distribution(&id).unwrap();
+    assert_eq!(distribution.supply_at, 1_000);
+    assert_eq!(distribution.snapshot_id, 1);
+    assert_eq!(s.asset.balance(&s.creator), 9_000);
+    assert_eq!(s.asset.balance(&s.client.address), 1_000);
+}
+
+#[test]
+fn test_claims_are_pro_rata_to_snapshot() {
+    let s = setup();
+    let id = s
+        .client
+        .deposit_distribution(&s.creator, &0, &s.asset.address, &1_000, &DEADLINE);
+
+    // Balance moves after the snapshot do not change shares
+    s.client.transfer(&s.bob, &0, &s.alice, &100);
+
+    assert_eq!(s.client.claim_distribution(&s.alice, &id), 200);
+    assert_eq!(s.client.claim_distribution(&s.bob, &id), 100);
+    assert_eq!(s.asset.balance(&s.alice), 200);
+    assert_eq!(s.asset.balance(&s.bob), 100);
+}
+
+#[test]
+fn test_escrowed_tokens_are_left_out_of_supply() {
+    let s = setup();
+    s.client
+        .create_flow_stream(&s.creator, &s.bob, &0, &1, &500);
+
+    let id = s
+        .client
+        .deposit_distribution(&s.creator, &0, &s.asset.address, &1_000, &DEADLINE);
+
+    // Only the 500 held by holders share the deposit
+    assert_eq!(s.client.get_distribution(&id).unwrap().supply_at, 500);
+    assert_eq!(s.client.claim_distribution(&s.creator, &id), 400);
+    assert_eq!(s.client.claim_distribution(&s.alice, &id), 400);
+    assert_eq!(s.client.claim_distribution(&s.bob, &id), 200);
+}
+
+#[test]
+fn test_claim_only_once() {
+    let s = setup();
+    let id = s
+        .client
+        .deposit_distribution(&s.creator, &0, &s.asset.address, &1_000, &DEADLINE);
+
+    s.client.claim_distribution(&s.alice, &id);
+    assert_eq!(
+        s.client.try_claim_distribution(&s.alice, &id),
+        Err(Ok(Error::NothingToClaim))
+    );
+    assert_eq!(s.client.get_claimable_distribution(&id, &s.alice), 0);
+
+    // Never held the token
+    assert_eq!(
+        s.client
+            .try_claim_distribution(&Address::generate(&s.env), &id),
+        Err(Ok(Error::NothingToClaim))
+    );
+}
+
+#[test]
+fn test_shares_round_down() {
+    let s = setup();
+    let id = s
+        .client
+        .deposit_distribution(&s.creator, &0, &s.asset.address, &7, &DEADLINE);
+
+    // 7 * 200 / 1000 = 1.4 -> 1, 7 * 100 / 1000 = 0.7 -> 0
+    assert_eq!(s.client.get_claimable_distribution(&id, &s.alice), 1);
+    assert_eq!(s.client.get_claimable_distribution(&id, &s.bob), 0);
+}
+
+#[test]
+fn test_sweep_after_deadline() {
+    let s = setup();
+    let id = s
+        .client
+        .deposit_distribution(&s.creator, &0, &s.asset.address, &1_000, &DEADLINE);
+    s.client.claim_distribution(&s.alice, &id);
+
+    assert_eq!(
+        s.client.try_sweep_distribution(&s.creator, &id),
+        Err(Ok(Error::InvalidStateTransition))
+    );
+
+    s.env.ledger().with_mut(|li| li.timestamp = DEADLINE);
+    assert_eq!(
+        s.client.try_claim_distribution(&s.bob, &id),
+        Err(Ok(Error::InvalidTimeWindow))
+    );
+    assert_eq!(
+        s.client.try_sweep_distribution(&s.alice, &id),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    assert_eq!(s.client.sweep_distribution(&s.creator, &id), 800);
+    assert_eq!(s.asset.balance(&s.creator), 9_800);
+    assert_eq!(
+        s.client.try_sweep_distribution(&s.creator, &id),
+        Err(Ok(Error::InvalidStateTransition))
+    );
+}
+
+#[test]
+fn test_deposit_validation() {
+    let s = setup();
+    let asset = s.asset.address.clone();
+
+    assert_eq!(
+        s.client
+            .try_deposit_distribution(&s.alice, &0, &asset, &100, &DEADLINE),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.client
+            .try_deposit_distribution(&s.creator, &0, &asset, &0, &DEADLINE),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        s.client
+            .try_deposit_distribution(&s.creator, &0, &asset, &100, &0),
+        Err(Ok(Error::InvalidTimeWindow))
+    );
+}
@@ -47,7 +47,7 @@
 ///
 /// Any schema changes require creating a new version (e.g., init_v2).
 
-use soroban_sdk::{symbol_short, Address, BytesN, Env, String};
+use soroban_sdk::{symbol_short, Address, BytesN, Env, String, Vec};
 
 /// Emit initialized event (v1)
 ///
@@ -183,6 +183,46 @@ pub fn emit_admin_proposed(env: &Env, current_admin: &Address, proposed_admin: &
         .publish((symbol_short!("adprp_v1"),), (current_admin, proposed_admin));
 }
 
+/// Emit admin proposal cancelled event (v1)
+///
+/// **Schema Version**: 1
+/// **Event Name**: adcnl_v1
+///
+/// **Topics** (indexed):
+/// - Event name: "adcnl_v1"
+///
+/// **Payload** (non-indexed):
+/// - current_admin: Address - The admin who withdrew the offer
+/// - proposed_admin: Address - The address the offer was made to
+///
+/// **Schema Stability**: This schema is immutable. Any changes require a new version.
+pub fn emit_admin_proposal_cancelled(
+    env: &Env,
+    current_admin: &Address,
+    proposed_admin: &Address,
+) {
+    env.events()
+        .publish((symbol_short!("adcnl_v1"),), (current_admin, proposed_admin));
+}
+
+/// Emit admin proposal expired event (v1)
+///
+/// **Schema Version**: 1
+/// **Event Name**: adexp_v1
+///
+/// **Topics** (indexed):
+/// - Event name: "adexp_v1"
+///
+/// **Payload** (non-indexed):
+/// - proposed_admin: Address - The address the offer was made to
+/// - expires_at: u64 - Deadline the offer passed without acceptance
+///
+/// **Schema Stability**: This schema is immutable. Any changes require a new version.
+pub fn emit_admin_proposal_expired(env: &Env, proposed_admin: &Address, expires_at: u64) {
+    env.events()
+        .publish((symbol_short!("adexp_v1"),), (proposed_admin, expires_at));
+}
+
 /// Emit pause event (v1)
 ///
 /// **Schema Version**: 1
@@ -216,6 +256,24 @@ pub fn emit_unpause(env: &Env, admin: &Address) {
         .publish((symbol_short!("unpaus_v1"),), (admin,));
 }
 
+/// Emit pause flags updated event (v1)
+///
+/// **Schema Version**: 1
+/// **Event Name**: pflags_v1
+///
+/// **Topics** (indexed):
+/// - Event name: "pflags_v1"
+///
+/// **Payload** (non-indexed):
+/// - admin: Address - The administrator who changed the flags
+/// - flags: u32 - New per-operation pause bitmask
+///
+/// **Schema Stability**: This schema is immutable. Any changes require a new version.
+pub fn emit_pause_flags_updated(env: &Env, admin: &Address, flags: u32) {
+    env.events()
+        .publish((symbol_short!("pflags_v1"),), (admin, flags));
+}
+
 /// Emit fees updated event (v1)
 ///
 /// **Schema Version**: 1
@@ -319,16 +377,19 @@ pub fn emit_timelock_configured(env: &Env, delay_seconds: u64) {
 
 /// Emit change scheduled event
 ///
-/// Emitted when a sensitive change is scheduled with timelock
+/// Emitted when a sensitive change is scheduled with timelock. Carries the
+/// execution window and content hash so watchers can alert on the payload.
 pub fn emit_change_scheduled(
     env: &Env,
     change_id: u64,
     change_type: crate::types::ChangeType,
     execute_at: u64,
+    expires_at: u64,
+    content_hash: &BytesN<32>,
 ) {
     env.events().publish(
         (symbol_short!("ch_sched"), change_id),
-        (change_type.clone(), execute_at),
+        (change_type.clone(), execute_at, expires_at, content_hash.clone()),
     );
 }
 
@@ -352,6 +413,24 @@ pub fn emit_change_cancelled(env: &Env, change_id: u64, change_type: crate::type
     );
 }
 
+/// Emit change expired event
+///
+/// Emitted when an unexecuted change is marked expired after its window
+pub fn emit_change_expired(env: &Env, change_id: u64, change_type: crate::types::ChangeType) {
+    env.events().publish(
+        (symbol_short!("ch_expd"), change_id),
+        (change_type.clone(),),
+    );
+}
+
+/// Emit change expiry updated event
+///
+/// Emitted when the execution window for new changes is reconfigured
+pub fn emit_change_expiry_updated(env: &Env, expiry_seconds: u64) {
+    env.events()
+        .publish((symbol_short!("ch_expcf"),), (expiry_seconds,));
+}
+
 /// Emit treasury updated event
 ///
 /// Emitted when treasury address is changed
@@ -368,6 +447,117 @@ pub fn emit_mint(env: &Env, token_index: u32, to: &Address, amount: i128) {
         .publish((symbol_short!("mint"), token_index), (to, amount));
 }
 
+/// Emit minter added event
+///
+/// Emitted when a token creator grants or replaces a minter allowance
+pub fn emit_minter_added(
+    env: &Env,
+    token_index: u32,
+    minter: &Address,
+    allowance: i128,
+    expires_at: u64,
+) {
+    env.events().publish(
+        (symbol_short!("mntr_add"), token_index),
+        (minter, allowance, expires_at),
+    );
+}
+
+/// Emit minter removed event
+///
+/// Emitted when a token creator revokes a minter
+pub fn emit_minter_removed(env: &Env, token_index: u32, minter: &Address) {
+    env.events()
+        .publish((symbol_short!("mntr_rm"), token_index), (minter,));
+}
+
+/// Emit max supply lowered event
+///
+/// Emitted when a token creator lowers (or first sets) the supply cap
+pub fn emit_max_supply_updated(
+    env: &Env,
+    token_index: u32,
+    old_max: Option<i128>,
+    new_max: i128,
+) {
+    env.events().publish(
+        (symbol_short!("sup_cap"), token_index),
+        (old_max, new_max),
+    );
+}
+
+/// Emit supply finalized event
+///
+/// Emitted when a token creator permanently disables minting
+pub fn emit_supply_finalized(env: &Env, token_index: u32, final_supply: i128) {
+    env.events()
+        .publish((symbol_short!("sup_fin"), token_index), (final_supply,));
+}
+
+/// Emit compliance mode updated event
+///
+/// Emitted when a token creator changes the holder compliance mode
+pub fn emit_compliance_mode_updated(
+    env: &Env,
+    token_index: u32,
+    mode: crate::types::ComplianceMode,
+) {
+    env.events()
+        .publish((symbol_short!("cmp_mode"), token_index), (mode,));
+}
+
+/// Emit holders listed event
+///
+/// Emitted when a token creator adds holders to an allowlist or denylist
+pub fn emit_holders_listed(
+    env: &Env,
+    token_index: u32,
+    list: crate::types::HolderList,
+    count: u32,
+) {
+    env.events()
+        .publish((symbol_short!("hld_add"), token_index), (list, count));
+}
+
+/// Emit holders unlisted event
+///
+/// Emitted when a token creator removes holders from an allowlist or denylist
+pub fn emit_holders_unlisted(
+    env: &Env,
+    token_index: u32,
+    list: crate::types::HolderList,
+    count: u32,
+) {
+    env.events()
+        .publish((symbol_short!("hld_rm"), token_index), (list, count));
+}
+
+/// Emit transfer event
+///
+/// Emitted when tokens move between holders. `amount` is the gross amount
+/// debited from `from`; `fee` of it went to the token's fee recipient.
+pub fn emit_transfer(
+    env: &Env,
+    token_index: u32,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+    fee: i128,
+) {
+    env.events().publish(
+        (symbol_short!("transfer"), token_index),
+        (from, to, amount, fee),
+    );
+}
+
+/// Emit approve event
+///
+/// Emitted when a holder sets a spender allowance
+pub fn emit_approve(env: &Env, token_index: u32, owner: &Address, spender: &Address, amount: i128) {
+    env.events()
+        .publish((symbol_short!("approve"), token_index), (owner, spender, amount));
+}
+
 // ── Treasury events ─────────────────────────────────────────
 
 /// Emit treasury withdrawal event
@@ -447,6 +637,398 @@ pub fn emit_metadata_set(
     );
 }
 
+/// Emit token metadata updated event
+///
+/// Emitted when a token creator publishes a new metadata version
+pub fn emit_token_metadata_updated(
+    env: &Env,
+    token_index: u32,
+    version: u32,
+    content_hash: &BytesN<32>,
+) {
+    env.events().publish(
+        (symbol_short!("meta_upd"), token_index),
+        (version, content_hash.clone()),
+    );
+}
+
+/// Emit symbol policy updated event
+///
+/// Emitted when the admin changes the symbol uniqueness policy
+pub fn emit_symbol_policy_updated(env: &Env, policy: crate::types::SymbolPolicy) {
+    env.events()
+        .publish((symbol_short!("sym_pol"),), (policy,));
+}
+
+/// Emit symbol reserved event
+///
+/// Emitted when the admin reserves (`true`) or releases (`false`) a symbol
+pub fn emit_symbol_reserved(env: &Env, symbol: &String, reserved: bool) {
+    env.events()
+        .publish((symbol_short!("sym_rsv"), symbol.clone()), (reserved,));
+}
+
+/// Emit token verified event
+///
+/// Emitted when the admin verifies or unverifies a token for its symbol
+pub fn emit_token_verified(env: &Env, token_index: u32, verified: bool) {
+    env.events()
+        .publish((symbol_short!("sym_vrf"), token_index), (verified,));
+}
+
+/// Emit airdrop created event
+///
+/// Emitted when a creator escrows tokens for a Merkle airdrop campaign
+pub fn emit_airdrop_created(
+    env: &Env,
+    campaign_id: u32,
+    token_index: u32,
+    merkle_root: &BytesN<32>,
+    total_amount: i128,
+    expires_at: u64,
+) {
+    env.events().publish(
+        (symbol_short!("adrp_crt"), campaign_id),
+        (token_index, merkle_root.clone(), total_amount, expires_at),
+    );
+}
+
+/// Emit airdrop claimed event
+///
+/// Emitted when a recipient claims their leaf of an airdrop campaign
+pub fn emit_airdrop_claimed(
+    env: &Env,
+    campaign_id: u32,
+    index: u32,
+    claimant: &Address,
+    amount: i128,
+) {
+    env.events().publish(
+        (symbol_short!("adrp_clm"), campaign_id),
+        (index, claimant.clone(), amount),
+    );
+}
+
+/// Emit airdrop reclaimed event
+///
+/// Emitted when the creator takes back the unclaimed remainder after expiry
+pub fn emit_airdrop_reclaimed(env: &Env, campaign_id: u32, creator: &Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("adrp_rcl"), campaign_id),
+        (creator.clone(), amount),
+    );
+}
+
+/// Emit snapshot taken event
+///
+/// Emitted when a token creator or the admin takes a balance snapshot
+pub fn emit_snapshot_taken(env: &Env, token_index: u32, snapshot_id: u32) {
+    env.events()
+        .publish((symbol_short!("snap"), token_index), (snapshot_id,));
+}
+
+/// Emit distribution deposited event
+///
+/// Emitted when a creator deposits an asset for distribution to holders
+pub fn emit_distribution_deposited(
+    env: &Env,
+    distribution_id: u32,
+    token_index: u32,
+    asset: &Address,
+    amount: i128,
+    snapshot_id: u32,
+) {
+    env.events().publish(
+        (symbol_short!("dist_dep"), distribution_id),
+        (token_index, asset.clone(), amount, snapshot_id),
+    );
+}
+
+/// Emit distribution claimed event
+///
+/// Emitted when a holder claims their share of a distribution
+pub fn emit_distribution_claimed(env: &Env, distribution_id: u32, holder: &Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("dist_clm"), distribution_id),
+        (holder.clone(), amount),
+    );
+}
+
+/// Emit distribution swept event
+///
+/// Emitted when the creator sweeps the unclaimed remainder after the deadline
+pub fn emit_distribution_swept(env: &Env, distribution_id: u32, creator: &Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("dist_swp"), distribution_id),
+        (creator.clone(), amount),
+    );
+}
+
+/// Emit staking pool created event
+///
+/// Emitted when a token creator opens a staking pool
+pub fn emit_staking_pool_created(
+    env: &Env,
+    pool_id: u32,
+    token_index: u32,
+    reward_token_index: u32,
+) {
+    env.events().publish(
+        (symbol_short!("stk_pool"), pool_id),
+        (token_index, reward_token_index),
+    );
+}
+
+/// Emit staking rewards funded event
+///
+/// Emitted when the pool creator funds a new reward period
+pub fn emit_staking_rewards_funded(
+    env: &Env,
+    pool_id: u32,
+    amount: i128,
+    reward_rate: i128,
+    period_finish: u64,
+) {
+    env.events().publish(
+        (symbol_short!("stk_fund"), pool_id),
+        (amount, reward_rate, period_finish),
+    );
+}
+
+/// Emit staked event
+///
+/// Emitted when a holder stakes tokens into a pool
+pub fn emit_staked(env: &Env, pool_id: u32, staker: &Address, amount: i128) {
+    env.events()
+        .publish((symbol_short!("stake"), pool_id), (staker.clone(), amount));
+}
+
+/// Emit unstaked event
+///
+/// Emitted when a holder unstakes; `penalty` is the early-exit penalty paid
+pub fn emit_unstaked(env: &Env, pool_id: u32, staker: &Address, amount: i128, penalty: i128) {
+    env.events().publish(
+        (symbol_short!("unstake"), pool_id),
+        (staker.clone(), amount, penalty),
+    );
+}
+
+/// Emit emergency unstaked event
+///
+/// Emitted when a holder withdraws a whole position without settling
+/// rewards; `forfeited` is the settled reward given up
+pub fn emit_emergency_unstaked(
+    env: &Env,
+    pool_id: u32,
+    staker: &Address,
+    amount: i128,
+    penalty: i128,
+    forfeited: i128,
+) {
+    env.events().publish(
+        (symbol_short!("stk_emrg"), pool_id),
+        (staker.clone(), amount, penalty, forfeited),
+    );
+}
+
+/// Emit staking rewards recovered event
+///
+/// Emitted when the pool creator takes back rewards never allocated to
+/// stakers
+pub fn emit_staking_rewards_recovered(env: &Env, pool_id: u32, amount: i128) {
+    env.events()
+        .publish((symbol_short!("stk_rcvr"), pool_id), amount);
+}
+
+/// Emit staking rewards claimed event
+///
+/// Emitted when a staker claims accrued rewards
+pub fn emit_staking_rewards_claimed(env: &Env, pool_id: u32, staker: &Address, amount: i128) {
+    env.events()
+        .publish((symbol_short!("stk_clm"), pool_id), (staker.clone(), amount));
+}
+
+/// Emit clawback announced event
+///
+/// Emitted when clawback is enabled on a token; holders can be clawed
+/// back from `active_from` on
+pub fn emit_clawback_announced(env: &Env, token_index: u32, active_from: u64) {
+    env.events()
+        .publish((symbol_short!("clwb_ann"), token_index), active_from);
+}
+
+/// Emit clawback notice period updated event
+pub fn emit_clawback_notice_updated(env: &Env, seconds: u64) {
+    env.events()
+        .publish((symbol_short!("clwb_ntc"),), (seconds,));
+}
+
+/// Emit clawback recorded event
+///
+/// Emitted for every entry appended to a token's clawback log
+pub fn emit_clawback_recorded(
+    env: &Env,
+    token_index: u32,
+    position: u32,
+    holder: &Address,
+    amount: i128,
+    reason_code: u32,
+) {
+    env.events().publish(
+        (symbol_short!("clwb_log"), token_index),
+        (position, holder.clone(), amount, reason_code),
+    );
+}
+
+/// Emit flow stream created event
+pub fn emit_flow_created(
+    env: &Env,
+    stream_id: u64,
+    sender: &Address,
+    recipient: &Address,
+    token_index: u32,
+    rate_per_second: i128,
+    deposit: i128,
+) {
+    env.events().publish(
+        (symbol_short!("flw_new"), stream_id),
+        (
+            sender.clone(),
+            recipient.clone(),
+            token_index,
+            rate_per_second,
+            deposit,
+        ),
+    );
+}
+
+/// Emit flow stream rate adjusted event
+pub fn emit_flow_rate_adjusted(env: &Env, stream_id: u64, old_rate: i128, new_rate: i128) {
+    env.events()
+        .publish((symbol_short!("flw_rate"), stream_id), (old_rate, new_rate));
+}
+
+/// Emit flow stream deposit event
+pub fn emit_flow_deposited(env: &Env, stream_id: u64, sender: &Address, amount: i128) {
+    env.events()
+        .publish((symbol_short!("flw_dep"), stream_id), (sender.clone(), amount));
+}
+
+/// Emit flow stream refund event
+pub fn emit_flow_refunded(env: &Env, stream_id: u64, sender: &Address, amount: i128) {
+    env.events()
+        .publish((symbol_short!("flw_rfd"), stream_id), (sender.clone(), amount));
+}
+
+/// Emit flow stream withdrawal event
+///
+/// `uncovered_debt` is what is still owed after the withdrawal because the
+/// deposit ran out
+pub fn emit_flow_withdrawn(
+    env: &Env,
+    stream_id: u64,
+    recipient: &Address,
+    amount: i128,
+    uncovered_debt: i128,
+) {
+    env.events().publish(
+        (symbol_short!("flw_wdr"), stream_id),
+        (recipient.clone(), amount, uncovered_debt),
+    );
+}
+
+/// Emit claim delegation updated event
+///
+/// Published when a recipient changes who may push claims for them
+pub fn emit_claim_delegation_set(
+    env: &Env,
+    recipient: &Address,
+    operator: &Option<Address>,
+    auto_claim: bool,
+    min_interval: u64,
+) {
+    env.events().publish(
+        (symbol_short!("clm_dlg"), recipient.clone()),
+        (operator.clone(), auto_claim, min_interval),
+    );
+}
+
+/// Emit claim pushed event
+///
+/// Published alongside the claim event when a keeper pushes a stream or
+/// vault claim; `is_vault` tells which kind `id` refers to
+pub fn emit_claim_pushed(env: &Env, id: u64, is_vault: bool, keeper: &Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("clm_push"), id),
+        (keeper.clone(), is_vault, amount),
+    );
+}
+
+/// Emit payroll pool created event
+pub fn emit_pool_created(
+    env: &Env,
+    pool_id: u64,
+    creator: &Address,
+    token_index: u32,
+    weighted_rate: i128,
+    deposit: i128,
+) {
+    env.events().publish(
+        (symbol_short!("pool_new"), pool_id),
+        (creator.clone(), token_index, weighted_rate, deposit),
+    );
+}
+
+/// Emit payroll pool deposit event
+pub fn emit_pool_deposited(env: &Env, pool_id: u64, creator: &Address, amount: i128) {
+    env.events()
+        .publish((symbol_short!("pool_dep"), pool_id), (creator.clone(), amount));
+}
+
+/// Emit payroll pool member added or updated event
+pub fn emit_pool_member_set(
+    env: &Env,
+    pool_id: u64,
+    member: &Address,
+    rate_per_second: i128,
+    weight: u32,
+) {
+    env.events().publish(
+        (symbol_short!("pool_mbr"), pool_id),
+        (member.clone(), rate_per_second, weight),
+    );
+}
+
+/// Emit payroll pool member removed event
+///
+/// `accrued` is what the member can still withdraw
+pub fn emit_pool_member_removed(env: &Env, pool_id: u64, member: &Address, accrued: i128) {
+    env.events()
+        .publish((symbol_short!("pool_rm"), pool_id), (member.clone(), accrued));
+}
+
+/// Emit payroll pool weighted rate changed event
+pub fn emit_pool_rate_set(env: &Env, pool_id: u64, old_rate: i128, new_rate: i128) {
+    env.events()
+        .publish((symbol_short!("pool_rate"), pool_id), (old_rate, new_rate));
+}
+
+/// Emit payroll pool refund event
+pub fn emit_pool_refunded(env: &Env, pool_id: u64, creator: &Address, amount: i128) {
+    env.events()
+        .publish((symbol_short!("pool_rfd"), pool_id), (creator.clone(), amount));
+}
+
+/// Emit payroll pool withdrawal event
+///
+/// `unpaid` is what the member is still owed because the balance ran out
+pub fn emit_pool_withdrawn(env: &Env, pool_id: u64, member: &Address, amount: i128, unpaid: i128) {
+    env.events().publish(
+        (symbol_short!("pool_wdr"), pool_id),
+        (member.clone(), amount, unpaid),
+    );
+}
+
 /// Emit batch streams created event
 ///
 /// Published when multiple streams are created in a batch
@@ -466,7 +1048,7 @@ pub fn emit_batch_streams_created(env: &Env, creator: &Address, count: u32) {
 /// 
 /// **Topics** (indexed):
 /// - Event name: "vlt_cr_v1"
-/// - stream_id: u32 - The unique identifier for the created stream
+/// - stream_id: u64 - The unique identifier for the created stream
 /// 
 /// **Payload** (non-indexed):
 /// - creator: Address - The address that created the stream
@@ -479,7 +1061,7 @@ pub fn emit_batch_streams_created(env: &Env, creator: &Address, count: u32) {
 /// Emitted when a new vesting stream is created
 pub fn emit_stream_created(
     env: &Env,
-    stream_id: u32,
+    stream_id: u64,
     creator: &Address,
     recipient: &Address,
     amount: i128,
@@ -559,6 +1141,8 @@ pub fn emit_stream_claimed(
 /// **Payload** (non-indexed):
 /// - canceller: Address - The address that cancelled the stream
 /// - remaining_amount: i128 - Amount of unvested tokens returned
+/// - settled_amount: i128 - Vested but unclaimed amount left to the recipient
+/// - cancelled_at: u64 - Ledger timestamp of the cancellation
 /// 
 /// **Schema Stability**: This schema is immutable. Any changes require a new version.
 /// 
@@ -568,10 +1152,166 @@ pub fn emit_stream_cancelled(
     stream_id: u32,
     canceller: &Address,
     remaining_amount: i128,
+    settled_amount: i128,
+    cancelled_at: u64,
 ) {
     env.events().publish(
         (symbol_short!("vlt_cn_v1"), stream_id),
-        (canceller, remaining_amount),
+        (canceller, remaining_amount, settled_amount, cancelled_at),
+    );
+}
+
+/// Emit stream paused event (v1)
+/// 
+/// **Schema Version**: 1
+/// **Event Name**: vlt_ps_v1
+/// 
+/// **Topics** (indexed):
+/// - Event name: "vlt_ps_v1"
+/// - stream_id: u64 - The stream identifier
+/// 
+/// **Payload** (non-indexed):
+/// - creator: Address - The address that paused the stream
+/// 
+/// **Schema Stability**: This schema is immutable. Any changes require a new version.
+/// 
+/// Emitted when a stream's vesting clock is stopped
+pub fn emit_stream_paused(env: &Env, stream_id: u64, creator: &Address) {
+    env.events()
+        .publish((symbol_short!("vlt_ps_v1"), stream_id), (creator,));
+}
+
+/// Emit stream resumed event (v1)
+/// 
+/// **Schema Version**: 1
+/// **Event Name**: vlt_rs_v1
+/// 
+/// **Topics** (indexed):
+/// - Event name: "vlt_rs_v1"
+/// - stream_id: u64 - The stream identifier
+/// 
+/// **Payload** (non-indexed):
+/// - creator: Address - The address that resumed the stream
+/// - paused_secs: u64 - Length of the pause the schedule was shifted by
+/// 
+/// **Schema Stability**: This schema is immutable. Any changes require a new version.
+/// 
+/// Emitted when a paused stream's vesting clock is restarted
+pub fn emit_stream_resumed(env: &Env, stream_id: u64, creator: &Address, paused_secs: u64) {
+    env.events().publish(
+        (symbol_short!("vlt_rs_v1"), stream_id),
+        (creator, paused_secs),
+    );
+}
+
+/// Emit stream topped up event (v1)
+/// 
+/// **Schema Version**: 1
+/// **Event Name**: vlt_tu_v1
+/// 
+/// **Topics** (indexed):
+/// - Event name: "vlt_tu_v1"
+/// - stream_id: u64 - The stream identifier
+/// 
+/// **Payload** (non-indexed):
+/// - creator: Address - The address that funded the amendment
+/// - additional_amount: i128 - Amount added to the stream
+/// - new_end_time: u64 - End time of the re-based schedule
+/// - vested_amount: i128 - Amount vested when the schedule was re-based
+/// 
+/// **Schema Stability**: This schema is immutable. Any changes require a new version.
+/// 
+/// Emitted when a stream is topped up or its end time is changed
+pub fn emit_stream_topped_up(
+    env: &Env,
+    stream_id: u64,
+    creator: &Address,
+    additional_amount: i128,
+    new_end_time: u64,
+    vested_amount: i128,
+) {
+    env.events().publish(
+        (symbol_short!("vlt_tu_v1"), stream_id),
+        (creator, additional_amount, new_end_time, vested_amount),
+    );
+}
+
+/// Emit stream transferred event (v1)
+/// 
+/// **Schema Version**: 1
+/// **Event Name**: vlt_tf_v1
+/// 
+/// **Topics** (indexed):
+/// - Event name: "vlt_tf_v1"
+/// - stream_id: u64 - The stream identifier
+/// 
+/// **Payload** (non-indexed):
+/// - from: Address - Previous recipient
+/// - to: Address - New recipient
+/// 
+/// **Schema Stability**: This schema is immutable. Any changes require a new version.
+/// 
+/// Emitted when a recipient hands a stream to another address
+pub fn emit_stream_transferred(env: &Env, stream_id: u64, from: &Address, to: &Address) {
+    env.events()
+        .publish((symbol_short!("vlt_tf_v1"), stream_id), (from, to));
+}
+
+/// Emit stream transferable flag set event (v1)
+/// 
+/// **Schema Version**: 1
+/// **Event Name**: vlt_tb_v1
+/// 
+/// **Topics** (indexed):
+/// - Event name: "vlt_tb_v1"
+/// - stream_id: u64 - The stream identifier
+/// 
+/// **Payload** (non-indexed):
+/// - creator: Address - Stream creator who changed the flag
+/// - transferable: bool - Whether the recipient may now transfer the stream
+/// 
+/// **Schema Stability**: This schema is immutable. Any changes require a new version.
+/// 
+/// Emitted when a creator allows or forbids transferring a stream
+pub fn emit_stream_transferable_set(
+    env: &Env,
+    stream_id: u64,
+    creator: &Address,
+    transferable: bool,
+) {
+    env.events().publish(
+        (symbol_short!("vlt_tb_v1"), stream_id),
+        (creator, transferable),
+    );
+}
+
+/// Emit stream split event (v1)
+/// 
+/// **Schema Version**: 1
+/// **Event Name**: vlt_sp_v1
+/// 
+/// **Topics** (indexed):
+/// - Event name: "vlt_sp_v1"
+/// - stream_id: u64 - The closed parent stream
+/// 
+/// **Payload** (non-indexed):
+/// - recipient: Address - Recipient who split the stream
+/// - children: Vec<u64> - IDs of the child streams, in part order
+/// - remaining: i128 - Unclaimed amount moved to the children
+/// 
+/// **Schema Stability**: This schema is immutable. Any changes require a new version.
+/// 
+/// Emitted when a recipient divides a stream into child streams
+pub fn emit_stream_split(
+    env: &Env,
+    stream_id: u64,
+    recipient: &Address,
+    children: &Vec<u64>,
+    remaining: i128,
+) {
+    env.events().publish(
+        (symbol_short!("vlt_sp_v1"), stream_id),
+        (recipient, children.clone(), remaining),
     );
 }
 
@@ -0,0 +1,283 @@
+//! Flow Streams
+//!
+//! Open-ended streams defined by a per-second rate instead of a total and
+//! an end date. The sender keeps a deposit topped up; debt to the
+//! recipient accrues every second:
+//!
+//! ```text
+//! total_debt = snapshot_debt + rate_per_second * (now - snapshot_time)
+//! withdrawable = min(total_debt, balance)
+//! uncovered_debt = total_debt - withdrawable
+//! ```
+//!
+//! The snapshot is taken whenever the rate or the balance changes, so a
+//! rate change never rewrites debt that has already accrued. When the
+//! deposit runs out the stream is insolvent: debt keeps accruing and is
+//! paid off first by the next deposit. Only the surplus above the total
+//! debt can be refunded to the sender.
+
+use crate::events;
+use crate::storage;
+use crate::streaming::{escrow_from, release_to};
+use crate::types::{Error, FlowStream, PAUSE_STREAM_CLAIM, PAUSE_STREAM_CREATE};
+use soroban_sdk::{Address, Env};
+
+/// Open a flow stream, optionally with an initial deposit
+///
+/// # Returns
+/// * `Ok(stream_id)` - Stream created
+/// * `Err(Error::ContractPaused)` - Stream creation is paused
+/// * `Err(Error::TokenNotFound)` - Token doesn't exist
+/// * `Err(Error::InvalidParameters)` - Sender streams to itself
+/// * `Err(Error::InvalidAmount)` - Rate is not positive or deposit is negative
+/// * `Err(Error::InsufficientBalance)` - Sender cannot fund the deposit
+pub fn create(
+    env: &Env,
+    sender: &Address,
+    recipient: &Address,
+    token_index: u32,
+    rate_per_second: i128,
+    deposit: i128,
+) -> Result<u64, Error> {
+    sender.require_auth();
+
+    if storage::is_operation_paused(env, PAUSE_STREAM_CREATE) {
+        return Err(Error::ContractPaused);
+    }
+
+    storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+
+    if sender == recipient {
+        return Err(Error::InvalidParameters);
+    }
+    if rate_per_second <= 0 || deposit < 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    if deposit > 0 {
+        escrow_from(env, token_index, sender, deposit)?;
+    }
+
+    let stream_id = storage::get_flow_stream_count(env);
+    let next_id = stream_id.checked_add(1).ok_or(Error::ArithmeticError)?;
+
+    storage::set_flow_stream(
+        env,
+        stream_id,
+        &FlowStream {
+            sender: sender.clone(),
+            recipient: recipient.clone(),
+            token_index,
+            rate_per_second,
+            balance: deposit,
+            snapshot_debt: 0,
+            snapshot_time: env.ledger().timestamp(),
+            withdrawn: 0,
+        },
+    );
+    storage::set_flow_stream_count(env, next_id);
+
+    events::emit_flow_created(
+        env,
+        stream_id,
+        sender,
+        recipient,
+        token_index,
+        rate_per_second,
+        deposit,
+    );
+
+    Ok(stream_id)
+}
+
+/// Change a flow stream's rate (sender only)
+///
+/// Debt accrued at the old rate is kept. A rate of 0 pauses accrual.
+///
+/// # Returns
+/// * `Ok(())` - Rate changed
+/// * `Err(Error::StreamNotFound)` - Stream doesn't exist
+/// * `Err(Error::Unauthorized)` - Caller is not the sender
+/// * `Err(Error::InvalidAmount)` - Rate is negative
+pub fn adjust_rate(
+    env: &Env,
+    sender: &Address,
+    stream_id: u64,
+    rate_per_second: i128,
+) -> Result<(), Error> {
+    sender.require_auth();
+
+    let mut stream = load_as_sender(env, sender, stream_id)?;
+    if rate_per_second < 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    snapshot(&mut stream, env.ledger().timestamp())?;
+    let old_rate = stream.rate_per_second;
+    stream.rate_per_second = rate_per_second;
+    storage::set_flow_stream(env, stream_id, &stream);
+
+    events::emit_flow_rate_adjusted(env, stream_id, old_rate, rate_per_second);
+    Ok(())
+}
+
+/// Add runway to a flow stream (sender only)
+///
+/// # Returns
+/// * `Ok(())` - Deposit added
+/// * `Err(Error::StreamNotFound)` - Stream doesn't exist
+/// * `Err(Error::Unauthorized)` - Caller is not the sender
+/// * `Err(Error::InvalidAmount)` - Amount is not positive
+/// * `Err(Error::InsufficientBalance)` - Sender cannot fund the deposit
+pub fn deposit(env: &Env, sender: &Address, stream_id: u64, amount: i128) -> Result<(), Error> {
+    sender.require_auth();
+
+    let mut stream = load_as_sender(env, sender, stream_id)?;
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    escrow_from(env, stream.token_index, sender, amount)?;
+    stream.balance = stream
+        .balance
+        .checked_add(amount)
+        .ok_or(Error::ArithmeticError)?;
+    storage::set_flow_stream(env, stream_id, &stream);
+
+    events::emit_flow_deposited(env, stream_id, sender, amount);
+    Ok(())
+}
+
+/// Return part of the deposit not owed to the recipient (sender only)
+///
+/// # Returns
+/// * `Ok(())` - Amount returned to the sender's balance
+/// * `Err(Error::StreamNotFound)` - Stream doesn't exist
+/// * `Err(Error::Unauthorized)` - Caller is not the sender
+/// * `Err(Error::InvalidAmount)` - Amount is not positive
+/// * `Err(Error::InsufficientBalance)` - Amount exceeds the surplus over the debt
+pub fn refund_excess(
+    env: &Env,
+    sender: &Address,
+    stream_id: u64,
+    amount: i128,
+) -> Result<(), Error> {
+    sender.require_auth();
+
+    let mut stream = load_as_sender(env, sender, stream_id)?;
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    if amount > refundable(&stream, env.ledger().timestamp())? {
+        return Err(Error::InsufficientBalance);
+    }
+
+    stream.balance -= amount;
+    storage::set_flow_stream(env, stream_id, &stream);
+    release_to(env, stream.token_index, sender, amount)?;
+
+    events::emit_flow_refunded(env, stream_id, sender, amount);
+    Ok(())
+}
+
+/// Withdraw everything currently covered by the deposit (recipient only)
+///
+/// # Returns
+/// * `Ok(amount)` - Amount credited to the recipient
+/// * `Err(Error::ContractPaused)` - Stream claims are paused
+/// * `Err(Error::StreamNotFound)` - Stream doesn't exist
+/// * `Err(Error::Unauthorized)` - Caller is not the recipient
+/// * `Err(Error::TokenPaused)` - Token is paused
+/// * `Err(Error::NothingToClaim)` - Nothing withdrawable
+pub fn withdraw(env: &Env, recipient: &Address, stream_id: u64) -> Result<i128, Error> {
+    recipient.require_auth();
+
+    if storage::is_operation_paused(env, PAUSE_STREAM_CLAIM) {
+        return Err(Error::ContractPaused);
+    }
+
+    let mut stream = storage::get_flow_stream(env, stream_id).ok_or(Error::StreamNotFound)?;
+    if stream.recipient != *recipient {
+        return Err(Error::Unauthorized);
+    }
+
+    crate::compliance::require_eligible(env, stream.token_index, recipient)?;
+
+    if storage::is_token_paused(env, stream.token_index) {
+        return Err(Error::TokenPaused);
+    }
+
+    snapshot(&mut stream, env.ledger().timestamp())?;
+    let amount = stream.snapshot_debt.min(stream.balance);
+    if amount == 0 {
+        return Err(Error::NothingToClaim);
+    }
+
+    stream.snapshot_debt -= amount;
+    stream.balance -= amount;
+    stream.withdrawn = stream
+        .withdrawn
+        .checked_add(amount)
+        .ok_or(Error::ArithmeticError)?;
+    storage::set_flow_stream(env, stream_id, &stream);
+    release_to(env, stream.token_index, recipient, amount)?;
+
+    events::emit_flow_withdrawn(env, stream_id, recipient, amount, stream.snapshot_debt);
+    Ok(amount)
+}
+
+/// Debt owed to the recipient at `now`, covered or not
+pub fn total_debt(stream: &FlowStream, now: u64) -> Result<i128, Error> {
+    let elapsed = now.saturating_sub(stream.snapshot_time) as i128;
+    stream
+        .rate_per_second
+        .checked_mul(elapsed)
+        .and_then(|accrued| accrued.checked_add(stream.snapshot_debt))
+        .ok_or(Error::ArithmeticError)
+}
+
+/// Amount the recipient can withdraw at `now`
+pub fn withdrawable(stream: &FlowStream, now: u64) -> Result<i128, Error> {
+    Ok(total_debt(stream, now)?.min(stream.balance))
+}
+
+/// Debt not covered by the deposit at `now` (0 while solvent)
+pub fn uncovered_debt(stream: &FlowStream, now: u64) -> Result<i128, Error> {
+    Ok((total_debt(stream, now)? - stream.balance).max(0))
+}
+
+/// Last second at which the deposit still covers the debt
+///
+/// `None` when the rate is 0 and the stream can never run dry; a time in
+/// the past when the stream is already insolvent.
+pub fn depletion_time(stream: &FlowStream) -> Option<u64> {
+    if stream.rate_per_second == 0 {
+        return None;
+    }
+    let runway = (stream.balance - stream.snapshot_debt).max(0) / stream.rate_per_second;
+    Some(
+        stream
+            .snapshot_time
+            .saturating_add(u64::try_from(runway).unwrap_or(u64::MAX)),
+    )
+}
+
+fn refundable(stream: &FlowStream, now: u64) -> Result<i128, Error> {
+    Ok((stream.balance - total_debt(stream, now)?).max(0))
+}
+
+/// Fold accrued debt into the snapshot
+fn snapshot(stream: &mut FlowStream, now: u64) -> Result<(), Error> {
+    stream.snapshot_debt = total_debt(stream, now)?;
+    stream.snapshot_time = now;
+    Ok(())
+}
+
+fn load_as_sender(env: &Env, sender: &Address, stream_id: u64) -> Result<FlowStream, Error> {
+    let stream = storage::get_flow_stream(env, stream_id).ok_or(Error::StreamNotFound)?;
+    if stream.sender != *sender {
+        return Err(Error::Unauthorized);
+    }
+    Ok(stream)
+}
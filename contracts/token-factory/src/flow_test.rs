@@ -0,0 +1,133 @@
+//! Flow Stream Tests
+//!
+//! Tests for open-ended rate-based streams: accrual, rate changes,
+//! deposits and refunds of excess, insolvency and debt tracking
+
+use crate::test_helpers::{set_time, StreamFixture};
+use crate::types::Error;
+
+const SUPPLY: i128 = 1_000_000;
+
+/// Token 0 owned by the creator, ledger time 1_000
+fn setup() -> StreamFixture {
+    let s = StreamFixture::new("SAL", SUPPLY);
+    set_time(&s.env, 1_000);
+    s
+}
+
+#[test]
+fn test_flow_accrues_and_withdraws() {
+    let s = setup();
+    let id = s
+        .client
+        .create_flow_stream(&s.creator, &s.alice, &0, &10, &1_000);
+    assert_eq!(s.client.get_balance(&0, &s.creator), SUPPLY - 1_000);
+    assert_eq!(s.client.get_depletion_time(&id), Some(1_100));
+
+    set_time(&s.env, 1_030);
+    assert_eq!(s.client.get_flow_withdrawable(&id), 300);
+    assert_eq!(s.client.withdraw_flow_stream(&s.alice, &id), 300);
+    assert_eq!(s.client.get_balance(&0, &s.alice), 300);
+    assert_eq!(
+        s.client.try_withdraw_flow_stream(&s.alice, &id),
+        Err(Ok(Error::NothingToClaim))
+    );
+
+    // Halving the rate keeps what accrued and doubles the remaining runway
+    set_time(&s.env, 1_050);
+    s.client.adjust_flow_rate(&s.creator, &id, &5);
+    assert_eq!(s.client.get_depletion_time(&id), Some(1_150));
+    set_time(&s.env, 1_060);
+    assert_eq!(s.client.get_flow_withdrawable(&id), 250);
+}
+
+#[test]
+fn test_insolvency_tracks_debt_until_topped_up() {
+    let s = setup();
+    let id = s
+        .client
+        .create_flow_stream(&s.creator, &s.alice, &0, &10, &500);
+
+    set_time(&s.env, 1_080);
+    assert_eq!(s.client.get_stream_debt(&id), 300);
+    assert_eq!(s.client.get_depletion_time(&id), Some(1_050));
+    assert_eq!(s.client.withdraw_flow_stream(&s.alice, &id), 500);
+    assert_eq!(s.client.get_stream_debt(&id), 300);
+
+    // A deposit pays the debt off before it adds runway
+    s.client.deposit_flow_stream(&s.creator, &id, &1_000);
+    assert_eq!(s.client.get_stream_debt(&id), 0);
+    assert_eq!(s.client.get_flow_withdrawable(&id), 300);
+    assert_eq!(s.client.get_depletion_time(&id), Some(1_150));
+
+    set_time(&s.env, 1_100);
+    assert_eq!(s.client.withdraw_flow_stream(&s.alice, &id), 500);
+    let stream = s.client.get_flow_stream(&id).unwrap();
+    assert_eq!(stream.withdrawn, 1_000);
+    assert_eq!(stream.balance, 500);
+}
+
+#[test]
+fn test_only_excess_is_refundable() {
+    let s = setup();
+    let id = s
+        .client
+        .create_flow_stream(&s.creator, &s.alice, &0, &10, &1_000);
+
+    set_time(&s.env, 1_040);
+    assert_eq!(
+        s.client.try_refund_flow_excess(&s.creator, &id, &601),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    s.client.refund_flow_excess(&s.creator, &id, &600);
+    assert_eq!(s.client.get_balance(&0, &s.creator), SUPPLY - 400);
+
+    // The recipient still gets everything accrued
+    assert_eq!(s.client.withdraw_flow_stream(&s.alice, &id), 400);
+    assert_eq!(s.client.get_stream_debt(&id), 0);
+
+    // Stopping the rate freezes the debt and the depletion time
+    s.client.adjust_flow_rate(&s.creator, &id, &0);
+    assert_eq!(s.client.get_depletion_time(&id), None);
+}
+
+#[test]
+fn test_flow_validation() {
+    let s = setup();
+
+    assert_eq!(
+        s.client
+            .try_create_flow_stream(&s.creator, &s.alice, &0, &0, &0),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        s.client
+            .try_create_flow_stream(&s.creator, &s.creator, &0, &10, &0),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(
+        s.client
+            .try_create_flow_stream(&s.creator, &s.alice, &0, &10, &(SUPPLY + 1)),
+        Err(Ok(Error::InsufficientBalance))
+    );
+
+    let id = s
+        .client
+        .create_flow_stream(&s.creator, &s.alice, &0, &10, &0);
+    assert_eq!(
+        s.client.try_deposit_flow_stream(&s.alice, &id, &100),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.client.try_adjust_flow_rate(&s.creator, &id, &-1),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        s.client.try_withdraw_flow_stream(&s.creator, &id),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.client.try_get_stream_debt(&7),
+        Err(Ok(Error::StreamNotFound))
+    );
+}
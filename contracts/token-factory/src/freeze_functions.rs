@@ -1,4 +1,7 @@
-use crate::{storage, types::Error};
+use crate::{
+    storage,
+    types::{Error, PAUSE_TOKEN_CONTROLS},
+};
 use soroban_sdk::{symbol_short, Address, Env};
 
 /// Freeze an address for a specific token
@@ -21,7 +24,7 @@ use soroban_sdk::{symbol_short, Address, Env};
 /// - Prevents freezing already frozen addresses
 ///
 /// # Errors
-/// * `ContractPaused` - If contract is paused
+/// * `ContractPaused` - If token controls are paused
 /// * `Unauthorized` - If caller is not the token creator
 /// * `TokenNotFound` - If token doesn't exist
 /// * `FreezeNotEnabled` - If freeze is not enabled for this token
@@ -33,7 +36,7 @@ pub fn freeze_address(
     address_to_freeze: &Address,
 ) -> Result<(), Error> {
     // Check if contract is paused
-    if storage::is_paused(env) {
+    if storage::is_operation_paused(env, PAUSE_TOKEN_CONTROLS) {
         return Err(Error::ContractPaused);
     }
 
@@ -95,7 +98,7 @@ pub fn freeze_address(
 /// - Prevents unfreezing non-frozen addresses
 ///
 /// # Errors
-/// * `ContractPaused` - If contract is paused
+/// * `ContractPaused` - If token controls are paused
 /// * `Unauthorized` - If caller is not the token creator
 /// * `TokenNotFound` - If token doesn't exist
 /// * `FreezeNotEnabled` - If freeze is not enabled for this token
@@ -107,7 +110,7 @@ pub fn unfreeze_address(
     address_to_unfreeze: &Address,
 ) -> Result<(), Error> {
     // Check if contract is paused
-    if storage::is_paused(env) {
+    if storage::is_operation_paused(env, PAUSE_TOKEN_CONTROLS) {
         return Err(Error::ContractPaused);
     }
 
@@ -174,7 +177,7 @@ pub fn is_frozen(env: &Env, token_address: &Address, address: &Address) -> bool
 /// * `enabled` - Whether to enable or disable freeze
 ///
 /// # Errors
-/// * `ContractPaused` - If contract is paused
+/// * `ContractPaused` - If token controls are paused
 /// * `Unauthorized` - If caller is not the token creator
 /// * `TokenNotFound` - If token doesn't exist
 pub fn set_freeze_enabled(
@@ -184,7 +187,7 @@ pub fn set_freeze_enabled(
     enabled: bool,
 ) -> Result<(), Error> {
     // Check if contract is paused
-    if storage::is_paused(env) {
+    if storage::is_operation_paused(env, PAUSE_TOKEN_CONTROLS) {
         return Err(Error::ContractPaused);
     }
 
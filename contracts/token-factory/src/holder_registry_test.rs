@@ -0,0 +1,85 @@
+//! Holder Registry Tests
+//!
+//! Tests for the per-token holder index, holder counts and paginated
+//! holder listing
+
+use soroban_sdk::{testutils::Address as _, vec, Address, Env};
+
+use crate::test_helpers::{create_token, TestEnv};
+use crate::TokenFactoryClient;
+
+fn setup() -> (Env, TokenFactoryClient<'static>, Address) {
+    let TestEnv { env, client, .. } = TestEnv::initialized();
+
+    let creator = Address::generate(&env);
+    create_token(&client, &creator, "HOLD", 1_000);
+
+    (env, client, creator)
+}
+
+#[test]
+fn test_creator_is_first_holder() {
+    let (_env, client, creator) = setup();
+
+    assert_eq!(client.get_token_stats(&0).holder_count, 1);
+    let page = client.get_holders(&0, &0, &10);
+    assert_eq!(page.holders.len(), 1);
+    assert_eq!(page.holders.get(0).unwrap(), (creator, 1_000));
+    assert_eq!(page.next_cursor, None);
+}
+
+#[test]
+fn test_holders_added_on_first_balance() {
+    let (env, client, creator) = setup();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    client.mint(&creator, &0, &alice, &100);
+    client.transfer(&creator, &0, &bob, &50);
+    // A second credit does not add a duplicate entry
+    client.transfer(&creator, &0, &alice, &50);
+
+    assert_eq!(client.get_token_stats(&0).holder_count, 3);
+}
+
+#[test]
+fn test_holders_removed_on_zero_balance() {
+    let (env, client, creator) = setup();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.transfer(&creator, &0, &alice, &100);
+    client.transfer(&creator, &0, &bob, &100);
+
+    // Emptying the first-listed holder moves the last one into its slot
+    client.transfer(&creator, &0, &alice, &800);
+    assert_eq!(client.get_token_stats(&0).holder_count, 2);
+
+    let page = client.get_holders(&0, &0, &10);
+    assert_eq!(page.holders.len(), 2);
+    assert!(page.holders.contains(&(alice.clone(), 900)));
+    assert!(page.holders.contains(&(bob.clone(), 100)));
+
+    client.transfer(&bob, &0, &alice, &100);
+    let page = client.get_holders(&0, &0, &10);
+    assert_eq!(page.holders, vec![&env, (alice, 1_000)]);
+}
+
+#[test]
+fn test_holder_pagination() {
+    let (env, client, creator) = setup();
+    for _ in 0..4 {
+        client.transfer(&creator, &0, &Address::generate(&env), &10);
+    }
+
+    let first = client.get_holders(&0, &0, &3);
+    assert_eq!(first.holders.len(), 3);
+    assert_eq!(first.next_cursor, Some(3));
+
+    let second = client.get_holders(&0, &3, &3);
+    assert_eq!(second.holders.len(), 2);
+    assert_eq!(second.next_cursor, None);
+
+    let beyond = client.get_holders(&0, &10, &3);
+    assert_eq!(beyond.holders.len(), 0);
+    assert_eq!(beyond.next_cursor, None);
+}
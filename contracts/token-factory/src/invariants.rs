@@ -100,7 +100,7 @@ pub fn assert_stream_count_monotonic(
     beneficiary: &soroban_sdk::Address,
     floor: u32,
 ) {
-    let current = storage::get_beneficiary_stream_count(env, beneficiary);
+    let current = storage::get_recipient_stream_count(env, beneficiary);
     assert!(
         current >= floor,
         "INVARIANT VIOLATION [stream_count_monotonic]: \
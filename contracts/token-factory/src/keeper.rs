@@ -0,0 +1,80 @@
+//! Keeper Claims
+//!
+//! Recipients of streams and vaults normally have to sign every claim. A
+//! recipient can instead name an operator, or opt into auto-claim so that
+//! any keeper may push claims for them. Pushed claims always pay the
+//! recipient, never the keeper, and can be rate-limited per stream or
+//! vault with a minimum interval.
+
+use crate::events;
+use crate::storage;
+use crate::types::{ClaimDelegation, Error, ExtDataKey};
+use soroban_sdk::{Address, Env};
+
+/// Set who may push claims for `recipient`
+///
+/// Naming no operator and disabling auto-claim revokes all delegation.
+///
+/// # Returns
+/// * `Ok(())` - Delegation updated
+/// * `Err(Error::InvalidParameters)` - Recipient names itself as operator
+pub fn set_delegation(
+    env: &Env,
+    recipient: &Address,
+    operator: Option<Address>,
+    auto_claim: bool,
+    min_interval: u64,
+) -> Result<(), Error> {
+    recipient.require_auth();
+
+    if operator.as_ref() == Some(recipient) {
+        return Err(Error::InvalidParameters);
+    }
+
+    if operator.is_none() && !auto_claim {
+        storage::remove_claim_delegation(env, recipient);
+    } else {
+        storage::set_claim_delegation(
+            env,
+            recipient,
+            &ClaimDelegation {
+                operator: operator.clone(),
+                auto_claim,
+                min_interval,
+            },
+        );
+    }
+
+    events::emit_claim_delegation_set(env, recipient, &operator, auto_claim, min_interval);
+    Ok(())
+}
+
+/// Check that `keeper` may push a claim for `recipient` now and record it
+///
+/// `last_claim_key` identifies the stream or vault being claimed.
+///
+/// # Returns
+/// * `Ok(())` - Claim may go ahead
+/// * `Err(Error::Unauthorized)` - Recipient has not delegated to `keeper`
+/// * `Err(Error::InvalidTimeWindow)` - Minimum interval not elapsed yet
+pub fn authorize_push(
+    env: &Env,
+    keeper: &Address,
+    recipient: &Address,
+    last_claim_key: &ExtDataKey,
+) -> Result<(), Error> {
+    let delegation = storage::get_claim_delegation(env, recipient).ok_or(Error::Unauthorized)?;
+    if !delegation.auto_claim && delegation.operator.as_ref() != Some(keeper) {
+        return Err(Error::Unauthorized);
+    }
+
+    let now = env.ledger().timestamp();
+    if let Some(last) = storage::get_last_pushed_claim(env, last_claim_key) {
+        if now < last.saturating_add(delegation.min_interval) {
+            return Err(Error::InvalidTimeWindow);
+        }
+    }
+    storage::set_last_pushed_claim(env, last_claim_key, now);
+
+    Ok(())
+}
@@ -0,0 +1,130 @@
+//! Keeper Claim Tests
+//!
+//! Tests for claims pushed by an operator or any keeper on behalf of
+//! stream recipients and vault owners, and the minimum claim interval
+
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    token, Address, BytesN, IntoVal, Symbol,
+};
+
+use crate::test_helpers::{create_stream, set_time, StreamFixture};
+use crate::types::Error;
+
+const WEEK: u64 = 604_800;
+
+/// Token 0 owned by the creator, stream 0 of 4_000 to alice vesting over
+/// four weeks from t=0, and a keeper
+fn setup() -> (StreamFixture, Address) {
+    let s = StreamFixture::new("PAY", 1_000_000);
+    create_stream(&s.client, &s.creator, &s.alice, 4_000, 0, 0, 4 * WEEK);
+    let keeper = Address::generate(&s.env);
+    (s, keeper)
+}
+
+#[test]
+fn test_operator_pushes_claims_to_recipient() {
+    let (s, keeper) = setup();
+
+    set_time(&s.env, WEEK);
+    assert_eq!(
+        s.client.try_push_claim(&keeper, &0),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    s.client
+        .set_claim_delegation(&s.alice, &Some(keeper.clone()), &false, &0);
+    assert_eq!(s.client.push_claim(&keeper, &0), 1_000);
+
+    let (_, topics, data) = s.env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (Symbol::new(&s.env, "clm_push"), 0u64).into_val(&s.env)
+    );
+    let payload: (Address, bool, i128) = data.into_val(&s.env);
+    assert_eq!(payload, (keeper.clone(), false, 1_000));
+
+    assert_eq!(s.client.get_balance(&0, &s.alice), 1_000);
+    assert_eq!(s.client.get_balance(&0, &keeper), 0);
+
+    // Only the named operator may push without auto-claim
+    let stranger = Address::generate(&s.env);
+    set_time(&s.env, 2 * WEEK);
+    assert_eq!(
+        s.client.try_push_claim(&stranger, &0),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    // Revoking stops the operator too
+    s.client.set_claim_delegation(&s.alice, &None, &false, &0);
+    assert_eq!(s.client.get_claim_delegation(&s.alice), None);
+    assert_eq!(
+        s.client.try_push_claim(&keeper, &0),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_auto_claim_respects_min_interval() {
+    let (s, keeper) = setup();
+    s.client.set_claim_delegation(&s.alice, &None, &true, &WEEK);
+
+    set_time(&s.env, WEEK);
+    assert_eq!(s.client.push_claim(&keeper, &0), 1_000);
+
+    // A different keeper is held to the same weekly interval
+    let other = Address::generate(&s.env);
+    set_time(&s.env, 2 * WEEK - 1);
+    assert_eq!(
+        s.client.try_push_claim(&other, &0),
+        Err(Ok(Error::InvalidTimeWindow))
+    );
+
+    // The recipient's own claims are never limited
+    assert_eq!(s.client.claim_stream(&s.alice, &0), 999);
+
+    set_time(&s.env, 2 * WEEK);
+    assert_eq!(s.client.push_claim(&other, &0), 1);
+    assert_eq!(s.client.get_balance(&0, &s.alice), 2_000);
+}
+
+#[test]
+fn test_keeper_pushes_vault_claim_to_owner() {
+    let (s, keeper) = setup();
+
+    let asset = s
+        .env
+        .register_stellar_asset_contract_v2(Address::generate(&s.env))
+        .address();
+    token::StellarAssetClient::new(&s.env, &asset).mint(&s.contract_id, &500);
+    let info = s.client.get_token_info(&0);
+    s.env.as_contract(&s.contract_id, || {
+        crate::storage::set_token_info_by_address(&s.env, &asset, &info);
+    });
+
+    let no_milestone = BytesN::from_array(&s.env, &[0u8; 32]);
+    let vault_id = s
+        .client
+        .create_vault(&s.creator, &asset, &s.alice, &500, &WEEK, &no_milestone);
+
+    s.client.set_claim_delegation(&s.alice, &None, &true, &0);
+    assert_eq!(
+        s.client.try_push_vault_claim(&keeper, &vault_id, &None),
+        Err(Ok(Error::InvalidParameters))
+    );
+
+    set_time(&s.env, WEEK);
+    assert_eq!(s.client.push_vault_claim(&keeper, &vault_id, &None), 500);
+    assert_eq!(token::Client::new(&s.env, &asset).balance(&s.alice), 500);
+    assert_eq!(token::Client::new(&s.env, &asset).balance(&keeper), 0);
+}
+
+#[test]
+fn test_recipient_cannot_name_itself_operator() {
+    let (s, _) = setup();
+    assert_eq!(
+        s.client
+            .try_set_claim_delegation(&s.alice, &Some(s.alice.clone()), &false, &0),
+        Err(Ok(Error::InvalidParameters))
+    );
+}
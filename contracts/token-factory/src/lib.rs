@@ -3,10 +3,18 @@
 mod freeze_functions;
 mod governance;
 
+mod airdrop;
 mod burn;
+mod clawback;
+mod compliance;
 mod differential_engine;
+mod distribution;
 mod event_versions;
 mod events;
+mod flow;
+mod keeper;
+mod math;
+pub mod merkle;
 mod milestone_verification;
 #[cfg(all(test, feature = "legacy-tests"))]
 mod milestone_verification_test;
@@ -14,13 +22,20 @@ mod milestone_verification_test;
 mod error_code_stability_test;
 mod mint;
 mod pagination;
+mod pool;
 mod proposal_state_machine;
+mod snapshot;
+mod staking;
 mod storage;
+mod streaming;
 mod stream_types;
+mod symbol_registry;
 #[cfg(test)]
 mod test_helpers;
 mod timelock;
 mod token_creation;
+mod token_metadata;
+mod transfer;
 mod treasury;
 mod types;
 mod vesting;
@@ -44,11 +59,17 @@ mod stream_claim_differential_test;
 
 use soroban_sdk::{contract, contractimpl, symbol_short, Address, Bytes, BytesN, Env, String, Vec};
 use types::{
-    ContractMetadata, Error, FactoryState, PaginationCursor, StreamInfo, StreamPage, StreamParams,
-    TokenCreationParams, TokenInfo, TokenStats, Vault, VaultStatus,
+    ContractMetadata, Error, FactoryState, PaginationCursor, PendingAdminOffer, StreamInfo,
+    StreamInfoPage, StreamPage, StreamParams, StreamStatus, TokenCreationParams, TokenInfo,
+    TokenStats, Vault, VaultStatus,
 };
 use crate::milestone_verification::MilestoneVerifier;
 
+/// Validity window of a two-step admin handover offer in seconds (7 days)
+///
+/// Independent of the timelock's change expiry window.
+const ADMIN_PROPOSAL_TTL: u64 = 604_800;
+
 #[contract]
 pub struct TokenFactory;
 
@@ -227,7 +248,8 @@ impl TokenFactory {
     ///
     /// Initiates a two-step admin transfer by proposing a new admin.
     /// Only one pending proposal can exist at a time - new proposals overwrite old ones.
-    /// The proposed admin must call `accept_admin` to complete the transfer.
+    /// The proposed admin must call `accept_admin` to complete the transfer
+    /// within `ADMIN_PROPOSAL_TTL` seconds, after which the offer expires.
     ///
     /// # Arguments
     /// * `env` - The contract environment
@@ -257,7 +279,19 @@ impl TokenFactory {
         }
 
         // Overwrite any existing pending admin (prevents stale proposals)
-        storage::set_pending_admin(&env, &new_admin);
+        let proposed_at = env.ledger().timestamp();
+        let expires_at = proposed_at
+            .checked_add(ADMIN_PROPOSAL_TTL)
+            .ok_or(Error::ArithmeticError)?;
+        storage::set_pending_admin(
+            &env,
+            &PendingAdminOffer {
+                proposed_admin: new_admin.clone(),
+                proposed_by: current_admin.clone(),
+                proposed_at,
+                expires_at,
+            },
+        );
 
         events::emit_admin_proposed(&env, &current_admin, &new_admin);
 
@@ -277,18 +311,28 @@ impl TokenFactory {
     /// Returns `Ok(())` on success
     ///
     /// # Errors
-    /// * `Unauthorized` - If caller is not the pending admin or no pending admin exists
+    /// * `Unauthorized` - If caller is not the pending admin, no pending admin exists,
+    ///   the offer has expired, or the admin who made the offer is no longer admin
     pub fn accept_admin(env: Env, new_admin: Address) -> Result<(), Error> {
         new_admin.require_auth();
 
         let pending = storage::get_pending_admin(&env).ok_or(Error::Unauthorized)?;
 
-        if new_admin != pending {
+        if new_admin != pending.proposed_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        if env.ledger().timestamp() > pending.expires_at {
             return Err(Error::Unauthorized);
         }
 
         let old_admin = storage::get_admin(&env);
 
+        // Offers made by a previous admin are void
+        if pending.proposed_by != old_admin {
+            return Err(Error::Unauthorized);
+        }
+
         // Update admin and clear pending in single operation
         storage::set_admin(&env, &new_admin);
         storage::clear_pending_admin(&env);
@@ -298,11 +342,68 @@ impl TokenFactory {
         Ok(())
     }
 
+    /// Cancel the pending admin proposal (admin only)
+    ///
+    /// Withdraws an outstanding handover offer before it is accepted.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `current_admin` - Current admin address (must authorize)
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If caller is not the current admin
+    /// * `InvalidParameters` - If there is no pending proposal
+    pub fn cancel_admin_proposal(env: Env, current_admin: Address) -> Result<(), Error> {
+        current_admin.require_auth();
+
+        if current_admin != storage::get_admin(&env) {
+            return Err(Error::Unauthorized);
+        }
+
+        let pending = storage::get_pending_admin(&env).ok_or(Error::InvalidParameters)?;
+        storage::clear_pending_admin(&env);
+
+        events::emit_admin_proposal_cancelled(&env, &current_admin, &pending.proposed_admin);
+
+        Ok(())
+    }
+
+    /// Clear an expired admin proposal
+    ///
+    /// Removes a handover offer whose deadline has passed and emits an
+    /// expiry event. Anyone can call this.
+    ///
+    /// # Errors
+    /// * `InvalidParameters` - If there is no pending proposal
+    /// * `InvalidStateTransition` - If the offer has not expired yet
+    pub fn expire_admin_proposal(env: Env) -> Result<(), Error> {
+        let pending = storage::get_pending_admin(&env).ok_or(Error::InvalidParameters)?;
+
+        if env.ledger().timestamp() <= pending.expires_at {
+            return Err(Error::InvalidStateTransition);
+        }
+
+        storage::clear_pending_admin(&env);
+
+        events::emit_admin_proposal_expired(&env, &pending.proposed_admin, pending.expires_at);
+
+        Ok(())
+    }
+
+    /// Get the pending admin proposal, if any
+    ///
+    /// Returns the offer including its `expires_at` deadline. An expired
+    /// offer is still returned until `expire_admin_proposal` clears it.
+    pub fn get_pending_admin(env: Env) -> Option<PendingAdminOffer> {
+        storage::get_pending_admin(&env)
+    }
+
     /// Pause the contract (admin only)
     ///
     /// Halts critical operations like token creation and metadata updates.
     /// Admin functions like fee updates remain operational during pause.
     /// This is a safety mechanism for emergency situations.
+    /// Equivalent to `set_pause_flags` with `PAUSE_ALL`.
     ///
     /// # Arguments
     /// * `env` - The contract environment
@@ -396,6 +497,50 @@ impl TokenFactory {
         storage::is_paused(&env)
     }
 
+    /// Set per-operation pause flags (admin only)
+    ///
+    /// Replaces the pause bitmask so individual operations can be halted
+    /// without stopping the rest of the factory. See the `PAUSE_*`
+    /// constants in `types` for the available flags.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must authorize and match stored admin)
+    /// * `flags` - New bitmask; 0 resumes everything
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::InvalidParameters` - Bitmask contains unknown flags
+    ///
+    /// # Examples
+    /// ```
+    /// // Halt vault claims and cancellations only
+    /// factory.set_pause_flags(&env, admin, PAUSE_VAULT_CLAIM | PAUSE_VAULT_CANCEL)?;
+    /// ```
+    pub fn set_pause_flags(env: Env, admin: Address, flags: u32) -> Result<(), Error> {
+        admin.require_auth();
+
+        let current_admin = storage::get_admin(&env);
+        if admin != current_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        if flags & !types::PAUSE_ALL != 0 {
+            return Err(Error::InvalidParameters);
+        }
+
+        storage::set_pause_flags(&env, flags);
+
+        events::emit_pause_flags_updated(&env, &admin, flags);
+
+        Ok(())
+    }
+
+    /// Get the current per-operation pause bitmask
+    pub fn get_pause_flags(env: Env) -> u32 {
+        storage::get_pause_flags(&env)
+    }
+
     /// Update fee structure (admin only)
     ///
     /// Allows the admin to update either or both deployment fees.
@@ -472,6 +617,7 @@ impl TokenFactory {
     pub fn get_token_info(env: Env, index: u32) -> Result<TokenInfo, Error> {
         let mut info = storage::get_token_info(&env, index).ok_or(Error::TokenNotFound)?;
         info.is_paused = storage::is_token_paused(&env, index);
+        info.clawback_enabled = clawback::is_active(&env, index);
         Ok(info)
     }
 
@@ -584,7 +730,9 @@ impl TokenFactory {
     /// assert_eq!(token.creator, expected_creator);
     /// ```
     pub fn get_token_info_by_address(env: Env, token_address: Address) -> Result<TokenInfo, Error> {
-        storage::get_token_info_by_address(&env, &token_address).ok_or(Error::TokenNotFound)
+        let index = storage::get_token_index_by_address(&env, &token_address)
+            .ok_or(Error::TokenNotFound)?;
+        Self::get_token_info(env, index)
     }
 
     /// * `symbol` - Token symbol
@@ -594,8 +742,10 @@ impl TokenFactory {
     /// Toggle clawback capability for a token (creator only)
     ///
     /// Allows the token creator to enable or disable clawback functionality.
-    /// When enabled, the creator can burn tokens from any holder's address.
-    /// This setting can be toggled multiple times by the creator.
+    /// While clawback is active, the admin can burn tokens from any holder's
+    /// address with `admin_burn` or `batch_burn`. If a notice period is
+    /// configured, enabling only announces clawback and it becomes active
+    /// once the notice has elapsed. Disabling takes effect immediately.
     ///
     /// # Arguments
     /// * `env` - The contract environment
@@ -607,7 +757,7 @@ impl TokenFactory {
     /// Returns `Ok(())` on success
     ///
     /// # Errors
-    /// * `Error::ContractPaused` - Contract is currently paused
+    /// * `Error::ContractPaused` - Token controls are paused
     /// * `Error::TokenNotFound` - Token address not found
     /// * `Error::Unauthorized` - Caller is not the token creator
     ///
@@ -625,30 +775,60 @@ impl TokenFactory {
         admin: Address,
         enabled: bool,
     ) -> Result<(), Error> {
-        // Early return if contract is paused (Phase 1 optimization)
-        if storage::is_paused(&env) {
-            return Err(Error::ContractPaused);
-        }
+        clawback::set_clawback(&env, &token_address, &admin, enabled)
+    }
 
-        // Require admin authorization
-        admin.require_auth();
+    /// Set the clawback notice period (admin only)
+    ///
+    /// Applies to clawback enablements made after the change; tokens that
+    /// already announced clawback keep their activation time.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Factory admin (must authorize)
+    /// * `seconds` - Delay between enabling and activating clawback (max 30 days)
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::InvalidParameters` - Notice period exceeds 30 days
+    ///
+    /// # Examples
+    /// ```
+    /// // Holders get a week's notice before clawback can be used
+    /// factory.set_clawback_notice_period(&admin, &604_800);
+    /// ```
+    pub fn set_clawback_notice_period(env: Env, admin: Address, seconds: u64) -> Result<(), Error> {
+        clawback::set_notice_period(&env, &admin, seconds)
+    }
 
-        // Get token info
-        let mut token_info =
-            storage::get_token_info_by_address(&env, &token_address).ok_or(Error::TokenNotFound)?;
+    /// Get the clawback notice period in seconds (0 when not configured)
+    pub fn get_clawback_notice_period(env: Env) -> u64 {
+        storage::get_clawback_notice_period(&env)
+    }
 
-        // Verify admin is the token creator
-        if token_info.creator != admin {
-            return Err(Error::Unauthorized);
-        }
+    /// Get the time from which clawback is active for a token
+    ///
+    /// Returns `None` while clawback is disabled. A value in the future
+    /// means clawback has been announced but is still in its notice period.
+    pub fn get_clawback_active_from(env: Env, token_index: u32) -> Option<u64> {
+        storage::get_clawback_active_from(&env, token_index)
+    }
 
-        // Update clawback setting
-        token_info.clawback_enabled = enabled;
-        storage::set_token_info_by_address(&env, &token_address, &token_info);
+    /// Whether `admin_burn` and `batch_burn` can currently claw back a token
+    pub fn is_clawback_active(env: Env, token_index: u32) -> bool {
+        clawback::is_active(&env, token_index)
+    }
 
-        // Emit optimized event
-        events::emit_clawback_toggled(&env, &token_address, &admin, enabled);
-        Ok(())
+    /// Return a page of a token's clawback log, oldest first
+    /// cursor: starting record number (0 for first page)
+    /// limit: max records to return (capped at 100)
+    pub fn get_clawback_log(
+        env: Env,
+        token_index: u32,
+        cursor: u32,
+        limit: u32,
+    ) -> types::ClawbackPage {
+        pagination::get_clawback_log(&env, token_index, cursor, limit)
     }
 
     /// Burn tokens from caller's own balance
@@ -690,6 +870,7 @@ impl TokenFactory {
     /// * `admin` - Admin address (must authorize and match stored admin)
     /// * `token_index` - Index of the token to burn
     /// * `burns` - Vector of (holder_address, amount) tuples (max 100 entries)
+    /// * `reason_code` - Reason recorded in the clawback log for every entry
     ///
     /// # Returns
     /// Returns `Ok(())` on success
@@ -697,7 +878,8 @@ impl TokenFactory {
     /// # Errors
     /// * `Error::Unauthorized` - Caller is not the admin
     /// * `Error::BatchTooLarge` - More than 100 burn entries
-    /// * `Error::InvalidParameters` - Empty batch or invalid amounts
+    /// * `Error::InvalidParameters` - Empty batch, invalid amounts or reason code 0
+    /// * `Error::ClawbackDisabled` - Clawback is disabled or still in its notice period
     /// * `Error::TokenNotFound` - Token index is invalid
     /// * `Error::InsufficientBalance` - Any holder has insufficient balance
     /// * `Error::ArithmeticError` - Numeric overflow/underflow
@@ -709,15 +891,16 @@ impl TokenFactory {
     ///     (holder1, 1_000_0000000),
     ///     (holder2, 2_000_0000000),
     /// ];
-    /// factory.batch_burn(&env, admin, 0, burns)?;
+    /// factory.batch_burn(&env, admin, 0, burns, 7)?;
     /// ```
     pub fn batch_burn(
         env: Env,
         admin: Address,
         token_index: u32,
         burns: soroban_sdk::Vec<(Address, i128)>,
+        reason_code: u32,
     ) -> Result<(), Error> {
-        burn::batch_burn(&env, admin, token_index, burns)
+        burn::batch_burn(&env, admin, token_index, burns, reason_code)
     }
 
     /// Get the total number of burn operations for a token
@@ -743,8 +926,10 @@ impl TokenFactory {
 
     /// Admin-initiated burn from any holder's balance
     ///
-    /// Allows the admin to burn tokens from any holder's address.
-    /// This is a privileged operation that requires admin authentication.
+    /// Allows the admin to burn tokens from any holder's address once
+    /// clawback is active for the token. This is a privileged operation
+    /// that requires admin authentication. The burn is recorded in the
+    /// token's clawback log.
     ///
     /// # Arguments
     /// * `env` - The contract environment
@@ -752,6 +937,7 @@ impl TokenFactory {
     /// * `token_index` - Index of the token to burn
     /// * `holder` - Address holding the tokens to burn
     /// * `amount` - Amount to burn (must be > 0 and <= holder's balance)
+    /// * `reason_code` - Reason recorded in the clawback log (must be non-zero)
     ///
     /// # Returns
     /// Returns `Ok(())` on success
@@ -759,14 +945,15 @@ impl TokenFactory {
     /// # Errors
     /// * `Error::Unauthorized` - Caller is not the admin
     /// * `Error::TokenNotFound` - Token index is invalid
-    /// * `Error::InvalidParameters` - Amount is zero or negative
+    /// * `Error::InvalidParameters` - Amount is zero or negative, or reason code is 0
+    /// * `Error::ClawbackDisabled` - Clawback is disabled or still in its notice period
     /// * `Error::InsufficientBalance` - Holder balance is less than amount
     /// * `Error::ArithmeticError` - Numeric overflow/underflow
     ///
     /// # Examples
     /// ```
     /// // Admin burns 1000 tokens from a holder
-    /// factory.admin_burn(&env, admin, 0, holder, 1_000_0000000)?;
+    /// factory.admin_burn(&env, admin, 0, holder, 1_000_0000000, 7)?;
     /// ```
     pub fn admin_burn(
         env: Env,
@@ -774,8 +961,9 @@ impl TokenFactory {
         token_index: u32,
         holder: Address,
         amount: i128,
+        reason_code: u32,
     ) -> Result<(), Error> {
-        burn::admin_burn(&env, admin, token_index, holder, amount)
+        burn::admin_burn(&env, admin, token_index, holder, amount, reason_code)
     }
 
     /// Set metadata URI for a token (one-time only)
@@ -826,7 +1014,9 @@ impl TokenFactory {
 
     /// Set metadata for a token
     /// 
-    /// Allows the token creator to set metadata URI once
+    /// Allows the token creator to set metadata URI once. To correct or
+    /// replace a URI afterwards, publish a new version with
+    /// `update_token_metadata`.
     pub fn set_token_metadata(
         env: Env,
         admin: Address,
@@ -863,6 +1053,71 @@ impl TokenFactory {
         Ok(())
     }
 
+    /// Publish a new structured metadata version for a token (creator only)
+    ///
+    /// Each update must carry `version == current + 1` (1 for the first
+    /// update). The content hash of every version is kept so indexers can
+    /// verify past metadata, and the token's `metadata_uri` follows the
+    /// latest version.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `creator` - Token creator address (must authorize)
+    /// * `token_index` - Index of the token to update
+    /// * `metadata` - New metadata (description, website, logo hash, socials, URI)
+    /// * `fee_payment` - Fee paid (must be >= metadata_fee)
+    ///
+    /// # Returns
+    /// The content hash recorded for the new version
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Token index is invalid
+    /// * `Error::Unauthorized` - Caller is not the token creator
+    /// * `Error::InsufficientFee` - Fee payment below metadata_fee
+    /// * `Error::InvalidParameters` - Field too long, too many socials, or version is not current + 1
+    ///
+    /// # Examples
+    /// ```
+    /// let metadata = TokenMetadata { version: 1, /* ... */ };
+    /// let hash = factory.update_token_metadata(&creator, &0, &metadata, &50_000_000);
+    /// ```
+    pub fn update_token_metadata(
+        env: Env,
+        creator: Address,
+        token_index: u32,
+        metadata: types::TokenMetadata,
+        fee_payment: i128,
+    ) -> Result<BytesN<32>, Error> {
+        token_metadata::update_token_metadata(&env, &creator, token_index, &metadata, fee_payment)
+    }
+
+    /// Get the latest structured metadata of a token, if any
+    pub fn get_token_metadata(env: Env, token_index: u32) -> Option<types::TokenMetadata> {
+        storage::get_token_metadata(&env, token_index)
+    }
+
+    /// Get the record of a single metadata version
+    pub fn get_metadata_version(
+        env: Env,
+        token_index: u32,
+        version: u32,
+    ) -> Option<types::MetadataVersion> {
+        storage::get_metadata_version(&env, token_index, version)
+    }
+
+    /// List metadata versions of a token, oldest first
+    ///
+    /// Starts at version `cursor` (versions start at 1) and returns at most
+    /// `limit` records (capped at 50).
+    pub fn get_metadata_history(
+        env: Env,
+        token_index: u32,
+        cursor: u32,
+        limit: u32,
+    ) -> Vec<types::MetadataVersion> {
+        token_metadata::get_metadata_history(&env, token_index, cursor, limit)
+    }
+
     pub fn pause_token(env: Env, admin: Address, token_index: u32) -> Result<(), Error> {
         admin.require_auth();
         if admin != storage::get_admin(&env) {
@@ -898,12 +1153,25 @@ impl TokenFactory {
             total_burned: storage::get_total_burned(&env, token_index),
             burn_count: storage::get_burn_count(&env, token_index),
             is_paused: storage::is_token_paused(&env, token_index),
-            clawback_enabled: false,
+            clawback_enabled: clawback::is_active(&env, token_index),
             freeze_enabled: false,
+            total_fees_collected: storage::get_fees_collected(&env, token_index),
+            holder_count: storage::get_holder_count(&env, token_index),
         })
     }
 
-    /// Return a paginated list of token indices where beneficiary is the creator.
+    /// Return a paginated list of holders of a token with their balances.
+    /// cursor: starting holder position (0 for first page)
+    /// limit: max holders to return (capped at 100)
+    ///
+    /// Only addresses with a non-zero balance are listed. Positions shift
+    /// when a holder's balance drops to zero, so pages read across balance
+    /// changes may skip or repeat an entry.
+    pub fn get_holders(env: Env, token_index: u32, cursor: u32, limit: u32) -> types::HolderPage {
+        pagination::get_holders(&env, token_index, cursor, limit)
+    }
+
+    /// Return a paginated list of the ids of streams paying `beneficiary`.
     /// cursor: starting entry index (0 for first page)
     /// limit: max entries to return (capped at 50)
     pub fn get_streams_by_beneficiary(
@@ -912,26 +1180,52 @@ impl TokenFactory {
         cursor: u32,
         limit: u32,
     ) -> StreamPage {
-        let limit = limit.min(50);
-        let total = storage::get_beneficiary_stream_count(&env, &beneficiary);
-
-        let mut token_indices = soroban_sdk::Vec::new(&env);
-        let mut i = cursor;
+        pagination::get_stream_ids_by_recipient(&env, &beneficiary, cursor, limit)
+    }
 
-        while i < total && (i - cursor) < limit {
-            if let Some(token_index) = storage::get_beneficiary_stream_entry(&env, &beneficiary, i)
-            {
-                token_indices.push_back(token_index);
-            }
-            i += 1;
-        }
+    /// Return a page of streams paying `recipient`.
+    /// status: only list streams in this state (None for all)
+    /// cursor: starting index position (0 for first page)
+    /// limit: max positions to read (capped at 100)
+    ///
+    /// Positions shift when a stream is transferred away, so pages read
+    /// across transfers may skip or repeat a stream.
+    pub fn get_streams_by_recipient(
+        env: Env,
+        recipient: Address,
+        status: Option<StreamStatus>,
+        cursor: u32,
+        limit: u32,
+    ) -> StreamInfoPage {
+        pagination::get_streams_by_recipient(&env, &recipient, status, cursor, limit)
+    }
 
-        let next_cursor = if i < total { Some(i) } else { None };
+    /// Return a page of streams created by `sender`, oldest first.
+    /// status: only list streams in this state (None for all)
+    /// cursor: starting index position (0 for first page)
+    /// limit: max positions to read (capped at 100)
+    pub fn get_streams_by_sender(
+        env: Env,
+        sender: Address,
+        status: Option<StreamStatus>,
+        cursor: u32,
+        limit: u32,
+    ) -> StreamInfoPage {
+        pagination::get_streams_by_sender(&env, &sender, status, cursor, limit)
+    }
 
-        StreamPage {
-            token_indices,
-            next_cursor,
-        }
+    /// Return a page of streams of a token, oldest first.
+    /// status: only list streams in this state (None for all)
+    /// cursor: starting index position (0 for first page)
+    /// limit: max positions to read (capped at 100)
+    pub fn get_streams_by_token(
+        env: Env,
+        token_index: u32,
+        status: Option<StreamStatus>,
+        cursor: u32,
+        limit: u32,
+    ) -> StreamInfoPage {
+        pagination::get_streams_by_token(&env, token_index, status, cursor, limit)
     }
     // ═══════════════════════════════════════════════════════════════════════
     // Timelock Functions
@@ -1040,6 +1334,7 @@ impl TokenFactory {
     /// * `Error::TokenNotFound` - Change ID not found
     /// * `Error::TimelockNotExpired` - Timelock period has not elapsed
     /// * `Error::ChangeAlreadyExecuted` - Change has already been executed
+    /// * `Error::InvalidStateTransition` - Change was cancelled or has expired
     ///
     /// # Examples
     /// ```
@@ -1067,6 +1362,7 @@ impl TokenFactory {
     /// * `Error::Unauthorized` - Caller is not the admin
     /// * `Error::TokenNotFound` - Change ID not found
     /// * `Error::ChangeAlreadyExecuted` - Change has already been executed
+    /// * `Error::InvalidStateTransition` - Change was cancelled or has expired
     ///
     /// # Examples
     /// ```
@@ -1098,6 +1394,64 @@ impl TokenFactory {
         timelock::get_pending_change(&env, change_id)
     }
 
+    /// List scheduled changes with pagination
+    ///
+    /// Returns changes in ascending id order, optionally filtered by status.
+    /// Each entry carries its `content_hash` and `expires_at` so monitoring
+    /// can alert on every queued admin change without knowing ids upfront.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `status` - Optional status filter (None = all changes)
+    /// * `cursor` - Change id to start from (0 for the first page)
+    /// * `limit` - Maximum entries to return (capped at 50)
+    ///
+    /// # Returns
+    /// Returns a `PendingChangesPage`; `next_cursor` is None once all ids
+    /// have been scanned
+    ///
+    /// # Examples
+    /// ```
+    /// let page = factory.list_pending_changes(&env, Some(ChangeStatus::Pending), 0, 20);
+    /// for change in page.changes.iter() {
+    ///     log!("change {} executes at {}", change.id, change.execute_at);
+    /// }
+    /// ```
+    pub fn list_pending_changes(
+        env: Env,
+        status: Option<types::ChangeStatus>,
+        cursor: u64,
+        limit: u32,
+    ) -> types::PendingChangesPage {
+        timelock::list_pending_changes(&env, status, cursor, limit)
+    }
+
+    /// Record the expiry of a change whose execution window has passed
+    ///
+    /// Anyone can call this to persist `ChangeStatus::Expired` and emit
+    /// the expiry event. Queries already report expired changes without it.
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Change ID not found
+    /// * `Error::TimelockNotExpired` - Execution window has not passed
+    /// * `Error::ChangeAlreadyExecuted` - Change has already been executed
+    /// * `Error::InvalidStateTransition` - Change was cancelled or already expired
+    pub fn expire_change(env: Env, change_id: u64) -> Result<(), Error> {
+        timelock::expire_change(&env, change_id)
+    }
+
+    /// Set the execution window for newly scheduled changes (admin only)
+    ///
+    /// A change may be executed from `execute_at` until
+    /// `execute_at + expiry_seconds`; afterwards it expires.
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::InvalidParameters` - Window is zero or exceeds 90 days
+    pub fn set_change_expiry(env: Env, admin: Address, expiry_seconds: u64) -> Result<(), Error> {
+        timelock::set_change_expiry(&env, &admin, expiry_seconds)
+    }
+
     /// Get timelock configuration
     ///
     /// Returns the current timelock settings including the delay period.
@@ -1200,11 +1554,12 @@ impl TokenFactory {
     ///
     /// Increases the total supply and the recipient's balance.
     /// Enforces max supply constraints if set for the token.
-    /// Only the token creator can mint new tokens.
+    /// The token creator can mint freely; delegated minters consume
+    /// their allowance (see `add_minter`).
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `creator` - Token creator address (must authorize)
+    /// * `creator` - Token creator or delegated minter address (must authorize)
     /// * `token_index` - Index of the token to mint
     /// * `to` - Address to receive the minted tokens
     /// * `amount` - Amount to mint (must be > 0)
@@ -1213,9 +1568,12 @@ impl TokenFactory {
     /// Returns `Ok(())` on success
     ///
     /// # Errors
-    /// * `Error::Unauthorized` - Caller is not the token creator
+    /// * `Error::Unauthorized` - Caller is neither the creator nor an active minter
+    /// * `Error::InsufficientBalance` - Amount exceeds the minter's allowance
+    /// * `Error::RecipientNotAllowed` - Recipient fails the token's compliance mode
     /// * `Error::TokenNotFound` - Token doesn't exist
     /// * `Error::InvalidAmount` - Amount is zero or negative
+    /// * `Error::MintingDisabled` - Supply has been finalized
     /// * `Error::MaxSupplyExceeded` - Would exceed max supply cap
     /// * `Error::ArithmeticError` - Overflow in calculation
     /// * `Error::ContractPaused` - Contract is paused
@@ -1237,24 +1595,85 @@ impl TokenFactory {
         to: Address,
         amount: i128,
     ) -> Result<(), Error> {
-        // Check if contract is paused
-        if storage::is_paused(&env) {
+        // Check if minting is paused
+        if storage::is_operation_paused(&env, types::PAUSE_MINT) {
             return Err(Error::ContractPaused);
         }
 
         creator.require_auth();
 
-        // Verify creator owns the token
+        // Verify creator owns the token, otherwise charge the minter allowance
         let token_info = storage::get_token_info(&env, token_index).ok_or(Error::TokenNotFound)?;
 
         if token_info.creator != creator {
-            return Err(Error::Unauthorized);
+            if amount <= 0 {
+                return Err(Error::InvalidAmount);
+            }
+            mint::consume_minter_allowance(&env, token_index, &creator, amount)?;
         }
 
         // Perform mint with max supply validation
         mint::mint(&env, token_index, &to, amount)
     }
 
+    /// Add a delegated minter for a token (creator only)
+    ///
+    /// Lets services such as bridges or reward distributors mint without
+    /// holding the creator key. Each mint consumes the allowance and still
+    /// respects the token's max supply.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `creator` - Token creator address (must authorize)
+    /// * `token_index` - Index of the token
+    /// * `minter` - Address to grant the minter role
+    /// * `allowance` - Maximum amount the minter may mint
+    /// * `expiry` - Timestamp after which the role lapses (0 = never)
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the token creator
+    /// * `Error::TokenNotFound` - Token doesn't exist
+    /// * `Error::InvalidAmount` - Allowance is zero or negative
+    /// * `Error::InvalidParameters` - Minter is the creator or expiry is in the past
+    ///
+    /// # Examples
+    /// ```
+    /// // Allow the bridge to mint up to 1M tokens for 30 days
+    /// factory.add_minter(&env, creator, 0, bridge, 1_000_000_0000000, now + 2_592_000)?;
+    /// ```
+    pub fn add_minter(
+        env: Env,
+        creator: Address,
+        token_index: u32,
+        minter: Address,
+        allowance: i128,
+        expiry: u64,
+    ) -> Result<(), Error> {
+        mint::add_minter(&env, &creator, token_index, &minter, allowance, expiry)
+    }
+
+    /// Remove a delegated minter (creator only)
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the token creator
+    /// * `Error::TokenNotFound` - Token doesn't exist
+    /// * `Error::InvalidParameters` - Address is not a minter
+    pub fn remove_minter(
+        env: Env,
+        creator: Address,
+        token_index: u32,
+        minter: Address,
+    ) -> Result<(), Error> {
+        mint::remove_minter(&env, &creator, token_index, &minter)
+    }
+
+    /// Get the remaining allowance of a delegated minter
+    ///
+    /// Returns 0 if the address is not a minter or its role has expired.
+    pub fn get_minter_allowance(env: Env, token_index: u32, minter: Address) -> i128 {
+        mint::get_minter_allowance(&env, token_index, &minter)
+    }
+
     /// Get remaining mintable supply for a token
     ///
     /// Returns how many more tokens can be minted before hitting the max supply.
@@ -1265,7 +1684,7 @@ impl TokenFactory {
     /// * `token_index` - Index of the token
     ///
     /// # Returns
-    /// * `Some(amount)` - Remaining mintable amount
+    /// * `Some(amount)` - Remaining mintable amount (0 once supply is finalized)
     /// * `None` - Unlimited minting (no max supply set)
     ///
     /// # Examples
@@ -1280,40 +1699,772 @@ impl TokenFactory {
         mint::get_remaining_mintable(&env, token_index)
     }
 
-    // ═══════════════════════════════════════════════════════════════════════
-    // Treasury Functions
-    // ═══════════════════════════════════════════════════════════════════════
-
-    /// Initialize treasury policy
+    /// Lower the max supply cap of a token (creator only)
     ///
-    /// Sets up withdrawal limits and controls for the treasury.
-    /// Should be called during contract initialization or when first
-    /// configuring treasury protections.
+    /// The cap can only be lowered, never raised, and never below the
+    /// current total supply. Setting a cap on an uncapped token is allowed.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `admin` - Admin address (must authorize)
-    /// * `daily_cap` - Optional maximum withdrawal per day in stroops (None = default 100 XLM)
-    /// * `allowlist_enabled` - Whether to enforce recipient allowlist
-    ///
-    /// # Returns
-    /// Returns `Ok(())` on success
+    /// * `creator` - Token creator address (must authorize)
+    /// * `token_index` - Index of the token
+    /// * `max_supply` - New supply cap
     ///
     /// # Errors
-    /// * `Error::Unauthorized` - Caller is not the admin
-    /// * `Error::InvalidParameters` - Daily cap is negative
+    /// * `Error::Unauthorized` - Caller is not the token creator
+    /// * `Error::TokenNotFound` - Token doesn't exist
+    /// * `Error::MintingDisabled` - Supply has been finalized
+    /// * `Error::InvalidMaxSupply` - Cap would rise or fall below current supply
     ///
     /// # Examples
     /// ```
-    /// // 100 XLM daily cap with allowlist
-    /// factory.initialize_treasury_policy(&env, admin, Some(100_0000000), true)?;
+    /// factory.set_max_supply(&env, creator, 0, 10_000_000_0000000)?;
     /// ```
-    pub fn initialize_treasury_policy(
+    pub fn set_max_supply(
         env: Env,
-        admin: Address,
-        daily_cap: Option<i128>,
-        allowlist_enabled: bool,
-    ) -> Result<(), Error> {
+        creator: Address,
+        token_index: u32,
+        max_supply: i128,
+    ) -> Result<(), Error> {
+        mint::set_max_supply(&env, &creator, token_index, max_supply)
+    }
+
+    /// Permanently disable minting for a token (creator only)
+    ///
+    /// Fixes the max supply at the current total supply. After this call
+    /// every mint fails with `MintingDisabled` and `get_remaining_mintable`
+    /// returns `Some(0)`. This cannot be undone.
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the token creator
+    /// * `Error::TokenNotFound` - Token doesn't exist
+    /// * `Error::MintingDisabled` - Supply is already finalized
+    pub fn finalize_supply(env: Env, creator: Address, token_index: u32) -> Result<(), Error> {
+        mint::finalize_supply(&env, &creator, token_index)
+    }
+
+    /// Check whether minting has been permanently disabled for a token
+    pub fn is_supply_finalized(env: Env, token_index: u32) -> bool {
+        storage::is_supply_finalized(&env, token_index)
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // Transfer Functions
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Transfer tokens to another holder
+    ///
+    /// If the token has a transfer tax, `fee_bps` of the amount (rounded
+    /// down) goes to the fee recipient and the rest to `to`. Transfers
+    /// sent or received by exempt addresses or the fee recipient are not taxed.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `from` - Sender address (must authorize)
+    /// * `token_index` - Index of the token
+    /// * `to` - Recipient address
+    /// * `amount` - Gross amount debited from the sender (must be > 0)
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Transfers are paused
+    /// * `Error::TokenNotFound` - Token doesn't exist
+    /// * `Error::TokenPaused` - Token is paused
+    /// * `Error::InvalidAmount` - Amount is zero or negative
+    /// * `Error::RecipientNotAllowed` - Recipient fails the token's compliance mode
+    /// * `Error::InsufficientBalance` - Sender balance is less than amount
+    /// * `Error::ArithmeticError` - Overflow in calculation
+    ///
+    /// # Examples
+    /// ```
+    /// // With a 2.5% tax, `to` receives 975 and the fee recipient 25
+    /// factory.transfer(&env, from, 0, to, 1_000)?;
+    /// ```
+    pub fn transfer(
+        env: Env,
+        from: Address,
+        token_index: u32,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        transfer::transfer(&env, &from, token_index, &to, amount)
+    }
+
+    /// Allow `spender` to transfer up to `amount` of the owner's tokens
+    ///
+    /// Replaces any existing allowance; an amount of 0 revokes it.
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Token doesn't exist
+    /// * `Error::InvalidAmount` - Amount is negative
+    pub fn approve(
+        env: Env,
+        owner: Address,
+        token_index: u32,
+        spender: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        transfer::approve(&env, &owner, token_index, &spender, amount)
+    }
+
+    /// Transfer tokens on behalf of `from` using an allowance
+    ///
+    /// The allowance is reduced by the gross amount. Transfer tax applies
+    /// as for `transfer`.
+    ///
+    /// # Errors
+    /// Same as `transfer`, plus:
+    /// * `Error::InsufficientBalance` - Allowance is less than amount
+    pub fn transfer_from(
+        env: Env,
+        spender: Address,
+        token_index: u32,
+        from: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        transfer::transfer_from(&env, &spender, token_index, &from, &to, amount)
+    }
+
+    /// Get the remaining allowance of `spender` over `owner`'s tokens
+    pub fn get_allowance(env: Env, token_index: u32, owner: Address, spender: Address) -> i128 {
+        storage::get_allowance(&env, token_index, &owner, &spender)
+    }
+
+    /// Get a holder's balance of a token
+    pub fn get_balance(env: Env, token_index: u32, holder: Address) -> i128 {
+        storage::get_balance(&env, token_index, &holder)
+    }
+
+    /// Get the transfer tax configuration of a token, if any
+    pub fn get_transfer_fee(env: Env, token_index: u32) -> Option<types::TransferFeeConfig> {
+        storage::get_transfer_fee(&env, token_index)
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // Snapshot Functions
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Take a snapshot of a token's balances and total supply
+    ///
+    /// Callable by the token creator or the admin. Snapshots are cheap:
+    /// balances are checkpointed lazily the first time they change
+    /// afterwards. Query them with `balance_of_at` and `total_supply_at`.
+    ///
+    /// # Returns
+    /// The new snapshot id (ids start at 1 for each token)
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Token index is invalid
+    /// * `Error::Unauthorized` - Caller is neither the token creator nor the admin
+    ///
+    /// # Examples
+    /// ```
+    /// let id = factory.snapshot(&creator, &0);
+    /// let weight = factory.balance_of_at(&0, &voter, &id);
+    /// ```
+    pub fn snapshot(env: Env, caller: Address, token_index: u32) -> Result<u32, Error> {
+        snapshot::snapshot(&env, &caller, token_index)
+    }
+
+    /// Get the latest snapshot id of a token (0 if none taken)
+    pub fn get_current_snapshot_id(env: Env, token_index: u32) -> u32 {
+        storage::get_snapshot_id(&env, token_index)
+    }
+
+    /// Get a holder's balance at a snapshot
+    ///
+    /// # Errors
+    /// * `Error::InvalidParameters` - Snapshot id is 0 or has not been taken
+    pub fn balance_of_at(
+        env: Env,
+        token_index: u32,
+        holder: Address,
+        snapshot_id: u32,
+    ) -> Result<i128, Error> {
+        snapshot::balance_of_at(&env, token_index, &holder, snapshot_id)
+    }
+
+    /// Get a token's total supply at a snapshot
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Token index is invalid
+    /// * `Error::InvalidParameters` - Snapshot id is 0 or has not been taken
+    pub fn total_supply_at(env: Env, token_index: u32, snapshot_id: u32) -> Result<i128, Error> {
+        snapshot::total_supply_at(&env, token_index, snapshot_id)
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // Distribution Functions
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Deposit a SEP-41 asset for pro-rata distribution to a token's holders
+    ///
+    /// Takes a balance snapshot of the token and transfers `amount` of
+    /// `asset` from the creator into the factory. Each holder at the
+    /// snapshot can claim `amount * balance_at / supply_at` (rounded down)
+    /// once before `claim_deadline`; the creator can then sweep the rest.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `creator` - Token creator address (must authorize)
+    /// * `token_index` - Index of the token whose holders are paid
+    /// * `asset` - SEP-41 asset to distribute
+    /// * `amount` - Amount of `asset` to deposit
+    /// * `claim_deadline` - Timestamp after which claims close
+    ///
+    /// # Returns
+    /// The new distribution id
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Transfers are paused
+    /// * `Error::TokenNotFound` - Token index is invalid
+    /// * `Error::Unauthorized` - Caller is not the token creator
+    /// * `Error::InvalidAmount` - Amount is zero or negative
+    /// * `Error::InvalidTimeWindow` - Deadline is not in the future
+    /// * `Error::InvalidParameters` - Token has no supply
+    ///
+    /// # Examples
+    /// ```
+    /// let id = factory.deposit_distribution(&creator, &0, &usdc, &1_000_0000000, &deadline);
+    /// let share = factory.claim_distribution(&holder, &id);
+    /// ```
+    pub fn deposit_distribution(
+        env: Env,
+        creator: Address,
+        token_index: u32,
+        asset: Address,
+        amount: i128,
+        claim_deadline: u64,
+    ) -> Result<u32, Error> {
+        distribution::deposit(&env, &creator, token_index, &asset, amount, claim_deadline)
+    }
+
+    /// Claim a holder's share of a distribution
+    ///
+    /// # Returns
+    /// The amount of the distributed asset transferred to the holder
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Transfers are paused
+    /// * `Error::InvalidParameters` - Distribution doesn't exist
+    /// * `Error::InvalidTimeWindow` - Claim deadline has passed
+    /// * `Error::NothingToClaim` - Already claimed or no balance at the snapshot
+    pub fn claim_distribution(env: Env, holder: Address, distribution_id: u32) -> Result<i128, Error> {
+        distribution::claim(&env, &holder, distribution_id)
+    }
+
+    /// Return the unclaimed remainder of a distribution to its creator
+    ///
+    /// # Errors
+    /// * `Error::InvalidParameters` - Distribution doesn't exist
+    /// * `Error::Unauthorized` - Caller is not the distribution creator
+    /// * `Error::InvalidStateTransition` - Deadline not reached or already swept
+    pub fn sweep_distribution(env: Env, creator: Address, distribution_id: u32) -> Result<i128, Error> {
+        distribution::sweep(&env, &creator, distribution_id)
+    }
+
+    /// Get a distribution
+    pub fn get_distribution(env: Env, distribution_id: u32) -> Option<types::Distribution> {
+        storage::get_distribution(&env, distribution_id)
+    }
+
+    /// Get the share a holder can still claim from a distribution
+    ///
+    /// # Errors
+    /// * `Error::InvalidParameters` - Distribution doesn't exist
+    pub fn get_claimable_distribution(
+        env: Env,
+        distribution_id: u32,
+        holder: Address,
+    ) -> Result<i128, Error> {
+        distribution::claimable(&env, distribution_id, &holder)
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // Staking Functions
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Create a staking pool for a token (token creator only)
+    ///
+    /// Rewards are paid in `reward_token_index`, which may be the staked
+    /// token itself. Each stake is locked for `lockup_seconds`; unstaking
+    /// earlier pays `early_exit_penalty_bps` of the amount to the creator.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `creator` - Creator of the staked token (must authorize)
+    /// * `token_index` - Token to stake
+    /// * `reward_token_index` - Token rewards are paid in
+    /// * `lockup_seconds` - Lockup from each stake (0 for none)
+    /// * `early_exit_penalty_bps` - Penalty for unstaking during lockup (max 5000)
+    ///
+    /// # Returns
+    /// The new pool id
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Staked or reward token doesn't exist
+    /// * `Error::Unauthorized` - Caller is not the staked token's creator
+    /// * `Error::InvalidParameters` - Penalty above 50%
+    ///
+    /// # Examples
+    /// ```
+    /// let pool = factory.create_staking_pool(&creator, &0, &0, &604_800, &1_000);
+    /// factory.fund_staking_rewards(&creator, &pool, &1_000_000, &2_592_000);
+    /// factory.stake(&holder, &pool, &5_000);
+    /// ```
+    pub fn create_staking_pool(
+        env: Env,
+        creator: Address,
+        token_index: u32,
+        reward_token_index: u32,
+        lockup_seconds: u64,
+        early_exit_penalty_bps: u32,
+    ) -> Result<u32, Error> {
+        staking::create_pool(
+            &env,
+            &creator,
+            token_index,
+            reward_token_index,
+            lockup_seconds,
+            early_exit_penalty_bps,
+        )
+    }
+
+    /// Fund a reward period of a staking pool (pool creator only)
+    ///
+    /// Emits `amount` (plus any leftover of a running period) evenly over
+    /// `duration` seconds from now.
+    ///
+    /// # Errors
+    /// * `Error::InvalidParameters` - Pool doesn't exist or duration is 0
+    /// * `Error::Unauthorized` - Caller is not the pool creator
+    /// * `Error::InvalidAmount` - Amount is not positive or below one unit per second
+    /// * `Error::InsufficientBalance` - Transferable reward token balance too low
+    pub fn fund_staking_rewards(
+        env: Env,
+        creator: Address,
+        pool_id: u32,
+        amount: i128,
+        duration: u64,
+    ) -> Result<(), Error> {
+        staking::fund_rewards(&env, &creator, pool_id, amount, duration)
+    }
+
+    /// Stake tokens into a pool
+    ///
+    /// Staked tokens stay in the holder's balance but cannot be
+    /// transferred or burned until unstaked.
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Transfers are paused
+    /// * `Error::InvalidParameters` - Pool doesn't exist
+    /// * `Error::TokenPaused` - Staked token is paused
+    /// * `Error::InvalidAmount` - Amount is zero or negative
+    /// * `Error::InsufficientBalance` - Transferable balance is less than amount
+    pub fn stake(env: Env, staker: Address, pool_id: u32, amount: i128) -> Result<(), Error> {
+        staking::stake(&env, &staker, pool_id, amount)
+    }
+
+    /// Unstake tokens from a pool
+    ///
+    /// # Returns
+    /// The early-exit penalty charged (0 after the lockup)
+    ///
+    /// # Errors
+    /// * `Error::InvalidParameters` - Pool doesn't exist
+    /// * `Error::InvalidAmount` - Amount is zero or negative
+    /// * `Error::InsufficientBalance` - Amount exceeds the staked position
+    pub fn unstake(env: Env, staker: Address, pool_id: u32, amount: i128) -> Result<i128, Error> {
+        staking::unstake(&env, &staker, pool_id, amount)
+    }
+
+    /// Unstake a whole position without settling its rewards
+    ///
+    /// For when reward accrual fails; unclaimed rewards are forfeited.
+    ///
+    /// # Returns
+    /// The amount unstaked
+    ///
+    /// # Errors
+    /// * `Error::InvalidParameters` - Pool doesn't exist
+    /// * `Error::NothingToClaim` - Nothing staked in the pool
+    pub fn emergency_unstake(env: Env, staker: Address, pool_id: u32) -> Result<i128, Error> {
+        staking::emergency_unstake(&env, &staker, pool_id)
+    }
+
+    /// Claim accrued staking rewards
+    ///
+    /// # Returns
+    /// The reward amount credited
+    ///
+    /// # Errors
+    /// * `Error::InvalidParameters` - Pool doesn't exist
+    /// * `Error::NothingToClaim` - No rewards accrued
+    /// * `Error::RecipientNotAllowed` - Staker fails the reward token's compliance mode
+    pub fn claim_rewards(env: Env, staker: Address, pool_id: u32) -> Result<i128, Error> {
+        staking::claim_rewards(&env, &staker, pool_id)
+    }
+
+    /// Recover staking rewards never allocated to stakers (pool creator only)
+    ///
+    /// Returns emission from periods with nothing staked and the rounding
+    /// remainder of each funding once the reward period is over.
+    ///
+    /// # Returns
+    /// The amount returned to the creator
+    ///
+    /// # Errors
+    /// * `Error::InvalidParameters` - Pool doesn't exist
+    /// * `Error::Unauthorized` - Caller is not the pool creator
+    /// * `Error::InvalidStateTransition` - Reward period still running
+    /// * `Error::NothingToClaim` - Every funded reward is owed to stakers
+    pub fn recover_unallocated_rewards(
+        env: Env,
+        creator: Address,
+        pool_id: u32,
+    ) -> Result<i128, Error> {
+        staking::recover_unallocated_rewards(&env, &creator, pool_id)
+    }
+
+    /// Get a staking pool with its accumulator brought up to date
+    ///
+    /// `reward_rate`, `period_finish` and `total_staked` are the inputs
+    /// for APR estimates: `reward_rate * seconds_per_year / total_staked`.
+    ///
+    /// # Errors
+    /// * `Error::InvalidParameters` - Pool doesn't exist
+    pub fn get_staking_pool(env: Env, pool_id: u32) -> Result<types::StakingPool, Error> {
+        staking::get_pool(&env, pool_id)
+    }
+
+    /// Get a staker's position in a pool, if any
+    pub fn get_stake(env: Env, pool_id: u32, staker: Address) -> Option<types::StakePosition> {
+        storage::get_stake_position(&env, pool_id, &staker)
+    }
+
+    /// Get the rewards a staker could claim now
+    ///
+    /// # Errors
+    /// * `Error::InvalidParameters` - Pool doesn't exist
+    pub fn get_earned_rewards(env: Env, pool_id: u32, staker: Address) -> Result<i128, Error> {
+        staking::earned(&env, pool_id, &staker)
+    }
+
+    /// Get the amount of a token a holder has staked across all pools
+    pub fn get_staked_balance(env: Env, token_index: u32, holder: Address) -> i128 {
+        storage::get_staked_balance(&env, token_index, &holder)
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // Airdrop Functions
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Create a Merkle airdrop campaign (token creator only)
+    ///
+    /// Escrows `total_amount` from the creator's balance. Recipients claim
+    /// with `claim_airdrop` until `expires_at`; the creator can then reclaim
+    /// the remainder with `reclaim_airdrop`. Build the root and proofs
+    /// off-chain with `merkle::MerkleTree`.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `creator` - Token creator address (must authorize)
+    /// * `token_index` - Index of the token to distribute
+    /// * `merkle_root` - sha256 Merkle root of `(index, address, amount)` leaves
+    /// * `total_amount` - Amount to escrow for the campaign
+    /// * `expires_at` - Timestamp after which claims are closed
+    ///
+    /// # Returns
+    /// The new campaign id
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Transfers are paused
+    /// * `Error::TokenNotFound` - Token index is invalid
+    /// * `Error::Unauthorized` - Caller is not the token creator
+    /// * `Error::TokenPaused` - Token is paused
+    /// * `Error::InvalidAmount` - Amount is zero or negative
+    /// * `Error::InvalidTimeWindow` - Expiry is not in the future
+    /// * `Error::InsufficientBalance` - Creator balance is less than amount
+    ///
+    /// # Examples
+    /// ```
+    /// let tree = merkle::MerkleTree::build(&env, &recipients)?;
+    /// let id = factory.create_airdrop(&creator, &0, &tree.root(), &total, &expires_at);
+    /// ```
+    pub fn create_airdrop(
+        env: Env,
+        creator: Address,
+        token_index: u32,
+        merkle_root: BytesN<32>,
+        total_amount: i128,
+        expires_at: u64,
+    ) -> Result<u32, Error> {
+        airdrop::create_airdrop(&env, &creator, token_index, &merkle_root, total_amount, expires_at)
+    }
+
+    /// Claim an airdrop allocation with a Merkle proof
+    ///
+    /// `index`, `claimant` and `amount` must match the committed leaf.
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Transfers are paused
+    /// * `Error::InvalidParameters` - Campaign doesn't exist
+    /// * `Error::TokenPaused` - Token is paused
+    /// * `Error::InvalidTimeWindow` - Campaign has expired
+    /// * `Error::InvalidAmount` - Amount is zero or negative
+    /// * `Error::NothingToClaim` - Leaf already claimed
+    /// * `Error::Unauthorized` - Proof does not match the campaign root
+    /// * `Error::RecipientNotAllowed` - Claimant fails the token's compliance mode
+    /// * `Error::InsufficientBalance` - Claim exceeds the escrowed amount
+    pub fn claim_airdrop(
+        env: Env,
+        claimant: Address,
+        campaign_id: u32,
+        index: u32,
+        amount: i128,
+        proof: Vec<BytesN<32>>,
+    ) -> Result<(), Error> {
+        airdrop::claim_airdrop(&env, &claimant, campaign_id, index, amount, &proof)
+    }
+
+    /// Return the unclaimed remainder of an expired campaign to its creator
+    ///
+    /// # Returns
+    /// The amount returned
+    ///
+    /// # Errors
+    /// * `Error::InvalidParameters` - Campaign doesn't exist
+    /// * `Error::Unauthorized` - Caller is not the campaign creator
+    /// * `Error::InvalidStateTransition` - Campaign not expired yet or already reclaimed
+    pub fn reclaim_airdrop(env: Env, creator: Address, campaign_id: u32) -> Result<i128, Error> {
+        airdrop::reclaim_airdrop(&env, &creator, campaign_id)
+    }
+
+    /// Get an airdrop campaign
+    pub fn get_airdrop(env: Env, campaign_id: u32) -> Option<types::AirdropCampaign> {
+        storage::get_airdrop(&env, campaign_id)
+    }
+
+    /// Check whether a leaf of an airdrop campaign has been claimed
+    pub fn is_airdrop_claimed(env: Env, campaign_id: u32, index: u32) -> bool {
+        storage::is_airdrop_claimed(&env, campaign_id, index)
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // Symbol Registry Functions
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Look up a token by symbol (case-insensitive)
+    ///
+    /// Returns the index of the admin-verified token for the symbol if there
+    /// is one, otherwise the first token created under it. Symbols outside
+    /// `[A-Za-z0-9]` never match.
+    ///
+    /// # Examples
+    /// ```
+    /// let index = factory.get_token_by_symbol(&String::from_str(&env, "usdc"));
+    /// ```
+    pub fn get_token_by_symbol(env: Env, symbol: String) -> Option<u32> {
+        symbol_registry::get_token_by_symbol(&env, &symbol)
+    }
+
+    /// Set the symbol uniqueness policy (admin only)
+    ///
+    /// * `AllowDuplicates` (default) - unreserved symbols may be reused
+    /// * `FirstCome` - a symbol can only be used by the first token created with it
+    ///
+    /// Applies to tokens created after the change.
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    pub fn set_symbol_policy(
+        env: Env,
+        admin: Address,
+        policy: types::SymbolPolicy,
+    ) -> Result<(), Error> {
+        symbol_registry::set_symbol_policy(&env, &admin, policy)
+    }
+
+    /// Get the current symbol uniqueness policy
+    pub fn get_symbol_policy(env: Env) -> types::SymbolPolicy {
+        storage::get_symbol_policy(&env)
+    }
+
+    /// Reserve symbols so only the admin can launch tokens with them (max 50)
+    ///
+    /// Matching is case-insensitive. Existing tokens are not affected.
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::InvalidParameters` - Symbol list is empty
+    /// * `Error::BatchTooLarge` - More than 50 symbols
+    /// * `Error::InvalidTokenParams` - A symbol is not 1-12 ASCII letters/digits
+    pub fn reserve_symbols(env: Env, admin: Address, symbols: Vec<String>) -> Result<(), Error> {
+        symbol_registry::set_symbols_reserved(&env, &admin, &symbols, true)
+    }
+
+    /// Release previously reserved symbols (max 50)
+    ///
+    /// # Errors
+    /// Same as `reserve_symbols`
+    pub fn release_symbols(env: Env, admin: Address, symbols: Vec<String>) -> Result<(), Error> {
+        symbol_registry::set_symbols_reserved(&env, &admin, &symbols, false)
+    }
+
+    /// Check whether a symbol is reserved (case-insensitive)
+    pub fn is_symbol_reserved(env: Env, symbol: String) -> bool {
+        symbol_registry::normalize_symbol(&env, &symbol)
+            .map(|normalized| storage::is_symbol_reserved(&env, &normalized))
+            .unwrap_or(false)
+    }
+
+    /// Verify or unverify a token as the canonical holder of its symbol (admin only)
+    ///
+    /// Verifying a token replaces any previously verified token with the
+    /// same symbol. `get_token_by_symbol` prefers the verified token.
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::TokenNotFound` - Token index is invalid
+    pub fn set_token_verified(
+        env: Env,
+        admin: Address,
+        token_index: u32,
+        verified: bool,
+    ) -> Result<(), Error> {
+        symbol_registry::set_token_verified(&env, &admin, token_index, verified)
+    }
+
+    /// Check whether a token is the verified holder of its symbol
+    pub fn is_token_verified(env: Env, token_index: u32) -> bool {
+        symbol_registry::is_token_verified(&env, token_index)
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // Compliance Functions
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Set the holder compliance mode of a token (creator only)
+    ///
+    /// * `Open` - anyone can receive the token
+    /// * `AllowlistOnly` - only allowlisted holders can receive it
+    /// * `DenylistOnly` - everyone except denylisted holders can receive it
+    ///
+    /// Mint, transfer, and stream/vault payouts fail with
+    /// `RecipientNotAllowed` for ineligible receivers. The token creator is
+    /// always eligible.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `creator` - Token creator address (must authorize)
+    /// * `token_index` - Index of the token
+    /// * `mode` - New compliance mode
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the token creator
+    /// * `Error::TokenNotFound` - Token doesn't exist
+    ///
+    /// # Examples
+    /// ```
+    /// factory.set_compliance_mode(&env, creator, 0, ComplianceMode::AllowlistOnly)?;
+    /// ```
+    pub fn set_compliance_mode(
+        env: Env,
+        creator: Address,
+        token_index: u32,
+        mode: types::ComplianceMode,
+    ) -> Result<(), Error> {
+        compliance::set_compliance_mode(&env, &creator, token_index, mode)
+    }
+
+    /// Get the compliance mode of a token
+    pub fn get_compliance_mode(env: Env, token_index: u32) -> types::ComplianceMode {
+        storage::get_compliance_mode(&env, token_index)
+    }
+
+    /// Add holders to a token's allowlist or denylist (creator only)
+    ///
+    /// A holder can be on only one list; adding it to one list removes
+    /// it from the other.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `creator` - Token creator address (must authorize)
+    /// * `token_index` - Index of the token
+    /// * `list` - List to add the holders to
+    /// * `holders` - Holder addresses (max 100)
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the token creator
+    /// * `Error::TokenNotFound` - Token doesn't exist
+    /// * `Error::InvalidParameters` - Holder batch is empty
+    /// * `Error::BatchTooLarge` - More than 100 holders
+    pub fn add_holders(
+        env: Env,
+        creator: Address,
+        token_index: u32,
+        list: types::HolderList,
+        holders: Vec<Address>,
+    ) -> Result<(), Error> {
+        compliance::add_holders(&env, &creator, token_index, list, &holders)
+    }
+
+    /// Remove holders from a token's allowlist or denylist (creator only)
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the token creator
+    /// * `Error::TokenNotFound` - Token doesn't exist
+    /// * `Error::InvalidParameters` - Holder batch is empty
+    /// * `Error::BatchTooLarge` - More than 100 holders
+    pub fn remove_holders(
+        env: Env,
+        creator: Address,
+        token_index: u32,
+        list: types::HolderList,
+        holders: Vec<Address>,
+    ) -> Result<(), Error> {
+        compliance::remove_holders(&env, &creator, token_index, list, &holders)
+    }
+
+    /// Check whether a holder may currently receive a token
+    pub fn is_holder_eligible(env: Env, token_index: u32, holder: Address) -> bool {
+        compliance::is_eligible(&env, token_index, &holder)
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // Treasury Functions
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Initialize treasury policy
+    ///
+    /// Sets up withdrawal limits and controls for the treasury.
+    /// Should be called during contract initialization or when first
+    /// configuring treasury protections.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must authorize)
+    /// * `daily_cap` - Optional maximum withdrawal per day in stroops (None = default 100 XLM)
+    /// * `allowlist_enabled` - Whether to enforce recipient allowlist
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::InvalidParameters` - Daily cap is negative
+    ///
+    /// # Examples
+    /// ```
+    /// // 100 XLM daily cap with allowlist
+    /// factory.initialize_treasury_policy(&env, admin, Some(100_0000000), true)?;
+    /// ```
+    pub fn initialize_treasury_policy(
+        env: Env,
+        admin: Address,
+        daily_cap: Option<i128>,
+        allowlist_enabled: bool,
+    ) -> Result<(), Error> {
         admin.require_auth();
 
         let current_admin = storage::get_admin(&env);
@@ -1501,13 +2652,357 @@ impl TokenFactory {
     ///     log!("Recipient is allowed");
     /// }
     /// ```
-    pub fn is_allowed_recipient(env: Env, recipient: Address) -> bool {
-        treasury::is_allowed_recipient(&env, &recipient)
+    pub fn is_allowed_recipient(env: Env, recipient: Address) -> bool {
+        treasury::is_allowed_recipient(&env, &recipient)
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // Stream Functions
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Create a payment stream
+    ///
+    /// Escrows `params.total_amount` from the creator's balance and releases
+    /// it to the recipient between `start_time` and `end_time` following
+    /// `params.curve`, with nothing claimable before `cliff_time`.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `creator` - Address funding the stream (must authorize)
+    /// * `params` - Recipient, token, amount and schedule
+    ///
+    /// # Returns
+    /// Returns the new stream ID
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Stream creation is paused
+    /// * `Error::InvalidAmount` - Amount is not positive
+    /// * `Error::InvalidParameters` - Start time is not before end time
+    /// * `Error::InvalidSchedule` - Cliff lies outside the stream, or the curve is malformed
+    /// * `Error::TokenNotFound` - Token does not exist
+    /// * `Error::TokenPaused` - Token is paused
+    /// * `Error::InsufficientBalance` - Creator cannot fund the stream
+    ///
+    /// # Examples
+    /// ```
+    /// let stream_id = factory.create_stream(&creator, &params)?;
+    /// ```
+    pub fn create_stream(env: Env, creator: Address, params: StreamParams) -> Result<u64, Error> {
+        streaming::create_stream(&env, &creator, &params)
+    }
+
+    /// Create several streams atomically
+    ///
+    /// Either every stream is created and funded or none is.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `creator` - Address funding the streams (must authorize)
+    /// * `streams` - Parameters for each stream
+    ///
+    /// # Returns
+    /// Returns the new stream IDs in input order
+    ///
+    /// # Errors
+    /// * `Error::BatchTooLarge` - Too many streams in one call
+    /// * `Error::InsufficientBalance` - Creator cannot fund every stream
+    /// * Any error from `create_stream` for an invalid entry
+    pub fn batch_create_streams(
+        env: Env,
+        creator: Address,
+        streams: Vec<StreamParams>,
+    ) -> Result<Vec<u64>, Error> {
+        streaming::batch_create_streams(&env, &creator, &streams)
+    }
+
+    /// Claim the vested, unclaimed amount of a stream
+    ///
+    /// Cancelled streams remain claimable up to the amount settled to the
+    /// recipient at cancellation.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `recipient` - Stream recipient (must authorize)
+    /// * `stream_id` - ID of the stream
+    ///
+    /// # Returns
+    /// Returns the amount credited to the recipient
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Stream not found
+    /// * `Error::Unauthorized` - Caller is not the recipient
+    /// * `Error::CliffNotReached` - Cliff not reached
+    /// * `Error::NothingToClaim` - Nothing claimable
+    pub fn claim_stream(env: Env, recipient: Address, stream_id: u64) -> Result<i128, Error> {
+        streaming::claim_stream(&env, &recipient, stream_id)
+    }
+
+    /// Claim from several streams atomically
+    ///
+    /// Streams with nothing claimable yield 0 rather than failing the batch.
+    ///
+    /// # Returns
+    /// Returns the claimed amount for each stream in input order
+    pub fn batch_claim_streams(
+        env: Env,
+        recipient: Address,
+        stream_ids: Vec<u64>,
+    ) -> Result<Vec<i128>, Error> {
+        streaming::batch_claim(&env, &recipient, &stream_ids)
+    }
+
+    /// Let an operator, or any keeper, push claims for `recipient`
+    ///
+    /// Covers all of the recipient's streams and vaults. Pushed claims are
+    /// always paid to the recipient. `min_interval` is the minimum number of
+    /// seconds between pushed claims on one stream or vault (0 for no
+    /// limit); the recipient's own claims are never limited. Passing no
+    /// operator with `auto_claim` off revokes the delegation.
+    ///
+    /// # Arguments
+    /// * `recipient` - Recipient delegating (must authorize)
+    /// * `operator` - Address allowed to push claims, if any
+    /// * `auto_claim` - Allow any address to push claims
+    /// * `min_interval` - Minimum seconds between pushed claims
+    ///
+    /// # Errors
+    /// * `Error::InvalidParameters` - Recipient names itself as operator
+    ///
+    /// # Examples
+    /// ```
+    /// // Weekly payroll pushed by any keeper
+    /// factory.set_claim_delegation(&employee, &None, &true, &604_800)?;
+    /// ```
+    pub fn set_claim_delegation(
+        env: Env,
+        recipient: Address,
+        operator: Option<Address>,
+        auto_claim: bool,
+        min_interval: u64,
+    ) -> Result<(), Error> {
+        keeper::set_delegation(&env, &recipient, operator, auto_claim, min_interval)
+    }
+
+    /// Return who may push claims for `recipient`, if anyone
+    pub fn get_claim_delegation(env: Env, recipient: Address) -> Option<types::ClaimDelegation> {
+        storage::get_claim_delegation(&env, &recipient)
+    }
+
+    /// Claim a stream for its recipient on their behalf
+    ///
+    /// Callable by the recipient's operator, or by anyone once the
+    /// recipient enabled auto-claim. The vested amount is paid to the
+    /// recipient, never to `keeper`.
+    ///
+    /// # Arguments
+    /// * `keeper` - Address pushing the claim (must authorize)
+    /// * `stream_id` - ID of the stream
+    ///
+    /// # Returns
+    /// Returns the amount credited to the recipient
+    ///
+    /// # Errors
+    /// * `Error::StreamNotFound` - Stream not found
+    /// * `Error::Unauthorized` - Recipient has not delegated claims to `keeper`
+    /// * `Error::InvalidTimeWindow` - Recipient's minimum claim interval not elapsed
+    /// * `Error::CliffNotReached` - Cliff not reached
+    /// * `Error::NothingToClaim` - Nothing claimable
+    pub fn push_claim(env: Env, keeper: Address, stream_id: u64) -> Result<i128, Error> {
+        streaming::push_claim(&env, &keeper, stream_id)
+    }
+
+    /// Cancel a stream (creator only)
+    ///
+    /// The amount vested but not yet claimed at the cancel timestamp stays
+    /// claimable by the recipient; the unvested remainder is returned to the
+    /// creator's balance.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `creator` - Stream creator (must authorize)
+    /// * `stream_id` - ID of the stream
+    ///
+    /// # Returns
+    /// Returns the amount refunded to the creator
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Stream not found
+    /// * `Error::Unauthorized` - Caller is not the creator
+    /// * `Error::InvalidParameters` - Stream already cancelled
+    ///
+    /// # Examples
+    /// ```
+    /// let refunded = factory.cancel_stream(&creator, &stream_id)?;
+    /// ```
+    pub fn cancel_stream(env: Env, creator: Address, stream_id: u64) -> Result<i128, Error> {
+        streaming::cancel_stream(&env, &creator, stream_id)
+    }
+
+    /// Pause a stream's vesting clock (creator only)
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Stream not found
+    /// * `Error::Unauthorized` - Caller is not the creator
+    /// * `Error::InvalidParameters` - Stream cancelled
+    /// * `Error::InvalidStateTransition` - Stream already paused
+    pub fn pause_stream(env: Env, creator: Address, stream_id: u64) -> Result<(), Error> {
+        streaming::pause_stream(&env, &creator, stream_id)
+    }
+
+    /// Resume a paused stream, shifting its schedule by the paused time
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Stream not found
+    /// * `Error::Unauthorized` - Caller is not the creator
+    /// * `Error::InvalidParameters` - Stream cancelled
+    /// * `Error::InvalidStateTransition` - Stream is not paused
+    pub fn unpause_stream(env: Env, creator: Address, stream_id: u64) -> Result<(), Error> {
+        streaming::unpause_stream(&env, &creator, stream_id)
+    }
+
+    /// Allow or forbid transferring a stream (creator only)
+    ///
+    /// Streams are transferable by default.
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Stream not found
+    /// * `Error::Unauthorized` - Caller is not the creator
+    pub fn set_stream_transferable(
+        env: Env,
+        creator: Address,
+        stream_id: u64,
+        transferable: bool,
+    ) -> Result<(), Error> {
+        streaming::set_transferable(&env, &creator, stream_id, transferable)
+    }
+
+    /// Move a stream's recipient rights from `from` to `to`
+    ///
+    /// Must be called by the current recipient. Unclaimed amounts, vested or
+    /// not, follow the stream; amounts already claimed stay with `from`.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `stream_id` - ID of the stream
+    /// * `from` - Current recipient (must authorize)
+    /// * `to` - New recipient
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Stream claims are paused
+    /// * `Error::TokenNotFound` - Stream not found
+    /// * `Error::Unauthorized` - Caller is not the current recipient
+    /// * `Error::InvalidParameters` - Stream is not transferable or was split, or `to` is `from`
+    /// * `Error::TokenPaused` - Token is paused
+    ///
+    /// # Examples
+    /// ```
+    /// // Rotate to a new key
+    /// factory.transfer_stream(&stream_id, &old_key, &new_key)?;
+    /// ```
+    pub fn transfer_stream(env: Env, stream_id: u64, from: Address, to: Address) -> Result<(), Error> {
+        streaming::transfer_stream(&env, stream_id, &from, &to)
+    }
+
+    /// Divide a stream between several recipients (recipient only)
+    ///
+    /// Closes the stream and creates child streams on the same schedule,
+    /// cliff and curve, each linked back through `parent_id`. The unclaimed
+    /// remainder is apportioned by basis points; amounts already claimed
+    /// stay on the parent.
+    ///
+    /// # Arguments
+    /// * `stream_id` - ID of the stream (its recipient must authorize)
+    /// * `parts` - `(recipient, bps)` pairs summing to 10_000 bps, 2 to 10 parts
+    ///
+    /// # Returns
+    /// IDs of the child streams, in part order
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Stream not found
+    /// * `Error::InvalidParameters` - Stream cancelled, split or not transferable, or bad parts
+    /// * `Error::InvalidStateTransition` - Stream is paused
+    /// * `Error::NothingToClaim` - Everything was already claimed
+    /// * `Error::InvalidAmount` - A part would receive nothing
+    /// * `Error::TokenPaused` - Token is paused
+    ///
+    /// # Examples
+    /// ```
+    /// // Share 30% of a vesting position with a tax escrow wallet
+    /// let parts = vec![&env, (recipient.clone(), 7_000), (escrow.clone(), 3_000)];
+    /// let children = factory.split_stream(&stream_id, &parts)?;
+    /// ```
+    pub fn split_stream(
+        env: Env,
+        stream_id: u64,
+        parts: Vec<(Address, u32)>,
+    ) -> Result<Vec<u64>, Error> {
+        streaming::split_stream(&env, stream_id, &parts)
+    }
+
+    /// Add funds to a stream and/or move its end time (creator only)
+    ///
+    /// The schedule is re-based at the current time so that the amount
+    /// already vested is preserved; the remainder of the new total vests
+    /// linearly until `new_end_time`. Changes that lower the vesting rate
+    /// must also be authorized by the recipient.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `creator` - Stream creator (must authorize)
+    /// * `stream_id` - ID of the stream
+    /// * `additional_amount` - Amount to escrow on top (0 to only move the end)
+    /// * `new_end_time` - Ledger time the amended stream ends at
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Stream creation is paused
+    /// * `Error::TokenNotFound` - Stream not found
+    /// * `Error::Unauthorized` - Caller is not the creator
+    /// * `Error::InvalidParameters` - Stream cancelled or not linear, or nothing changes
+    /// * `Error::InvalidStateTransition` - Stream is paused
+    /// * `Error::InvalidAmount` - Negative amount
+    /// * `Error::InvalidSchedule` - New end is not after now and the cliff
+    /// * `Error::InsufficientBalance` - Creator cannot fund the top-up
+    ///
+    /// # Examples
+    /// ```
+    /// // Double a grant and extend it by a year
+    /// factory.top_up_stream(&creator, &stream_id, &grant, &(end + 31_536_000))?;
+    /// ```
+    pub fn top_up_stream(
+        env: Env,
+        creator: Address,
+        stream_id: u64,
+        additional_amount: i128,
+        new_end_time: u64,
+    ) -> Result<(), Error> {
+        streaming::top_up_stream(&env, &creator, stream_id, additional_amount, new_end_time)
     }
 
-    // ═══════════════════════════════════════════════════════════════════════
-    // Stream Functions
-    // ═══════════════════════════════════════════════════════════════════════
+    /// List a stream's amendments, oldest first
+    ///
+    /// cursor: amendment number to start from (0 for the first page)
+    /// limit: max amendments to return (capped at 100)
+    pub fn get_stream_amendments(
+        env: Env,
+        stream_id: u64,
+        cursor: u32,
+        limit: u32,
+    ) -> types::StreamAmendmentPage {
+        pagination::get_stream_amendments(&env, stream_id, cursor, limit)
+    }
+
+    /// Get stream information, or `None` if the stream does not exist
+    pub fn get_stream(env: Env, stream_id: u64) -> Option<StreamInfo> {
+        streaming::get_stream(&env, stream_id)
+    }
+
+    /// Get the amount currently claimable from a stream
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Stream not found
+    pub fn get_stream_claimable(env: Env, stream_id: u64) -> Result<i128, Error> {
+        streaming::get_claimable_amount(&env, stream_id)
+    }
 
     /// Create a vault with either time-based unlock, milestone-based unlock, or both.
     pub fn create_vault(
@@ -1521,7 +3016,7 @@ impl TokenFactory {
     ) -> Result<u64, Error> {
         creator.require_auth();
 
-        if storage::is_paused(&env) {
+        if storage::is_operation_paused(&env, types::PAUSE_VAULT_CREATE) {
             return Err(Error::ContractPaused);
         }
 
@@ -1606,76 +3101,54 @@ impl TokenFactory {
     ) -> Result<i128, Error> {
         owner.require_auth();
 
-        if storage::is_paused(&env) {
+        if storage::is_operation_paused(&env, types::PAUSE_VAULT_CLAIM) {
             return Err(Error::ContractPaused);
         }
 
         // Load vault
-        let mut vault = storage::get_vault(&env, vault_id).ok_or(Error::TokenNotFound)?;
+        let vault = storage::get_vault(&env, vault_id).ok_or(Error::TokenNotFound)?;
 
         // Verify owner
         if vault.owner != owner {
             return Err(Error::Unauthorized);
         }
 
-        // Check vault status
-        if vault.status != VaultStatus::Active {
-            return match vault.status {
-                VaultStatus::Claimed => Err(Error::InvalidParameters),
-                VaultStatus::Cancelled => Err(Error::InvalidParameters),
-                _ => Err(Error::InvalidParameters),
-            };
-        }
-
-        // Milestone verification (if required)
-        let zero_hash = BytesN::from_array(&env, &[0u8; 32]);
-        if vault.milestone_hash != zero_hash {
-            // Non-zero milestone_hash requires proof
-            let proof_bytes = proof.ok_or(Error::InvalidParameters)?;
-
-            // TODO: Inject verifier instance (currently using stub)
-            // In production, replace with oracle-based verifier that:
-            // - Validates cryptographic signatures from trusted oracles
-            // - Checks proof timestamps to prevent replay attacks
-            // - Verifies milestone_hash matches proof payload
-            // - Handles oracle service unavailability gracefully
-            use crate::milestone_verification::MilestoneVerifier as _;
-            let verifier = milestone_verification::MilestoneVerifierStub::new(&env);
-
-            let is_valid =
-                verifier.verify_milestone(&env, &vault.milestone_hash, &proof_bytes)?;
-
-            if !is_valid {
-                return Err(Error::InvalidParameters);
-            }
-        }
+        pay_vault_claim(&env, vault, proof)
+    }
 
-        // Time-based unlock check (independent of milestone verification)
-        let current_time = env.ledger().timestamp();
-        if vault.unlock_time > 0 && current_time < vault.unlock_time {
-            return Err(Error::InvalidParameters);
-        }
+    /// Claim a vault for its owner on their behalf
+    ///
+    /// Any keeper may call this once the owner opted into auto-claim, or the
+    /// owner's operator otherwise (see `set_claim_delegation`). Tokens go to
+    /// the vault owner; the checks are those of `claim_vault`.
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Owner has not delegated claims to `keeper`
+    /// * `Error::InvalidTimeWindow` - Owner's minimum claim interval not elapsed
+    /// * Any error of `claim_vault`
+    pub fn push_vault_claim(
+        env: Env,
+        keeper: Address,
+        vault_id: u64,
+        proof: Option<Bytes>,
+    ) -> Result<i128, Error> {
+        keeper.require_auth();
 
-        // Calculate claimable amount
-        let claimable = vault
-            .total_amount
-            .checked_sub(vault.claimed_amount)
-            .ok_or(Error::ArithmeticError)?;
-        if claimable <= 0 {
-            return Err(Error::NothingToClaim);
+        if storage::is_operation_paused(&env, types::PAUSE_VAULT_CLAIM) {
+            return Err(Error::ContractPaused);
         }
 
-        // Transfer tokens
-        let token_client = soroban_sdk::token::Client::new(&env, &vault.token);
-        token_client.transfer(&env.current_contract_address(), &owner, &claimable);
+        let vault = storage::get_vault(&env, vault_id).ok_or(Error::TokenNotFound)?;
 
-        // Update vault
-        vault.claimed_amount = vault.total_amount;
-        vault.status = VaultStatus::Claimed;
-        storage::set_vault(&env, &vault)?;
+        keeper::authorize_push(
+            &env,
+            &keeper,
+            &vault.owner,
+            &types::ExtDataKey::VaultLastPushedClaim(vault_id),
+        )?;
 
-        // Emit event
-        events::emit_vault_claimed(&env, vault_id, &owner, claimable);
+        let claimable = pay_vault_claim(&env, vault, proof)?;
+        events::emit_claim_pushed(&env, vault_id, true, &keeper, claimable);
 
         Ok(claimable)
     }
@@ -1694,7 +3167,7 @@ impl TokenFactory {
     pub fn cancel_vault(env: Env, vault_id: u64, actor: Address) -> Result<(), Error> {
         actor.require_auth();
 
-        if storage::is_paused(&env) {
+        if storage::is_operation_paused(&env, types::PAUSE_VAULT_CANCEL) {
             return Err(Error::ContractPaused);
         }
 
@@ -1795,8 +3268,8 @@ impl TokenFactory {
         // Require updater authorization
         updater.require_auth();
 
-        // Early return if contract is paused
-        if storage::is_paused(&env) {
+        // Early return if stream management is paused
+        if storage::is_operation_paused(&env, types::PAUSE_STREAM_CREATE) {
             return Err(Error::ContractPaused);
         }
 
@@ -1898,6 +3371,410 @@ impl TokenFactory {
     ) -> bool {
         governance::is_approval_met(yes_votes, total_votes, approval_percent)
     }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // Flow Stream Functions
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Open an open-ended stream paying `rate_per_second` to `recipient`
+    ///
+    /// The sender funds the stream with deposits; the recipient can
+    /// withdraw accrued debt at any time while the deposit covers it.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `sender` - Address paying the stream (must authorize)
+    /// * `recipient` - Address receiving the stream
+    /// * `token_index` - Token streamed
+    /// * `rate_per_second` - Amount accrued per second (must be > 0)
+    /// * `deposit` - Initial deposit escrowed from the sender (may be 0)
+    ///
+    /// # Returns
+    /// Returns the flow stream ID
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Stream creation is paused
+    /// * `Error::TokenNotFound` - Token doesn't exist
+    /// * `Error::InvalidParameters` - Sender streams to itself
+    /// * `Error::InvalidAmount` - Rate is not positive or deposit is negative
+    /// * `Error::InsufficientBalance` - Sender cannot fund the deposit
+    ///
+    /// # Examples
+    /// ```
+    /// // 1 token (7 decimals) per day with 30 days of runway
+    /// let id = factory.create_flow_stream(&employer, &employee, &0, &115, &300_000_000)?;
+    /// ```
+    pub fn create_flow_stream(
+        env: Env,
+        sender: Address,
+        recipient: Address,
+        token_index: u32,
+        rate_per_second: i128,
+        deposit: i128,
+    ) -> Result<u64, Error> {
+        flow::create(
+            &env,
+            &sender,
+            &recipient,
+            token_index,
+            rate_per_second,
+            deposit,
+        )
+    }
+
+    /// Change a flow stream's rate (sender only)
+    ///
+    /// Debt accrued at the old rate is kept; a rate of 0 pauses accrual.
+    ///
+    /// # Errors
+    /// * `Error::StreamNotFound` - Stream doesn't exist
+    /// * `Error::Unauthorized` - Caller is not the sender
+    /// * `Error::InvalidAmount` - Rate is negative
+    pub fn adjust_flow_rate(
+        env: Env,
+        sender: Address,
+        stream_id: u64,
+        rate_per_second: i128,
+    ) -> Result<(), Error> {
+        flow::adjust_rate(&env, &sender, stream_id, rate_per_second)
+    }
+
+    /// Add runway to a flow stream (sender only)
+    ///
+    /// Uncovered debt of an insolvent stream is paid off first.
+    ///
+    /// # Errors
+    /// * `Error::StreamNotFound` - Stream doesn't exist
+    /// * `Error::Unauthorized` - Caller is not the sender
+    /// * `Error::InvalidAmount` - Amount is not positive
+    /// * `Error::InsufficientBalance` - Sender cannot fund the deposit
+    pub fn deposit_flow_stream(
+        env: Env,
+        sender: Address,
+        stream_id: u64,
+        amount: i128,
+    ) -> Result<(), Error> {
+        flow::deposit(&env, &sender, stream_id, amount)
+    }
+
+    /// Return deposit in excess of the accrued debt to the sender
+    ///
+    /// # Errors
+    /// * `Error::StreamNotFound` - Stream doesn't exist
+    /// * `Error::Unauthorized` - Caller is not the sender
+    /// * `Error::InvalidAmount` - Amount is not positive
+    /// * `Error::InsufficientBalance` - Amount exceeds the surplus over the debt
+    pub fn refund_flow_excess(
+        env: Env,
+        sender: Address,
+        stream_id: u64,
+        amount: i128,
+    ) -> Result<(), Error> {
+        flow::refund_excess(&env, &sender, stream_id, amount)
+    }
+
+    /// Withdraw everything the deposit currently covers (recipient only)
+    ///
+    /// # Returns
+    /// Returns the amount credited to the recipient
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Stream claims are paused
+    /// * `Error::StreamNotFound` - Stream doesn't exist
+    /// * `Error::Unauthorized` - Caller is not the recipient
+    /// * `Error::TokenPaused` - Token is paused
+    /// * `Error::NothingToClaim` - Nothing withdrawable
+    pub fn withdraw_flow_stream(
+        env: Env,
+        recipient: Address,
+        stream_id: u64,
+    ) -> Result<i128, Error> {
+        flow::withdraw(&env, &recipient, stream_id)
+    }
+
+    /// Get a flow stream, or `None` if it does not exist
+    pub fn get_flow_stream(env: Env, stream_id: u64) -> Option<types::FlowStream> {
+        storage::get_flow_stream(&env, stream_id)
+    }
+
+    /// Get the amount a flow stream's recipient can withdraw now
+    ///
+    /// # Errors
+    /// * `Error::StreamNotFound` - Stream doesn't exist
+    pub fn get_flow_withdrawable(env: Env, stream_id: u64) -> Result<i128, Error> {
+        let stream = storage::get_flow_stream(&env, stream_id).ok_or(Error::StreamNotFound)?;
+        flow::withdrawable(&stream, env.ledger().timestamp())
+    }
+
+    /// Get a flow stream's debt not covered by its deposit (0 while solvent)
+    ///
+    /// # Errors
+    /// * `Error::StreamNotFound` - Stream doesn't exist
+    pub fn get_stream_debt(env: Env, stream_id: u64) -> Result<i128, Error> {
+        let stream = storage::get_flow_stream(&env, stream_id).ok_or(Error::StreamNotFound)?;
+        flow::uncovered_debt(&stream, env.ledger().timestamp())
+    }
+
+    /// Get the last second a flow stream's deposit covers its debt
+    ///
+    /// Returns `None` while the rate is 0. A time in the past means the
+    /// stream is insolvent.
+    ///
+    /// # Errors
+    /// * `Error::StreamNotFound` - Stream doesn't exist
+    pub fn get_depletion_time(env: Env, stream_id: u64) -> Result<Option<u64>, Error> {
+        let stream = storage::get_flow_stream(&env, stream_id).ok_or(Error::StreamNotFound)?;
+        Ok(flow::depletion_time(&stream))
+    }
+
+    // ========== Payroll Pool Functions ==========
+
+    /// Create a payroll pool: one funded balance streamed to many members
+    ///
+    /// Members are added with `set_pool_member` and paid either a fixed
+    /// per-second rate or a share of `weighted_rate` by weight.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `creator` - Address funding and managing the pool (must authorize)
+    /// * `token_index` - Token the pool pays in
+    /// * `weighted_rate` - Per-second amount shared by weighted members (may be 0)
+    /// * `deposit` - Initial deposit escrowed from the creator (may be 0)
+    ///
+    /// # Returns
+    /// Returns the pool ID
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Stream creation is paused
+    /// * `Error::TokenNotFound` - Token doesn't exist
+    /// * `Error::InvalidAmount` - Weighted rate or deposit is negative
+    /// * `Error::InsufficientBalance` - Creator cannot fund the deposit
+    ///
+    /// # Examples
+    /// ```
+    /// // 10 tokens (7 decimals) per day shared by weight, 30 days of runway
+    /// let id = factory.create_payroll_pool(&employer, &0, &1_157, &3_000_000_000)?;
+    /// factory.set_pool_member(&employer, &id, &employee, &0, &1)?;
+    /// ```
+    pub fn create_payroll_pool(
+        env: Env,
+        creator: Address,
+        token_index: u32,
+        weighted_rate: i128,
+        deposit: i128,
+    ) -> Result<u64, Error> {
+        pool::create(&env, &creator, token_index, weighted_rate, deposit)
+    }
+
+    /// Add runway to a payroll pool (creator only)
+    ///
+    /// # Errors
+    /// * `Error::StreamNotFound` - Pool doesn't exist
+    /// * `Error::Unauthorized` - Caller is not the creator
+    /// * `Error::InvalidAmount` - Amount is not positive
+    /// * `Error::InsufficientBalance` - Creator cannot fund the deposit
+    pub fn deposit_payroll_pool(
+        env: Env,
+        creator: Address,
+        pool_id: u64,
+        amount: i128,
+    ) -> Result<(), Error> {
+        pool::deposit(&env, &creator, pool_id, amount)
+    }
+
+    /// Add a pool member or change their rate or weight (creator only)
+    ///
+    /// Exactly one of `rate_per_second` and `weight` must be non-zero.
+    /// Everything accrued under the old terms is settled first.
+    ///
+    /// # Errors
+    /// * `Error::StreamNotFound` - Pool doesn't exist
+    /// * `Error::Unauthorized` - Caller is not the creator
+    /// * `Error::InvalidParameters` - Creator adds itself, or not exactly
+    ///   one of rate and weight is set
+    /// * `Error::InvalidAmount` - Rate is negative
+    pub fn set_pool_member(
+        env: Env,
+        creator: Address,
+        pool_id: u64,
+        member: Address,
+        rate_per_second: i128,
+        weight: u32,
+    ) -> Result<(), Error> {
+        pool::set_member(&env, &creator, pool_id, &member, rate_per_second, weight)
+    }
+
+    /// Stop a pool member's accrual (creator only)
+    ///
+    /// The member can still withdraw what accrued before removal.
+    ///
+    /// # Errors
+    /// * `Error::StreamNotFound` - Pool doesn't exist
+    /// * `Error::Unauthorized` - Caller is not the creator
+    /// * `Error::InvalidParameters` - Address is not an active member
+    pub fn remove_pool_member(
+        env: Env,
+        creator: Address,
+        pool_id: u64,
+        member: Address,
+    ) -> Result<(), Error> {
+        pool::remove_member(&env, &creator, pool_id, &member)
+    }
+
+    /// Change the per-second amount shared by weighted members (creator only)
+    ///
+    /// # Errors
+    /// * `Error::StreamNotFound` - Pool doesn't exist
+    /// * `Error::Unauthorized` - Caller is not the creator
+    /// * `Error::InvalidAmount` - Rate is negative
+    pub fn set_pool_weighted_rate(
+        env: Env,
+        creator: Address,
+        pool_id: u64,
+        weighted_rate: i128,
+    ) -> Result<(), Error> {
+        pool::set_weighted_rate(&env, &creator, pool_id, weighted_rate)
+    }
+
+    /// Return pool balance in excess of what members are owed to the creator
+    ///
+    /// # Errors
+    /// * `Error::StreamNotFound` - Pool doesn't exist
+    /// * `Error::Unauthorized` - Caller is not the creator
+    /// * `Error::InvalidAmount` - Amount is not positive
+    /// * `Error::InsufficientBalance` - Amount exceeds the surplus over the debt
+    pub fn refund_pool_excess(
+        env: Env,
+        creator: Address,
+        pool_id: u64,
+        amount: i128,
+    ) -> Result<(), Error> {
+        pool::refund_excess(&env, &creator, pool_id, amount)
+    }
+
+    /// Withdraw everything accrued to a member that the pool balance covers
+    ///
+    /// # Returns
+    /// Returns the amount credited to the member
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Stream claims are paused
+    /// * `Error::StreamNotFound` - Pool doesn't exist
+    /// * `Error::Unauthorized` - Caller is not a member
+    /// * `Error::TokenPaused` - Token is paused
+    /// * `Error::NothingToClaim` - Nothing withdrawable
+    pub fn withdraw_from_pool(env: Env, member: Address, pool_id: u64) -> Result<i128, Error> {
+        pool::withdraw(&env, &member, pool_id)
+    }
+
+    /// Get a payroll pool, or `None` if it does not exist
+    pub fn get_payroll_pool(env: Env, pool_id: u64) -> Option<types::PayrollPool> {
+        storage::get_payroll_pool(&env, pool_id)
+    }
+
+    /// Get a pool member's position as last settled, or `None`
+    pub fn get_pool_member(env: Env, pool_id: u64, member: Address) -> Option<types::PoolMember> {
+        storage::get_pool_member(&env, pool_id, &member)
+    }
+
+    /// Get the amount a pool member can withdraw now
+    ///
+    /// # Errors
+    /// * `Error::StreamNotFound` - Pool doesn't exist
+    /// * `Error::InvalidParameters` - Address is not a member
+    pub fn get_pool_withdrawable(env: Env, pool_id: u64, member: Address) -> Result<i128, Error> {
+        let pool = storage::get_payroll_pool(&env, pool_id).ok_or(Error::StreamNotFound)?;
+        let position =
+            storage::get_pool_member(&env, pool_id, &member).ok_or(Error::InvalidParameters)?;
+        pool::withdrawable(&pool, &position, env.ledger().timestamp())
+    }
+
+    /// Get a pool's balance, total debt, outflow rate and remaining runway
+    ///
+    /// Runway is `None` while nothing accrues and 0 once the pool is
+    /// insolvent.
+    ///
+    /// # Errors
+    /// * `Error::StreamNotFound` - Pool doesn't exist
+    pub fn get_pool_solvency(env: Env, pool_id: u64) -> Result<types::PoolSolvency, Error> {
+        let pool = storage::get_payroll_pool(&env, pool_id).ok_or(Error::StreamNotFound)?;
+        pool::solvency(&pool, env.ledger().timestamp())
+    }
+}
+
+/// Pay out an active vault to its owner once its unlock conditions are met
+///
+/// Shared by `claim_vault` and `push_vault_claim` after they have checked
+/// who is asking.
+fn pay_vault_claim(env: &Env, mut vault: Vault, proof: Option<Bytes>) -> Result<i128, Error> {
+    let owner = vault.owner.clone();
+    let vault_id = vault.id;
+
+    // Check vault status
+    if vault.status != VaultStatus::Active {
+        return match vault.status {
+            VaultStatus::Claimed => Err(Error::InvalidParameters),
+            VaultStatus::Cancelled => Err(Error::InvalidParameters),
+            _ => Err(Error::InvalidParameters),
+        };
+    }
+
+    // Milestone verification (if required)
+    let zero_hash = BytesN::from_array(env, &[0u8; 32]);
+    if vault.milestone_hash != zero_hash {
+        // Non-zero milestone_hash requires proof
+        let proof_bytes = proof.ok_or(Error::InvalidParameters)?;
+
+        // TODO: Inject verifier instance (currently using stub)
+        // In production, replace with oracle-based verifier that:
+        // - Validates cryptographic signatures from trusted oracles
+        // - Checks proof timestamps to prevent replay attacks
+        // - Verifies milestone_hash matches proof payload
+        // - Handles oracle service unavailability gracefully
+        use crate::milestone_verification::MilestoneVerifier as _;
+        let verifier = milestone_verification::MilestoneVerifierStub::new(env);
+
+        let is_valid =
+            verifier.verify_milestone(env, &vault.milestone_hash, &proof_bytes)?;
+
+        if !is_valid {
+            return Err(Error::InvalidParameters);
+        }
+    }
+
+    // Time-based unlock check (independent of milestone verification)
+    let current_time = env.ledger().timestamp();
+    if vault.unlock_time > 0 && current_time < vault.unlock_time {
+        return Err(Error::InvalidParameters);
+    }
+
+    // Calculate claimable amount
+    let claimable = vault
+        .total_amount
+        .checked_sub(vault.claimed_amount)
+        .ok_or(Error::ArithmeticError)?;
+    if claimable <= 0 {
+        return Err(Error::NothingToClaim);
+    }
+
+    // Payout must satisfy the token's compliance mode
+    if let Some(token_index) = storage::get_token_index_by_address(env, &vault.token) {
+        compliance::require_eligible(env, token_index, &owner)?;
+    }
+
+    // Transfer tokens
+    let token_client = soroban_sdk::token::Client::new(env, &vault.token);
+    token_client.transfer(&env.current_contract_address(), &owner, &claimable);
+
+    // Update vault
+    vault.claimed_amount = vault.total_amount;
+    vault.status = VaultStatus::Claimed;
+    storage::set_vault(env, &vault)?;
+
+    // Emit event
+    events::emit_vault_claimed(env, vault_id, &owner, claimable);
+
+    Ok(claimable)
 }
 
 // Temporarily disabled - requires create_token implementation
@@ -2014,6 +3891,75 @@ mod event_replay_test;
 #[cfg(test)]
 mod batch_token_creation_test;
 
+#[cfg(test)]
+mod admin_proposal_expiry_test;
+
+#[cfg(test)]
+mod pause_flags_test;
+
+#[cfg(test)]
+mod minter_allowance_test;
+
+#[cfg(test)]
+mod supply_policy_test;
+
+#[cfg(test)]
+mod compliance_test;
+
+#[cfg(test)]
+mod transfer_tax_test;
+
+#[cfg(test)]
+mod token_metadata_test;
+
+#[cfg(test)]
+mod symbol_registry_test;
+
+#[cfg(test)]
+mod airdrop_test;
+
+#[cfg(test)]
+mod holder_registry_test;
+
+#[cfg(test)]
+mod snapshot_test;
+
+#[cfg(test)]
+mod distribution_test;
+
+#[cfg(test)]
+mod staking_test;
+
+#[cfg(test)]
+mod clawback_test;
+
+#[cfg(test)]
+mod stream_cancel_test;
+
+#[cfg(test)]
+mod stream_amendment_test;
+
+#[cfg(test)]
+mod stream_curve_test;
+
+#[cfg(test)]
+mod flow_test;
+
+#[cfg(test)]
+mod stream_transfer_test;
+
+#[cfg(test)]
+mod stream_index_test;
+
+#[cfg(test)]
+mod stream_split_test;
+
+#[cfg(test)]
+mod keeper_claim_test;
+
+#[cfg(test)]
+mod pool_test;
+
 #[cfg(all(test, feature = "legacy-tests"))]
 mod vault_cancellation_test;
 
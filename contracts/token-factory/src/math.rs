@@ -0,0 +1,96 @@
+//! Fixed-Point Helpers
+//!
+//! `a * b / denominator` with a 256-bit intermediate product, for pro-rata
+//! shares and reward accumulators where the product of two 18-decimal
+//! amounts does not fit in an `i128` even though the result does.
+
+use crate::types::Error;
+
+/// Compute `a * b / denominator`, rounded down
+///
+/// # Returns
+/// * `Ok(result)` - The quotient
+/// * `Err(Error::ArithmeticError)` - Negative operand, zero denominator, or
+///   a quotient that does not fit in an `i128`
+pub fn mul_div(a: i128, b: i128, denominator: i128) -> Result<i128, Error> {
+    if a < 0 || b < 0 || denominator <= 0 {
+        return Err(Error::ArithmeticError);
+    }
+
+    let (high, low) = mul_wide(a as u128, b as u128);
+    div_wide(high, low, denominator as u128)
+        .and_then(|quotient| i128::try_from(quotient).ok())
+        .ok_or(Error::ArithmeticError)
+}
+
+/// Full 256-bit product of two `u128` values as `(high, low)` halves
+fn mul_wide(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+
+    let (a_high, a_low) = (a >> 64, a & MASK);
+    let (b_high, b_low) = (b >> 64, b & MASK);
+
+    let low_low = a_low * b_low;
+    let high_low = a_high * b_low;
+    let low_high = a_low * b_high;
+    let high_high = a_high * b_high;
+
+    let (low, carry_a) = low_low.overflowing_add(high_low << 64);
+    let (low, carry_b) = low.overflowing_add(low_high << 64);
+    let high = high_high + (high_low >> 64) + (low_high >> 64) + carry_a as u128 + carry_b as u128;
+
+    (high, low)
+}
+
+/// Divide a 256-bit value by a divisor below 2^127
+///
+/// `None` if the quotient does not fit in 128 bits.
+fn div_wide(high: u128, low: u128, divisor: u128) -> Option<u128> {
+    if high >= divisor {
+        return None;
+    }
+
+    // Bitwise long division; the remainder stays below the divisor, so
+    // shifting it left by one cannot overflow
+    let mut remainder = high;
+    let mut quotient = 0u128;
+    for bit in (0..128).rev() {
+        remainder = (remainder << 1) | ((low >> bit) & 1);
+        quotient <<= 1;
+        if remainder >= divisor {
+            remainder -= divisor;
+            quotient |= 1;
+        }
+    }
+
+    Some(quotient)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const E18: i128 = 1_000_000_000_000_000_000;
+
+    #[test]
+    fn test_small_values_match_plain_arithmetic() {
+        assert_eq!(mul_div(1_000, 1, 3), Ok(333));
+        assert_eq!(mul_div(7, 9, 2), Ok(31));
+        assert_eq!(mul_div(0, i128::MAX, 1), Ok(0));
+    }
+
+    #[test]
+    fn test_product_wider_than_i128() {
+        // 1e9 tokens of 18 decimals times 1e9 tokens, over 2e9 tokens
+        let amount = 1_000_000_000 * E18;
+        assert_eq!(mul_div(amount, amount, 2 * amount), Ok(amount / 2));
+        assert_eq!(mul_div(i128::MAX, i128::MAX, i128::MAX), Ok(i128::MAX));
+    }
+
+    #[test]
+    fn test_rejects_out_of_range() {
+        assert_eq!(mul_div(i128::MAX, 2, 1), Err(Error::ArithmeticError));
+        assert_eq!(mul_div(1, 1, 0), Err(Error::ArithmeticError));
+        assert_eq!(mul_div(-1, 1, 1), Err(Error::ArithmeticError));
+    }
+}
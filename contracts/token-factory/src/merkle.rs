@@ -0,0 +1,201 @@
+//! Merkle Tree Hashing for Airdrop Campaigns
+//!
+//! Leaves commit to `(index, address, amount)`:
+//!
+//! ```text
+//! leaf = sha256(0x00 || index as u32 BE || address XDR || amount as i128 BE)
+//! node = sha256(0x01 || min(a, b) || max(a, b))
+//! ```
+//!
+//! Pairs are hashed in sorted order, so proofs carry no left/right flags.
+//! A node without a sibling is promoted to the next level unchanged.
+//!
+//! The contract only needs [`leaf_hash`] and [`verify_proof`]. [`MerkleTree`]
+//! is the off-chain helper that builds the root and per-recipient proofs
+//! with the exact same hashing; it is not compiled into the wasm contract.
+
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, Vec};
+
+#[cfg(not(target_family = "wasm"))]
+use crate::types::Error;
+
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+
+/// Maximum accepted proof length (supports trees of up to 2^32 leaves)
+pub const MAX_PROOF_LEN: u32 = 32;
+
+/// Hash an airdrop leaf
+pub fn leaf_hash(env: &Env, index: u32, account: &Address, amount: i128) -> BytesN<32> {
+    let mut data = Bytes::new(env);
+    data.push_back(LEAF_PREFIX);
+    data.extend_from_array(&index.to_be_bytes());
+    data.append(&account.clone().to_xdr(env));
+    data.extend_from_array(&amount.to_be_bytes());
+    env.crypto().sha256(&data).into()
+}
+
+/// Hash two sibling nodes in sorted order
+pub fn hash_pair(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (a, b) = (a.to_array(), b.to_array());
+    let (low, high) = if a <= b { (a, b) } else { (b, a) };
+
+    let mut data = Bytes::new(env);
+    data.push_back(NODE_PREFIX);
+    data.extend_from_array(&low);
+    data.extend_from_array(&high);
+    env.crypto().sha256(&data).into()
+}
+
+/// Check that `leaf` is included under `root`
+pub fn verify_proof(
+    env: &Env,
+    root: &BytesN<32>,
+    leaf: &BytesN<32>,
+    proof: &Vec<BytesN<32>>,
+) -> bool {
+    if proof.len() > MAX_PROOF_LEN {
+        return false;
+    }
+
+    let mut computed = leaf.clone();
+    for sibling in proof.iter() {
+        computed = hash_pair(env, &computed, &sibling);
+    }
+    computed == *root
+}
+
+/// Off-chain Merkle tree builder for airdrop campaigns
+///
+/// Recipient `i` in the input list gets leaf index `i`; pass the same
+/// index, address, and amount to `claim_airdrop` together with
+/// `proof(i)`.
+///
+/// # Examples
+/// ```ignore
+/// let env = Env::default();
+/// let recipients = vec![&env, (alice.clone(), 100i128), (bob.clone(), 250i128)];
+/// let tree = MerkleTree::build(&env, &recipients)?;
+///
+/// factory.create_airdrop(&creator, &0, &tree.root(), &350, &expires_at);
+/// factory.claim_airdrop(&bob, &0, &1, &250, &tree.proof(&env, 1));
+/// ```
+#[cfg(not(target_family = "wasm"))]
+pub struct MerkleTree {
+    /// Tree levels from leaves (index 0) up to the root
+    levels: Vec<Vec<BytesN<32>>>,
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl MerkleTree {
+    /// Build the tree for `(address, amount)` recipients
+    ///
+    /// # Returns
+    /// * `Ok(tree)` - Tree over all recipients
+    /// * `Err(Error::InvalidParameters)` - Recipient list is empty
+    pub fn build(env: &Env, recipients: &Vec<(Address, i128)>) -> Result<Self, Error> {
+        if recipients.is_empty() {
+            return Err(Error::InvalidParameters);
+        }
+
+        let mut level = Vec::new(env);
+        for (index, (account, amount)) in recipients.iter().enumerate() {
+            level.push_back(leaf_hash(env, index as u32, &account, amount));
+        }
+
+        let mut levels = Vec::new(env);
+        while level.len() > 1 {
+            let mut next = Vec::new(env);
+            let mut i = 0;
+            while i < level.len() {
+                let left = level.get_unchecked(i);
+                if i + 1 < level.len() {
+                    next.push_back(hash_pair(env, &left, &level.get_unchecked(i + 1)));
+                } else {
+                    next.push_back(left);
+                }
+                i += 2;
+            }
+            levels.push_back(level);
+            level = next;
+        }
+        levels.push_back(level);
+
+        Ok(Self { levels })
+    }
+
+    /// Root to post with `create_airdrop`
+    pub fn root(&self) -> BytesN<32> {
+        self.levels.last_unchecked().get_unchecked(0)
+    }
+
+    /// Inclusion proof for the recipient at `index`
+    ///
+    /// Panics if `index` is out of range.
+    pub fn proof(&self, env: &Env, index: u32) -> Vec<BytesN<32>> {
+        assert!(
+            index < self.levels.get_unchecked(0).len(),
+            "leaf index out of range"
+        );
+
+        let mut proof = Vec::new(env);
+        let mut position = index;
+        for level in self.levels.iter().take(self.levels.len() as usize - 1) {
+            let sibling = position ^ 1;
+            if sibling < level.len() {
+                proof.push_back(level.get_unchecked(sibling));
+            }
+            position /= 2;
+        }
+        proof
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    fn recipients(env: &Env, count: u32) -> Vec<(Address, i128)> {
+        let mut list = Vec::new(env);
+        for i in 0..count {
+            list.push_back((Address::generate(env), 100 + i as i128));
+        }
+        list
+    }
+
+    #[test]
+    fn test_every_proof_verifies() {
+        let env = Env::default();
+        for count in [1u32, 2, 3, 5, 8] {
+            let list = recipients(&env, count);
+            let tree = MerkleTree::build(&env, &list).unwrap();
+
+            for (i, (account, amount)) in list.iter().enumerate() {
+                let leaf = leaf_hash(&env, i as u32, &account, amount);
+                assert!(verify_proof(&env, &tree.root(), &leaf, &tree.proof(&env, i as u32)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_proof_rejects_wrong_amount_or_index() {
+        let env = Env::default();
+        let list = recipients(&env, 4);
+        let tree = MerkleTree::build(&env, &list).unwrap();
+        let (account, amount) = list.get_unchecked(2);
+        let proof = tree.proof(&env, 2);
+
+        let wrong_amount = leaf_hash(&env, 2, &account, amount + 1);
+        assert!(!verify_proof(&env, &tree.root(), &wrong_amount, &proof));
+
+        let wrong_index = leaf_hash(&env, 3, &account, amount);
+        assert!(!verify_proof(&env, &tree.root(), &wrong_index, &proof));
+    }
+
+    #[test]
+    fn test_empty_tree_rejected() {
+        let env = Env::default();
+        assert!(MerkleTree::build(&env, &Vec::new(&env)).is_err());
+    }
+}
@@ -1,5 +1,5 @@
 use crate::storage;
-use crate::types::Error;
+use crate::types::{Error, MinterAllowance};
 use soroban_sdk::{Address, Env};
 
 /// Validate max supply constraints
@@ -91,6 +91,8 @@ pub fn validate_max_supply_at_creation(
 /// * `Ok(())` - Mint successful
 /// * `Err(Error::TokenNotFound)` - Token doesn't exist
 /// * `Err(Error::InvalidAmount)` - Amount is zero or negative
+/// * `Err(Error::MintingDisabled)` - Supply has been finalized
+/// * `Err(Error::RecipientNotAllowed)` - Recipient fails the token's compliance mode
 /// * `Err(Error::MaxSupplyExceeded)` - Would exceed max supply
 /// * `Err(Error::ArithmeticError)` - Overflow in calculation
 pub fn mint(env: &Env, token_index: u32, to: &Address, amount: i128) -> Result<(), Error> {
@@ -102,6 +104,12 @@ pub fn mint(env: &Env, token_index: u32, to: &Address, amount: i128) -> Result<(
     // Get token info
     let mut token_info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
 
+    if storage::is_supply_finalized(env, token_index) {
+        return Err(Error::MintingDisabled);
+    }
+
+    crate::compliance::require_eligible(env, token_index, to)?;
+
     // Validate max supply constraint
     validate_max_supply(token_info.total_supply, amount, token_info.max_supply)?;
 
@@ -151,10 +159,14 @@ pub fn batch_mint(
     let mut token_info = storage::get_token_info(env, token_index)
         .ok_or(Error::TokenNotFound)?;
 
+    if storage::is_supply_finalized(env, token_index) {
+        return Err(Error::MintingDisabled);
+    }
+
     // Validate upfront to preserve atomic/event-noise guarantees.
     let mut total_mint: i128 = 0;
     for (to, amount) in mints.iter() {
-        let _ = to;
+        crate::compliance::require_eligible(env, token_index, &to)?;
         if amount <= 0 {
             return Err(Error::InvalidAmount);
         }
@@ -187,6 +199,218 @@ pub fn batch_mint(
     Ok(())
 }
 
+/// Grant or replace a delegated minter allowance
+///
+/// Only the token creator can add minters. Adding an existing minter
+/// replaces its allowance and expiry.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `creator` - Token creator address (must authorize)
+/// * `token_index` - Index of the token
+/// * `minter` - Address allowed to mint
+/// * `allowance` - Maximum amount the minter may mint (must be > 0)
+/// * `expires_at` - Timestamp after which the role lapses (0 = never)
+///
+/// # Returns
+/// * `Ok(())` - Minter added
+/// * `Err(Error::TokenNotFound)` - Token doesn't exist
+/// * `Err(Error::Unauthorized)` - Caller is not the token creator
+/// * `Err(Error::InvalidAmount)` - Allowance is zero or negative
+/// * `Err(Error::InvalidParameters)` - Minter is the creator or expiry is in the past
+pub fn add_minter(
+    env: &Env,
+    creator: &Address,
+    token_index: u32,
+    minter: &Address,
+    allowance: i128,
+    expires_at: u64,
+) -> Result<(), Error> {
+    creator.require_auth();
+
+    let token_info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+    if token_info.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+
+    if allowance <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    if *minter == token_info.creator
+        || (expires_at != 0 && expires_at <= env.ledger().timestamp())
+    {
+        return Err(Error::InvalidParameters);
+    }
+
+    storage::set_minter(
+        env,
+        token_index,
+        minter,
+        &MinterAllowance {
+            remaining: allowance,
+            expires_at,
+        },
+    );
+
+    crate::events::emit_minter_added(env, token_index, minter, allowance, expires_at);
+
+    Ok(())
+}
+
+/// Revoke a delegated minter
+///
+/// # Returns
+/// * `Ok(())` - Minter removed
+/// * `Err(Error::TokenNotFound)` - Token doesn't exist
+/// * `Err(Error::Unauthorized)` - Caller is not the token creator
+/// * `Err(Error::InvalidParameters)` - Address is not a minter
+pub fn remove_minter(
+    env: &Env,
+    creator: &Address,
+    token_index: u32,
+    minter: &Address,
+) -> Result<(), Error> {
+    creator.require_auth();
+
+    let token_info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+    if token_info.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+
+    if storage::get_minter(env, token_index, minter).is_none() {
+        return Err(Error::InvalidParameters);
+    }
+
+    storage::remove_minter(env, token_index, minter);
+
+    crate::events::emit_minter_removed(env, token_index, minter);
+
+    Ok(())
+}
+
+/// Get the amount a delegated minter can still mint
+///
+/// Returns 0 for unknown or expired minters.
+pub fn get_minter_allowance(env: &Env, token_index: u32, minter: &Address) -> i128 {
+    match storage::get_minter(env, token_index, minter) {
+        Some(entry) if entry.expires_at == 0 || env.ledger().timestamp() <= entry.expires_at => {
+            entry.remaining
+        }
+        _ => 0,
+    }
+}
+
+/// Deduct a mint from a delegated minter's allowance
+///
+/// # Returns
+/// * `Ok(())` - Allowance consumed
+/// * `Err(Error::Unauthorized)` - Address is not a minter or its role expired
+/// * `Err(Error::InsufficientBalance)` - Amount exceeds the remaining allowance
+pub fn consume_minter_allowance(
+    env: &Env,
+    token_index: u32,
+    minter: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    let mut entry = storage::get_minter(env, token_index, minter).ok_or(Error::Unauthorized)?;
+
+    if entry.expires_at != 0 && env.ledger().timestamp() > entry.expires_at {
+        return Err(Error::Unauthorized);
+    }
+
+    if amount > entry.remaining {
+        return Err(Error::InsufficientBalance);
+    }
+
+    entry.remaining -= amount;
+    storage::set_minter(env, token_index, minter, &entry);
+
+    Ok(())
+}
+
+/// Lower the max supply cap of a token
+///
+/// The cap can only move down and never below the current total supply.
+/// Setting a cap on an uncapped token counts as lowering it.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `creator` - Token creator address (must authorize)
+/// * `token_index` - Index of the token
+/// * `new_max_supply` - New cap
+///
+/// # Returns
+/// * `Ok(())` - Cap updated
+/// * `Err(Error::TokenNotFound)` - Token doesn't exist
+/// * `Err(Error::Unauthorized)` - Caller is not the token creator
+/// * `Err(Error::MintingDisabled)` - Supply has been finalized
+/// * `Err(Error::InvalidMaxSupply)` - Cap would rise or fall below current supply
+pub fn set_max_supply(
+    env: &Env,
+    creator: &Address,
+    token_index: u32,
+    new_max_supply: i128,
+) -> Result<(), Error> {
+    creator.require_auth();
+
+    let mut token_info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+    if token_info.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+
+    if storage::is_supply_finalized(env, token_index) {
+        return Err(Error::MintingDisabled);
+    }
+
+    if new_max_supply < token_info.total_supply {
+        return Err(Error::InvalidMaxSupply);
+    }
+    if let Some(current_max) = token_info.max_supply {
+        if new_max_supply > current_max {
+            return Err(Error::InvalidMaxSupply);
+        }
+    }
+
+    let old_max_supply = token_info.max_supply;
+    token_info.max_supply = Some(new_max_supply);
+    storage::update_token_info(env, token_index, &token_info);
+
+    crate::events::emit_max_supply_updated(env, token_index, old_max_supply, new_max_supply);
+
+    Ok(())
+}
+
+/// Permanently disable minting for a token
+///
+/// Pins the max supply to the current total supply. Irreversible.
+///
+/// # Returns
+/// * `Ok(())` - Supply finalized
+/// * `Err(Error::TokenNotFound)` - Token doesn't exist
+/// * `Err(Error::Unauthorized)` - Caller is not the token creator
+/// * `Err(Error::MintingDisabled)` - Supply is already finalized
+pub fn finalize_supply(env: &Env, creator: &Address, token_index: u32) -> Result<(), Error> {
+    creator.require_auth();
+
+    let mut token_info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+    if token_info.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+
+    if storage::is_supply_finalized(env, token_index) {
+        return Err(Error::MintingDisabled);
+    }
+
+    token_info.max_supply = Some(token_info.total_supply);
+    storage::update_token_info(env, token_index, &token_info);
+    storage::set_supply_finalized(env, token_index);
+
+    crate::events::emit_supply_finalized(env, token_index, token_info.total_supply);
+
+    Ok(())
+}
+
 /// Get remaining mintable supply
 ///
 /// Returns how many more tokens can be minted before hitting the max supply.
@@ -197,11 +421,15 @@ pub fn batch_mint(
 /// * `token_index` - Index of the token
 ///
 /// # Returns
-/// * `Some(amount)` - Remaining mintable amount
+/// * `Some(amount)` - Remaining mintable amount (0 once supply is finalized)
 /// * `None` - Unlimited minting (no max supply)
 pub fn get_remaining_mintable(env: &Env, token_index: u32) -> Option<i128> {
     let token_info = storage::get_token_info(env, token_index)?;
 
+    if storage::is_supply_finalized(env, token_index) {
+        return Some(0);
+    }
+
     token_info
         .max_supply
         .map(|max| max.saturating_sub(token_info.total_supply).max(0))
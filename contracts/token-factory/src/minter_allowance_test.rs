@@ -0,0 +1,135 @@
+//! Delegated Minter Tests
+//!
+//! Tests for per-token minter roles with allowances and expiry
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Env,
+};
+
+use crate::test_helpers::{create_tokens, token_params, TestEnv};
+use crate::types::{Error, TokenCreationParams};
+use crate::TokenFactoryClient;
+
+fn setup(max_supply: Option<i128>) -> (Env, TokenFactoryClient<'static>, Address) {
+    let TestEnv { env, client, .. } = TestEnv::initialized();
+
+    let creator = Address::generate(&env);
+    let params = TokenCreationParams {
+        max_supply,
+        ..token_params(&env, "MNT", 1_000)
+    };
+    create_tokens(&client, &creator, vec![&env, params]);
+
+    (env, client, creator)
+}
+
+#[test]
+fn test_minter_consumes_allowance() {
+    let (env, client, creator) = setup(None);
+    let minter = Address::generate(&env);
+    let to = Address::generate(&env);
+
+    client.add_minter(&creator, &0, &minter, &500, &0);
+    assert_eq!(client.get_minter_allowance(&0, &minter), 500);
+
+    client.mint(&minter, &0, &to, &200);
+
+    assert_eq!(client.get_minter_allowance(&0, &minter), 300);
+    assert_eq!(client.get_token_info(&0).total_supply, 1_200);
+}
+
+#[test]
+fn test_minter_cannot_exceed_allowance() {
+    let (env, client, creator) = setup(None);
+    let minter = Address::generate(&env);
+    let to = Address::generate(&env);
+
+    client.add_minter(&creator, &0, &minter, &100, &0);
+
+    assert_eq!(
+        client.try_mint(&minter, &0, &to, &101),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    assert_eq!(client.get_minter_allowance(&0, &minter), 100);
+}
+
+#[test]
+fn test_expired_minter_cannot_mint() {
+    let (env, client, creator) = setup(None);
+    let minter = Address::generate(&env);
+    let to = Address::generate(&env);
+    let expiry = env.ledger().timestamp() + 100;
+
+    client.add_minter(&creator, &0, &minter, &500, &expiry);
+
+    env.ledger().with_mut(|li| li.timestamp = expiry + 1);
+
+    assert_eq!(client.get_minter_allowance(&0, &minter), 0);
+    assert_eq!(
+        client.try_mint(&minter, &0, &to, &1),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_removed_minter_cannot_mint() {
+    let (env, client, creator) = setup(None);
+    let minter = Address::generate(&env);
+    let to = Address::generate(&env);
+
+    client.add_minter(&creator, &0, &minter, &500, &0);
+    client.remove_minter(&creator, &0, &minter);
+
+    assert_eq!(client.get_minter_allowance(&0, &minter), 0);
+    assert_eq!(
+        client.try_mint(&minter, &0, &to, &1),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_remove_minter(&creator, &0, &minter),
+        Err(Ok(Error::InvalidParameters))
+    );
+}
+
+#[test]
+fn test_only_creator_manages_minters() {
+    let (env, client, _creator) = setup(None);
+    let stranger = Address::generate(&env);
+    let minter = Address::generate(&env);
+
+    assert_eq!(
+        client.try_add_minter(&stranger, &0, &minter, &500, &0),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_add_minter_validates_input() {
+    let (env, client, creator) = setup(None);
+    let minter = Address::generate(&env);
+
+    assert_eq!(
+        client.try_add_minter(&creator, &0, &minter, &0, &0),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_add_minter(&creator, &0, &creator, &10, &0),
+        Err(Ok(Error::InvalidParameters))
+    );
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    assert_eq!(
+        client.try_add_minter(&creator, &0, &minter, &10, &1_000),
+        Err(Ok(Error::InvalidParameters))
+    );
+}
+
+#[test]
+fn test_creator_mint_is_unlimited_by_allowance() {
+    let (env, client, creator) = setup(None);
+    let to = Address::generate(&env);
+
+    client.mint(&creator, &0, &to, &10_000);
+
+    assert_eq!(client.get_token_info(&0).total_supply, 11_000);
+}
@@ -1,7 +1,11 @@
 use soroban_sdk::{Address, Env, Vec};
 
 use crate::storage;
-use crate::types::{Error, PaginatedTokens, PaginationCursor, TokenInfo};
+use crate::stream_types;
+use crate::types::{
+    ClawbackPage, Error, HolderPage, PaginatedTokens, PaginationCursor, StreamAmendmentPage,
+    StreamInfoPage, StreamPage, StreamStatus, TokenInfo,
+};
 
 const MAX_PAGE_SIZE: u32 = 100;
 const DEFAULT_PAGE_SIZE: u32 = 20;
@@ -73,6 +77,196 @@ pub fn get_creator_token_count(env: &Env, creator: &Address) -> u32 {
     storage::get_creator_token_count(env, creator)
 }
 
+/// List holders of a token with their balances
+///
+/// `cursor` is the holder position to start from (0 for the first page);
+/// `limit` is capped at 100.
+pub fn get_holders(env: &Env, token_index: u32, cursor: u32, limit: u32) -> HolderPage {
+    let limit = limit.min(MAX_PAGE_SIZE);
+    let total = storage::get_holder_count(env, token_index);
+
+    let mut holders = Vec::new(env);
+    let mut position = cursor;
+    while position < total && position - cursor < limit {
+        if let Some(holder) = storage::get_holder_at(env, token_index, position) {
+            let balance = storage::get_balance(env, token_index, &holder);
+            holders.push_back((holder, balance));
+        }
+        position += 1;
+    }
+
+    let next_cursor = if position < total { Some(position) } else { None };
+
+    HolderPage {
+        holders,
+        next_cursor,
+    }
+}
+
+/// List a token's clawback records, oldest first
+///
+/// `cursor` is the record number to start from (0 for the first page);
+/// `limit` is capped at 100.
+pub fn get_clawback_log(env: &Env, token_index: u32, cursor: u32, limit: u32) -> ClawbackPage {
+    let limit = limit.min(MAX_PAGE_SIZE);
+    let total = storage::get_clawback_log_count(env, token_index);
+
+    let mut records = Vec::new(env);
+    let mut position = cursor;
+    while position < total && position - cursor < limit {
+        if let Some(record) = storage::get_clawback_record(env, token_index, position) {
+            records.push_back(record);
+        }
+        position += 1;
+    }
+
+    let next_cursor = if position < total { Some(position) } else { None };
+
+    ClawbackPage {
+        records,
+        next_cursor,
+    }
+}
+
+/// List a stream's amendments, oldest first
+///
+/// `cursor` is the amendment number to start from (0 for the first page);
+/// `limit` is capped at 100.
+pub fn get_stream_amendments(
+    env: &Env,
+    stream_id: u64,
+    cursor: u32,
+    limit: u32,
+) -> StreamAmendmentPage {
+    let limit = limit.min(MAX_PAGE_SIZE);
+    let total = storage::get_stream_amendment_count(env, stream_id);
+
+    let mut amendments = Vec::new(env);
+    let mut position = cursor;
+    while position < total && position - cursor < limit {
+        if let Some(amendment) = storage::get_stream_amendment(env, stream_id, position) {
+            amendments.push_back(amendment);
+        }
+        position += 1;
+    }
+
+    let next_cursor = if position < total { Some(position) } else { None };
+
+    StreamAmendmentPage {
+        amendments,
+        next_cursor,
+    }
+}
+
+/// List the ids of streams paying a recipient
+///
+/// `cursor` is the index position to start from (0 for the first page);
+/// `limit` is capped at 50. Positions shift when a stream is transferred
+/// away, so pages read across transfers may skip or repeat an entry.
+pub fn get_stream_ids_by_recipient(
+    env: &Env,
+    recipient: &Address,
+    cursor: u32,
+    limit: u32,
+) -> StreamPage {
+    let limit = limit.min(50);
+    let total = storage::get_recipient_stream_count(env, recipient);
+
+    let mut stream_ids = Vec::new(env);
+    let mut position = cursor;
+    while position < total && position - cursor < limit {
+        if let Some(stream_id) = storage::get_recipient_stream_at(env, recipient, position) {
+            stream_ids.push_back(stream_id);
+        }
+        position += 1;
+    }
+
+    let next_cursor = if position < total { Some(position) } else { None };
+
+    StreamPage {
+        stream_ids,
+        next_cursor,
+    }
+}
+
+/// List streams paying a recipient, optionally only those in `status`
+///
+/// See `get_stream_ids_by_recipient` for how positions move on transfers.
+pub fn get_streams_by_recipient(
+    env: &Env,
+    recipient: &Address,
+    status: Option<StreamStatus>,
+    cursor: u32,
+    limit: u32,
+) -> StreamInfoPage {
+    let total = storage::get_recipient_stream_count(env, recipient);
+    stream_page(env, total, status, cursor, limit, |position| {
+        storage::get_recipient_stream_at(env, recipient, position)
+    })
+}
+
+/// List streams created by a sender, oldest first
+pub fn get_streams_by_sender(
+    env: &Env,
+    sender: &Address,
+    status: Option<StreamStatus>,
+    cursor: u32,
+    limit: u32,
+) -> StreamInfoPage {
+    let total = storage::get_creator_stream_count(env, sender);
+    stream_page(env, total, status, cursor, limit, |position| {
+        storage::get_creator_stream_at(env, sender, position)
+    })
+}
+
+/// List streams of a token, oldest first
+pub fn get_streams_by_token(
+    env: &Env,
+    token_index: u32,
+    status: Option<StreamStatus>,
+    cursor: u32,
+    limit: u32,
+) -> StreamInfoPage {
+    let total = storage::get_token_stream_count(env, token_index);
+    stream_page(env, total, status, cursor, limit, |position| {
+        storage::get_token_stream_at(env, token_index, position)
+    })
+}
+
+/// Read up to `limit` (capped at 100) positions of a stream index
+///
+/// The limit bounds the positions scanned rather than the streams
+/// returned, so filtered pages stay cheap however sparse the matches are.
+/// Streams whose entries have expired are skipped.
+fn stream_page(
+    env: &Env,
+    total: u32,
+    status: Option<StreamStatus>,
+    cursor: u32,
+    limit: u32,
+    stream_at: impl Fn(u32) -> Option<u64>,
+) -> StreamInfoPage {
+    let limit = limit.min(MAX_PAGE_SIZE);
+
+    let mut streams = Vec::new(env);
+    let mut position = cursor;
+    while position < total && position - cursor < limit {
+        if let Some(stream) = stream_at(position).and_then(|id| storage::get_stream(env, id)) {
+            if status.is_none_or(|s| stream_types::stream_status(&stream) == s) {
+                streams.push_back(stream);
+            }
+        }
+        position += 1;
+    }
+
+    let next_cursor = if position < total { Some(position) } else { None };
+
+    StreamInfoPage {
+        streams,
+        next_cursor,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,241 @@
+//! Per-Operation Pause Flag Tests
+//!
+//! Tests that each entry point honours its own pause flag instead of a
+//! single global pause bit
+
+use soroban_sdk::{testutils::Address as _, vec, Address, BytesN};
+
+use crate::test_helpers::{create_stream, create_token, token_params, TestEnv, BASE_FEE};
+use crate::types::{
+    Error, PAUSE_ALL, PAUSE_BURN, PAUSE_CREATE, PAUSE_MINT, PAUSE_STREAM_CLAIM,
+    PAUSE_STREAM_CREATE, PAUSE_TOKEN_CONTROLS, PAUSE_TRANSFER, PAUSE_TREASURY_WITHDRAW,
+    PAUSE_VAULT_CANCEL, PAUSE_VAULT_CLAIM, PAUSE_VAULT_CREATE,
+};
+#[test]
+fn test_pause_flags_default_to_zero() {
+    let TestEnv { client, .. } = TestEnv::initialized();
+
+    assert_eq!(client.get_pause_flags(), 0);
+    assert!(!client.is_paused());
+}
+
+#[test]
+fn test_set_pause_flags_round_trip() {
+    let TestEnv { client, admin, .. } = TestEnv::initialized();
+    let flags = PAUSE_VAULT_CLAIM | PAUSE_VAULT_CANCEL;
+
+    client.set_pause_flags(&admin, &flags);
+
+    assert_eq!(client.get_pause_flags(), flags);
+    // A partial pause is not the legacy global pause
+    assert!(!client.is_paused());
+}
+
+#[test]
+fn test_set_pause_flags_rejects_unknown_bits() {
+    let TestEnv { client, admin, .. } = TestEnv::initialized();
+
+    assert_eq!(
+        client.try_set_pause_flags(&admin, &(PAUSE_ALL + 1)),
+        Err(Ok(Error::InvalidParameters))
+    );
+}
+
+#[test]
+fn test_set_pause_flags_requires_admin() {
+    let TestEnv { env, client, .. } = TestEnv::initialized();
+    let stranger = Address::generate(&env);
+
+    assert_eq!(
+        client.try_set_pause_flags(&stranger, &PAUSE_MINT),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_global_pause_sets_every_flag() {
+    let TestEnv { client, admin, .. } = TestEnv::initialized();
+
+    client.pause(&admin);
+    assert_eq!(client.get_pause_flags(), PAUSE_ALL);
+    assert!(client.is_paused());
+
+    client.unpause(&admin);
+    assert_eq!(client.get_pause_flags(), 0);
+}
+
+#[test]
+fn test_vault_pause_does_not_block_creation() {
+    let TestEnv {
+        env, client, admin, ..
+    } = TestEnv::initialized();
+    let creator = Address::generate(&env);
+
+    client.set_pause_flags(&admin, &(PAUSE_VAULT_CREATE | PAUSE_VAULT_CLAIM));
+
+    let token = create_token(&client, &creator, "PSE", 1_000_000);
+    let owner = Address::generate(&env);
+    let result = client.try_create_vault(
+        &creator,
+        &token,
+        &owner,
+        &1_000,
+        &1,
+        &BytesN::from_array(&env, &[0u8; 32]),
+    );
+    assert_eq!(result, Err(Ok(Error::ContractPaused)));
+}
+
+#[test]
+fn test_create_flag_blocks_token_creation() {
+    let TestEnv {
+        env, client, admin, ..
+    } = TestEnv::initialized();
+    let creator = Address::generate(&env);
+
+    client.set_pause_flags(&admin, &PAUSE_CREATE);
+
+    let params = token_params(&env, "PSE", 1_000_000);
+    let result = client.try_set_metadata(&creator, &vec![&env, params], &BASE_FEE);
+    assert_eq!(result, Err(Ok(Error::ContractPaused)));
+}
+
+#[test]
+fn test_mint_and_burn_flags_are_independent() {
+    let TestEnv {
+        env, client, admin, ..
+    } = TestEnv::initialized();
+    let creator = Address::generate(&env);
+    create_token(&client, &creator, "PSE", 1_000_000);
+
+    client.set_pause_flags(&admin, &PAUSE_BURN);
+
+    assert_eq!(
+        client.try_burn(&creator, &0, &100),
+        Err(Ok(Error::ContractPaused))
+    );
+    client.mint(&creator, &0, &creator, &100);
+
+    client.set_pause_flags(&admin, &PAUSE_MINT);
+
+    assert_eq!(
+        client.try_mint(&creator, &0, &creator, &100),
+        Err(Ok(Error::ContractPaused))
+    );
+    client.burn(&creator, &0, &100);
+}
+
+#[test]
+fn test_treasury_withdraw_flag() {
+    let TestEnv {
+        env, client, admin, ..
+    } = TestEnv::initialized();
+    let recipient = Address::generate(&env);
+
+    client.set_pause_flags(&admin, &PAUSE_TREASURY_WITHDRAW);
+
+    assert_eq!(
+        client.try_withdraw_fees(&admin, &recipient, &1_000),
+        Err(Ok(Error::ContractPaused))
+    );
+}
+
+#[test]
+fn test_vault_cancel_flag() {
+    let TestEnv { client, admin, .. } = TestEnv::initialized();
+
+    client.set_pause_flags(&admin, &PAUSE_VAULT_CANCEL);
+
+    assert_eq!(
+        client.try_cancel_vault(&1, &admin),
+        Err(Ok(Error::ContractPaused))
+    );
+}
+
+#[test]
+fn test_stream_create_flag_blocks_top_ups() {
+    let TestEnv {
+        env, client, admin, ..
+    } = TestEnv::initialized();
+    let creator = Address::generate(&env);
+    create_token(&client, &creator, "PSE", 1_000_000);
+    let recipient = Address::generate(&env);
+    let id = create_stream(&client, &creator, &recipient, 1_000, 0, 0, 100);
+
+    client.set_pause_flags(&admin, &PAUSE_STREAM_CREATE);
+    assert_eq!(
+        client.try_top_up_stream(&creator, &id, &500, &200),
+        Err(Ok(Error::ContractPaused))
+    );
+
+    client.set_pause_flags(&admin, &0);
+    client.top_up_stream(&creator, &id, &500, &200);
+    assert_eq!(client.get_stream(&id).unwrap().total_amount, 1_500);
+}
+
+#[test]
+fn test_stream_claim_flag_blocks_transfers() {
+    let TestEnv {
+        env, client, admin, ..
+    } = TestEnv::initialized();
+    let creator = Address::generate(&env);
+    create_token(&client, &creator, "PSE", 1_000_000);
+    let recipient = Address::generate(&env);
+    let id = create_stream(&client, &creator, &recipient, 1_000, 0, 0, 100);
+    let new_recipient = Address::generate(&env);
+
+    client.set_pause_flags(&admin, &PAUSE_STREAM_CLAIM);
+    assert_eq!(
+        client.try_transfer_stream(&id, &recipient, &new_recipient),
+        Err(Ok(Error::ContractPaused))
+    );
+
+    client.set_pause_flags(&admin, &0);
+    client.transfer_stream(&id, &recipient, &new_recipient);
+    assert_eq!(client.get_stream(&id).unwrap().recipient, new_recipient);
+}
+
+#[test]
+fn test_partial_masks_reach_staking_and_token_controls() {
+    let TestEnv {
+        env, client, admin, ..
+    } = TestEnv::initialized();
+    let creator = Address::generate(&env);
+    let token = create_token(&client, &creator, "PSE", 1_000_000);
+    let pool = client.create_staking_pool(&creator, &0, &0, &0, &0);
+
+    client.set_pause_flags(&admin, &PAUSE_TRANSFER);
+    assert_eq!(
+        client.try_stake(&creator, &pool, &100),
+        Err(Ok(Error::ContractPaused))
+    );
+    client.set_clawback(&token, &creator, &true);
+
+    client.set_pause_flags(&admin, &PAUSE_TOKEN_CONTROLS);
+    assert_eq!(
+        client.try_set_clawback(&token, &creator, &false),
+        Err(Ok(Error::ContractPaused))
+    );
+    client.stake(&creator, &pool, &100);
+}
+
+#[test]
+fn test_legacy_global_pause_survives_upgrade() {
+    let TestEnv {
+        env, client, admin, ..
+    } = TestEnv::initialized();
+
+    // Contracts paused before flags existed only hold the old bit
+    env.as_contract(&client.address, || {
+        env.storage()
+            .instance()
+            .set(&crate::types::ExtDataKey::Paused, &true);
+    });
+    assert_eq!(client.get_pause_flags(), PAUSE_ALL);
+    assert!(client.is_paused());
+
+    client.set_pause_flags(&admin, &PAUSE_MINT);
+    assert_eq!(client.get_pause_flags(), PAUSE_MINT);
+    client.unpause(&admin);
+    assert!(!client.is_paused());
+}
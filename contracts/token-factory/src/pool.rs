@@ -0,0 +1,508 @@
+//! Payroll Pools
+//!
+//! One funded balance streamed to many members. Each member is paid either
+//! a fixed per-second rate or a share of the pool's `weighted_rate`
+//! proportional to their weight. Weighted accrual uses the same
+//! accumulator as staking rewards:
+//!
+//! ```text
+//! reward_per_weight += elapsed * weighted_rate * POOL_PRECISION / total_weight
+//! accrued += rate_per_second * elapsed
+//!          + weight * (reward_per_weight - reward_per_weight_paid) / POOL_PRECISION
+//! ```
+//!
+//! Both products go through a 256-bit intermediate. Weights are whole
+//! numbers, so `POOL_PRECISION` only needs to resolve fractions of a unit
+//! per weight and stays small enough for 18-decimal rates.
+//!
+//! The pool tracks what it owes in total the way a flow stream does, except
+//! that weighted accrual is counted from the accumulator:
+//!
+//! ```text
+//! total_debt = snapshot_debt + fixed_rate_total * (now - snapshot_time)
+//!            + (total_weight * reward_per_weight - weight_paid_total) / POOL_PRECISION
+//! ```
+//!
+//! Settling a member moves their rounded weighted accrual into
+//! `snapshot_debt`, so rounding dust returns to the surplus instead of
+//! staying in the debt.
+//!
+//! The pool and the member are settled before any change to a member, so
+//! adding, removing or reweighting never rewrites what has already accrued.
+//! When the balance runs out members keep accruing and are paid in the
+//! order they withdraw; only the surplus above the total debt can be
+//! refunded to the creator.
+
+use crate::events;
+use crate::math::mul_div;
+use crate::storage;
+use crate::streaming::{escrow_from, release_to};
+use crate::types::{
+    Error, PayrollPool, PoolMember, PoolSolvency, PAUSE_STREAM_CLAIM, PAUSE_STREAM_CREATE,
+};
+use soroban_sdk::{Address, Env};
+
+/// Scale of `reward_per_weight`
+pub const POOL_PRECISION: i128 = 1_000_000_000;
+
+/// Open a payroll pool, optionally with an initial deposit
+///
+/// # Returns
+/// * `Ok(pool_id)` - Pool created
+/// * `Err(Error::ContractPaused)` - Stream creation is paused
+/// * `Err(Error::TokenNotFound)` - Token doesn't exist
+/// * `Err(Error::InvalidAmount)` - Weighted rate or deposit is negative
+/// * `Err(Error::InsufficientBalance)` - Creator cannot fund the deposit
+pub fn create(
+    env: &Env,
+    creator: &Address,
+    token_index: u32,
+    weighted_rate: i128,
+    deposit: i128,
+) -> Result<u64, Error> {
+    creator.require_auth();
+
+    if storage::is_operation_paused(env, PAUSE_STREAM_CREATE) {
+        return Err(Error::ContractPaused);
+    }
+
+    storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+
+    if weighted_rate < 0 || deposit < 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    if deposit > 0 {
+        escrow_from(env, token_index, creator, deposit)?;
+    }
+
+    let pool_id = storage::get_payroll_pool_count(env);
+    let next_id = pool_id.checked_add(1).ok_or(Error::ArithmeticError)?;
+
+    storage::set_payroll_pool(
+        env,
+        pool_id,
+        &PayrollPool {
+            creator: creator.clone(),
+            token_index,
+            balance: deposit,
+            fixed_rate_total: 0,
+            weighted_rate,
+            total_weight: 0,
+            reward_per_weight: 0,
+            weight_paid_total: 0,
+            snapshot_debt: 0,
+            snapshot_time: env.ledger().timestamp(),
+            member_count: 0,
+        },
+    );
+    storage::set_payroll_pool_count(env, next_id);
+
+    events::emit_pool_created(env, pool_id, creator, token_index, weighted_rate, deposit);
+
+    Ok(pool_id)
+}
+
+/// Add runway to a pool (creator only)
+///
+/// # Returns
+/// * `Ok(())` - Deposit added
+/// * `Err(Error::StreamNotFound)` - Pool doesn't exist
+/// * `Err(Error::Unauthorized)` - Caller is not the creator
+/// * `Err(Error::InvalidAmount)` - Amount is not positive
+/// * `Err(Error::InsufficientBalance)` - Creator cannot fund the deposit
+pub fn deposit(env: &Env, creator: &Address, pool_id: u64, amount: i128) -> Result<(), Error> {
+    creator.require_auth();
+
+    let mut pool = load_as_creator(env, creator, pool_id)?;
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    escrow_from(env, pool.token_index, creator, amount)?;
+    pool.balance = pool
+        .balance
+        .checked_add(amount)
+        .ok_or(Error::ArithmeticError)?;
+    storage::set_payroll_pool(env, pool_id, &pool);
+
+    events::emit_pool_deposited(env, pool_id, creator, amount);
+    Ok(())
+}
+
+/// Add a member or change their terms (creator only)
+///
+/// A member is paid either a fixed `rate_per_second` or a share of the
+/// pool's weighted rate by `weight`, so exactly one of the two must be
+/// non-zero. Whatever accrued under the old terms stays withdrawable.
+///
+/// # Returns
+/// * `Ok(())` - Member updated
+/// * `Err(Error::StreamNotFound)` - Pool doesn't exist
+/// * `Err(Error::Unauthorized)` - Caller is not the creator
+/// * `Err(Error::InvalidParameters)` - Creator adds itself, or not exactly
+///   one of rate and weight is set
+/// * `Err(Error::InvalidAmount)` - Rate is negative
+pub fn set_member(
+    env: &Env,
+    creator: &Address,
+    pool_id: u64,
+    member: &Address,
+    rate_per_second: i128,
+    weight: u32,
+) -> Result<(), Error> {
+    creator.require_auth();
+
+    let mut pool = load_as_creator(env, creator, pool_id)?;
+    if member == creator {
+        return Err(Error::InvalidParameters);
+    }
+    if rate_per_second < 0 {
+        return Err(Error::InvalidAmount);
+    }
+    if (rate_per_second == 0) == (weight == 0) {
+        return Err(Error::InvalidParameters);
+    }
+
+    let now = env.ledger().timestamp();
+    settle_pool(&mut pool, now)?;
+    let mut position = match storage::get_pool_member(env, pool_id, member) {
+        Some(mut position) => {
+            settle_member(&mut pool, &mut position, now)?;
+            position
+        }
+        None => {
+            pool.member_count = pool
+                .member_count
+                .checked_add(1)
+                .ok_or(Error::ArithmeticError)?;
+            PoolMember {
+                rate_per_second: 0,
+                weight: 0,
+                accrued: 0,
+                reward_per_weight_paid: pool.reward_per_weight,
+                snapshot_time: now,
+                withdrawn: 0,
+            }
+        }
+    };
+
+    pool.fixed_rate_total = (pool.fixed_rate_total - position.rate_per_second)
+        .checked_add(rate_per_second)
+        .ok_or(Error::ArithmeticError)?;
+    pool.total_weight = (pool.total_weight - position.weight)
+        .checked_add(weight)
+        .ok_or(Error::ArithmeticError)?;
+    reweight(&mut pool, &position, weight)?;
+    position.rate_per_second = rate_per_second;
+    position.weight = weight;
+
+    storage::set_pool_member(env, pool_id, member, &position);
+    storage::set_payroll_pool(env, pool_id, &pool);
+
+    events::emit_pool_member_set(env, pool_id, member, rate_per_second, weight);
+    Ok(())
+}
+
+/// Stop a member's accrual (creator only)
+///
+/// The member keeps what accrued up to now and can still withdraw it.
+///
+/// # Returns
+/// * `Ok(())` - Member removed
+/// * `Err(Error::StreamNotFound)` - Pool doesn't exist
+/// * `Err(Error::Unauthorized)` - Caller is not the creator
+/// * `Err(Error::InvalidParameters)` - Address is not an active member
+pub fn remove_member(
+    env: &Env,
+    creator: &Address,
+    pool_id: u64,
+    member: &Address,
+) -> Result<(), Error> {
+    creator.require_auth();
+
+    let mut pool = load_as_creator(env, creator, pool_id)?;
+    let mut position =
+        storage::get_pool_member(env, pool_id, member).ok_or(Error::InvalidParameters)?;
+    if position.rate_per_second == 0 && position.weight == 0 {
+        return Err(Error::InvalidParameters);
+    }
+
+    let now = env.ledger().timestamp();
+    settle_pool(&mut pool, now)?;
+    settle_member(&mut pool, &mut position, now)?;
+
+    pool.fixed_rate_total -= position.rate_per_second;
+    pool.total_weight -= position.weight;
+    reweight(&mut pool, &position, 0)?;
+    position.rate_per_second = 0;
+    position.weight = 0;
+
+    store_member(env, &mut pool, pool_id, member, &position);
+    storage::set_payroll_pool(env, pool_id, &pool);
+
+    events::emit_pool_member_removed(env, pool_id, member, position.accrued);
+    Ok(())
+}
+
+/// Change the per-second amount shared by weighted members (creator only)
+///
+/// # Returns
+/// * `Ok(())` - Rate changed
+/// * `Err(Error::StreamNotFound)` - Pool doesn't exist
+/// * `Err(Error::Unauthorized)` - Caller is not the creator
+/// * `Err(Error::InvalidAmount)` - Rate is negative
+pub fn set_weighted_rate(
+    env: &Env,
+    creator: &Address,
+    pool_id: u64,
+    weighted_rate: i128,
+) -> Result<(), Error> {
+    creator.require_auth();
+
+    let mut pool = load_as_creator(env, creator, pool_id)?;
+    if weighted_rate < 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    settle_pool(&mut pool, env.ledger().timestamp())?;
+    let old_rate = pool.weighted_rate;
+    pool.weighted_rate = weighted_rate;
+    storage::set_payroll_pool(env, pool_id, &pool);
+
+    events::emit_pool_rate_set(env, pool_id, old_rate, weighted_rate);
+    Ok(())
+}
+
+/// Return part of the balance not owed to members (creator only)
+///
+/// # Returns
+/// * `Ok(())` - Amount returned to the creator's balance
+/// * `Err(Error::StreamNotFound)` - Pool doesn't exist
+/// * `Err(Error::Unauthorized)` - Caller is not the creator
+/// * `Err(Error::InvalidAmount)` - Amount is not positive
+/// * `Err(Error::InsufficientBalance)` - Amount exceeds the surplus over the debt
+pub fn refund_excess(
+    env: &Env,
+    creator: &Address,
+    pool_id: u64,
+    amount: i128,
+) -> Result<(), Error> {
+    creator.require_auth();
+
+    let mut pool = load_as_creator(env, creator, pool_id)?;
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    let surplus = pool.balance - total_debt(&pool, env.ledger().timestamp())?;
+    if amount > surplus {
+        return Err(Error::InsufficientBalance);
+    }
+
+    pool.balance -= amount;
+    storage::set_payroll_pool(env, pool_id, &pool);
+    release_to(env, pool.token_index, creator, amount)?;
+
+    events::emit_pool_refunded(env, pool_id, creator, amount);
+    Ok(())
+}
+
+/// Withdraw everything accrued to `member` that the balance covers
+///
+/// # Returns
+/// * `Ok(amount)` - Amount credited to the member
+/// * `Err(Error::ContractPaused)` - Stream claims are paused
+/// * `Err(Error::StreamNotFound)` - Pool doesn't exist
+/// * `Err(Error::Unauthorized)` - Caller is not a member
+/// * `Err(Error::TokenPaused)` - Token is paused
+/// * `Err(Error::NothingToClaim)` - Nothing withdrawable
+pub fn withdraw(env: &Env, member: &Address, pool_id: u64) -> Result<i128, Error> {
+    member.require_auth();
+
+    if storage::is_operation_paused(env, PAUSE_STREAM_CLAIM) {
+        return Err(Error::ContractPaused);
+    }
+
+    let mut pool = storage::get_payroll_pool(env, pool_id).ok_or(Error::StreamNotFound)?;
+    let mut position = storage::get_pool_member(env, pool_id, member).ok_or(Error::Unauthorized)?;
+
+    crate::compliance::require_eligible(env, pool.token_index, member)?;
+
+    if storage::is_token_paused(env, pool.token_index) {
+        return Err(Error::TokenPaused);
+    }
+
+    let now = env.ledger().timestamp();
+    settle_pool(&mut pool, now)?;
+    settle_member(&mut pool, &mut position, now)?;
+    let amount = position.accrued.min(pool.balance);
+    if amount == 0 {
+        return Err(Error::NothingToClaim);
+    }
+
+    position.accrued -= amount;
+    position.withdrawn = position
+        .withdrawn
+        .checked_add(amount)
+        .ok_or(Error::ArithmeticError)?;
+    pool.balance -= amount;
+    pool.snapshot_debt -= amount;
+
+    store_member(env, &mut pool, pool_id, member, &position);
+    storage::set_payroll_pool(env, pool_id, &pool);
+    release_to(env, pool.token_index, member, amount)?;
+
+    events::emit_pool_withdrawn(env, pool_id, member, amount, position.accrued);
+    Ok(amount)
+}
+
+/// Amount `member` could withdraw at `now`
+pub fn withdrawable(pool: &PayrollPool, member: &PoolMember, now: u64) -> Result<i128, Error> {
+    let mut pool = pool.clone();
+    let mut member = member.clone();
+    settle_pool(&mut pool, now)?;
+    settle_member(&mut pool, &mut member, now)?;
+    Ok(member.accrued.min(pool.balance))
+}
+
+/// Amount owed to all members at `now`, covered or not
+///
+/// Unsettled weighted accrual is rounded down as a whole, which is never
+/// less than the members' individually rounded shares.
+pub fn total_debt(pool: &PayrollPool, now: u64) -> Result<i128, Error> {
+    let mut pool = pool.clone();
+    settle_pool(&mut pool, now)?;
+    let weighted = (pool.total_weight as i128)
+        .checked_mul(pool.reward_per_weight)
+        .and_then(|v| v.checked_sub(pool.weight_paid_total))
+        .ok_or(Error::ArithmeticError)?
+        / POOL_PRECISION;
+    pool.snapshot_debt
+        .checked_add(weighted)
+        .ok_or(Error::ArithmeticError)
+}
+
+/// Balance, debt and remaining runway of a pool at `now`
+pub fn solvency(pool: &PayrollPool, now: u64) -> Result<PoolSolvency, Error> {
+    let total_debt = total_debt(pool, now)?;
+    let outflow_rate = outflow_rate(pool);
+    let runway_secs = if outflow_rate == 0 {
+        None
+    } else {
+        let runway = (pool.balance - total_debt).max(0) / outflow_rate;
+        Some(u64::try_from(runway).unwrap_or(u64::MAX))
+    };
+
+    Ok(PoolSolvency {
+        balance: pool.balance,
+        total_debt,
+        outflow_rate,
+        runway_secs,
+        depletion_time: runway_secs.map(|runway| now.saturating_add(runway)),
+    })
+}
+
+/// Amount owed per second across all members
+fn outflow_rate(pool: &PayrollPool) -> i128 {
+    if pool.total_weight == 0 {
+        pool.fixed_rate_total
+    } else {
+        pool.fixed_rate_total + pool.weighted_rate
+    }
+}
+
+/// Fold fixed-rate debt and weighted rewards into the pool snapshot
+fn settle_pool(pool: &mut PayrollPool, now: u64) -> Result<(), Error> {
+    let elapsed = now.saturating_sub(pool.snapshot_time) as i128;
+    if pool.total_weight > 0 {
+        let emitted = pool
+            .weighted_rate
+            .checked_mul(elapsed)
+            .ok_or(Error::ArithmeticError)?;
+        let increment = mul_div(emitted, POOL_PRECISION, pool.total_weight as i128)?;
+        pool.reward_per_weight = pool
+            .reward_per_weight
+            .checked_add(increment)
+            .ok_or(Error::ArithmeticError)?;
+    }
+    pool.snapshot_debt = pool
+        .fixed_rate_total
+        .checked_mul(elapsed)
+        .and_then(|accrued| accrued.checked_add(pool.snapshot_debt))
+        .ok_or(Error::ArithmeticError)?;
+    pool.snapshot_time = now;
+    Ok(())
+}
+
+/// Fold a member's accrual into `accrued`; the pool must be settled first
+///
+/// Their rounded weighted accrual moves into the pool's `snapshot_debt`.
+fn settle_member(pool: &mut PayrollPool, member: &mut PoolMember, now: u64) -> Result<(), Error> {
+    let elapsed = now.saturating_sub(member.snapshot_time) as i128;
+    let fixed = member
+        .rate_per_second
+        .checked_mul(elapsed)
+        .ok_or(Error::ArithmeticError)?;
+    let weight = member.weight as i128;
+    let weighted = mul_div(
+        weight,
+        pool.reward_per_weight - member.reward_per_weight_paid,
+        POOL_PRECISION,
+    )?;
+
+    member.accrued = member
+        .accrued
+        .checked_add(fixed)
+        .and_then(|v| v.checked_add(weighted))
+        .ok_or(Error::ArithmeticError)?;
+    pool.snapshot_debt = pool
+        .snapshot_debt
+        .checked_add(weighted)
+        .ok_or(Error::ArithmeticError)?;
+    reweight(pool, member, member.weight)?;
+    member.reward_per_weight_paid = pool.reward_per_weight;
+    member.snapshot_time = now;
+    Ok(())
+}
+
+/// Update `weight_paid_total` for a member settled at the current
+/// accumulator whose weight becomes `new_weight`
+fn reweight(pool: &mut PayrollPool, member: &PoolMember, new_weight: u32) -> Result<(), Error> {
+    let paid_before = (member.weight as i128)
+        .checked_mul(member.reward_per_weight_paid)
+        .ok_or(Error::ArithmeticError)?;
+    let paid_after = (new_weight as i128)
+        .checked_mul(pool.reward_per_weight)
+        .ok_or(Error::ArithmeticError)?;
+    pool.weight_paid_total = pool
+        .weight_paid_total
+        .checked_sub(paid_before)
+        .and_then(|v| v.checked_add(paid_after))
+        .ok_or(Error::ArithmeticError)?;
+    Ok(())
+}
+
+/// Save a member, dropping removed members once they are paid in full
+fn store_member(
+    env: &Env,
+    pool: &mut PayrollPool,
+    pool_id: u64,
+    member: &Address,
+    position: &PoolMember,
+) {
+    if position.rate_per_second == 0 && position.weight == 0 && position.accrued == 0 {
+        storage::remove_pool_member(env, pool_id, member);
+        pool.member_count -= 1;
+    } else {
+        storage::set_pool_member(env, pool_id, member, position);
+    }
+}
+
+fn load_as_creator(env: &Env, creator: &Address, pool_id: u64) -> Result<PayrollPool, Error> {
+    let pool = storage::get_payroll_pool(env, pool_id).ok_or(Error::StreamNotFound)?;
+    if pool.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+    Ok(pool)
+}
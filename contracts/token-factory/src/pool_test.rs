@@ -0,0 +1,238 @@
+//! Payroll Pool Tests
+//!
+//! Tests for pools streaming one funded balance to fixed-rate and weighted
+//! members, settlement on membership changes, and the runway view
+
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    vec, Address, IntoVal, Symbol,
+};
+
+use crate::test_helpers::{create_tokens, set_time, token_params, StreamFixture, TestEnv};
+use crate::types::{Error, PoolSolvency, TokenCreationParams};
+
+#[test]
+fn test_fixed_rate_members_accrue_and_run_dry() {
+    let s = StreamFixture::new("POOL", 1_000_000);
+    let pool = s.client.create_payroll_pool(&s.creator, &0, &0, &1_000);
+    s.client
+        .set_pool_member(&s.creator, &pool, &s.alice, &2, &0);
+    s.client.set_pool_member(&s.creator, &pool, &s.bob, &3, &0);
+    assert_eq!(s.client.get_balance(&0, &s.creator), 999_000);
+
+    set_time(&s.env, 100);
+    assert_eq!(s.client.get_pool_withdrawable(&pool, &s.bob), 300);
+    assert_eq!(s.client.withdraw_from_pool(&s.alice, &pool), 200);
+
+    let (_, topics, data) = s.env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (Symbol::new(&s.env, "pool_wdr"), pool).into_val(&s.env)
+    );
+    let payload: (Address, i128, i128) = data.into_val(&s.env);
+    assert_eq!(payload, (s.alice.clone(), 200, 0));
+
+    assert_eq!(
+        s.client.get_pool_solvency(&pool),
+        PoolSolvency {
+            balance: 800,
+            total_debt: 300,
+            outflow_rate: 5,
+            runway_secs: Some(100),
+            depletion_time: Some(200),
+        }
+    );
+
+    // Past the runway the first to withdraw drains the balance
+    set_time(&s.env, 300);
+    let solvency = s.client.get_pool_solvency(&pool);
+    assert_eq!(
+        (solvency.total_debt, solvency.runway_secs),
+        (1_300, Some(0))
+    );
+    assert_eq!(s.client.withdraw_from_pool(&s.bob, &pool), 800);
+    assert_eq!(
+        s.client.try_withdraw_from_pool(&s.alice, &pool),
+        Err(Ok(Error::NothingToClaim))
+    );
+
+    // A new deposit pays the outstanding debt first
+    s.client.deposit_payroll_pool(&s.creator, &pool, &1_000);
+    assert_eq!(s.client.withdraw_from_pool(&s.alice, &pool), 400);
+    assert_eq!(s.client.withdraw_from_pool(&s.bob, &pool), 100);
+    assert_eq!(s.client.get_balance(&0, &s.alice), 600);
+    assert_eq!(s.client.get_balance(&0, &s.bob), 900);
+    assert_eq!(s.client.get_pool_solvency(&pool).balance, 500);
+}
+
+#[test]
+fn test_reweighting_settles_at_change_time() {
+    let s = StreamFixture::new("POOL", 1_000_000);
+    let pool = s.client.create_payroll_pool(&s.creator, &0, &100, &100_000);
+    s.client
+        .set_pool_member(&s.creator, &pool, &s.alice, &0, &1);
+    s.client.set_pool_member(&s.creator, &pool, &s.bob, &0, &1);
+
+    set_time(&s.env, 10);
+    assert_eq!(s.client.get_pool_withdrawable(&pool, &s.alice), 500);
+    s.client.set_pool_member(&s.creator, &pool, &s.bob, &0, &4);
+
+    // Bob's earlier half share is kept; from now on he gets four fifths
+    set_time(&s.env, 20);
+    assert_eq!(s.client.get_pool_withdrawable(&pool, &s.alice), 700);
+    assert_eq!(s.client.get_pool_withdrawable(&pool, &s.bob), 1_300);
+
+    // Moving alice to a fixed rate leaves bob the whole weighted rate
+    s.client
+        .set_pool_member(&s.creator, &pool, &s.alice, &10, &0);
+    set_time(&s.env, 30);
+    assert_eq!(s.client.get_pool_withdrawable(&pool, &s.alice), 800);
+    assert_eq!(s.client.get_pool_withdrawable(&pool, &s.bob), 2_300);
+
+    let solvency = s.client.get_pool_solvency(&pool);
+    assert_eq!((solvency.total_debt, solvency.outflow_rate), (3_100, 110));
+
+    // Halving the weighted rate only affects what accrues afterwards
+    s.client.set_pool_weighted_rate(&s.creator, &pool, &50);
+    set_time(&s.env, 40);
+    assert_eq!(s.client.withdraw_from_pool(&s.bob, &pool), 2_800);
+    assert_eq!(
+        s.client.get_pool_member(&pool, &s.bob).unwrap().withdrawn,
+        2_800
+    );
+}
+
+#[test]
+fn test_removed_member_keeps_accrued_and_surplus_is_refundable() {
+    let s = StreamFixture::new("POOL", 1_000_000);
+    let pool = s.client.create_payroll_pool(&s.creator, &0, &0, &1_000);
+    s.client
+        .set_pool_member(&s.creator, &pool, &s.alice, &5, &0);
+
+    set_time(&s.env, 100);
+    s.client.remove_pool_member(&s.creator, &pool, &s.alice);
+    assert_eq!(
+        s.client.try_remove_pool_member(&s.creator, &pool, &s.alice),
+        Err(Ok(Error::InvalidParameters))
+    );
+
+    let solvency = s.client.get_pool_solvency(&pool);
+    assert_eq!((solvency.total_debt, solvency.outflow_rate), (500, 0));
+    assert_eq!(solvency.runway_secs, None);
+
+    // Only the surplus above what alice is owed can go back
+    assert_eq!(
+        s.client.try_refund_pool_excess(&s.creator, &pool, &501),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    s.client.refund_pool_excess(&s.creator, &pool, &500);
+    assert_eq!(s.client.get_balance(&0, &s.creator), 999_500);
+
+    set_time(&s.env, 200);
+    assert_eq!(s.client.withdraw_from_pool(&s.alice, &pool), 500);
+    assert_eq!(s.client.get_pool_member(&pool, &s.alice), None);
+    assert_eq!(s.client.get_payroll_pool(&pool).unwrap().member_count, 0);
+    assert_eq!(
+        s.client.try_withdraw_from_pool(&s.alice, &pool),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_pool_validation() {
+    let s = StreamFixture::new("POOL", 1_000_000);
+    let pool = s.client.create_payroll_pool(&s.creator, &0, &10, &0);
+
+    assert_eq!(
+        s.client
+            .try_set_pool_member(&s.creator, &pool, &s.alice, &0, &0),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(
+        s.client
+            .try_set_pool_member(&s.creator, &pool, &s.alice, &1, &1),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(
+        s.client
+            .try_set_pool_member(&s.creator, &pool, &s.creator, &1, &0),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(
+        s.client
+            .try_set_pool_member(&s.creator, &pool, &s.alice, &-1, &0),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        s.client
+            .try_set_pool_member(&s.alice, &pool, &s.bob, &1, &0),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.client.try_deposit_payroll_pool(&s.creator, &7, &100),
+        Err(Ok(Error::StreamNotFound))
+    );
+    assert_eq!(
+        s.client.try_create_payroll_pool(&s.creator, &9, &0, &0),
+        Err(Ok(Error::TokenNotFound))
+    );
+
+    // Weighted rate only flows once someone holds weight
+    set_time(&s.env, 50);
+    assert_eq!(s.client.get_pool_solvency(&pool).total_debt, 0);
+    s.client
+        .set_pool_member(&s.creator, &pool, &s.alice, &0, &1);
+    set_time(&s.env, 60);
+    assert_eq!(s.client.get_pool_withdrawable(&pool, &s.alice), 0);
+    assert_eq!(s.client.get_pool_solvency(&pool).total_debt, 100);
+    assert_eq!(
+        s.client.try_withdraw_from_pool(&s.alice, &pool),
+        Err(Ok(Error::NothingToClaim))
+    );
+}
+
+#[test]
+fn test_rounding_dust_returns_to_surplus() {
+    let s = StreamFixture::new("POOL", 1_000_000);
+    let carol = Address::generate(&s.env);
+    let pool = s.client.create_payroll_pool(&s.creator, &0, &10, &100);
+    for member in [&s.alice, &s.bob, &carol] {
+        s.client.set_pool_member(&s.creator, &pool, member, &0, &1);
+    }
+
+    // 10 split three ways pays 3 each; the leftover 1 is owed to nobody
+    set_time(&s.env, 1);
+    for member in [&s.alice, &s.bob, &carol] {
+        assert_eq!(s.client.withdraw_from_pool(member, &pool), 3);
+    }
+    assert_eq!(s.client.get_pool_solvency(&pool).total_debt, 0);
+
+    s.client.refund_pool_excess(&s.creator, &pool, &91);
+    assert_eq!(s.client.get_pool_solvency(&pool).balance, 0);
+}
+
+#[test]
+fn test_18_decimal_weighted_rate_accrues_without_overflow() {
+    const E18: i128 = 1_000_000_000_000_000_000;
+
+    let TestEnv { env, client, .. } = TestEnv::initialized();
+    let creator = Address::generate(&env);
+    let params = TokenCreationParams {
+        decimals: 18,
+        ..token_params(&env, "POOL", 1_000_000_000 * E18)
+    };
+    create_tokens(&client, &creator, vec![&env, params]);
+
+    // One token a second split 1:3 over 30 days
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let pool = client.create_payroll_pool(&creator, &0, &E18, &(10_000_000 * E18));
+    client.set_pool_member(&creator, &pool, &alice, &0, &1);
+    client.set_pool_member(&creator, &pool, &bob, &0, &3);
+
+    set_time(&env, 2_592_000);
+    assert_eq!(client.get_pool_solvency(&pool).total_debt, 2_592_000 * E18);
+    assert_eq!(client.withdraw_from_pool(&alice, &pool), 648_000 * E18);
+    assert_eq!(client.withdraw_from_pool(&bob, &pool), 1_944_000 * E18);
+    assert_eq!(client.get_pool_solvency(&pool).total_debt, 0);
+}
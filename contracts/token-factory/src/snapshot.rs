@@ -0,0 +1,142 @@
+//! Balance and Supply Snapshots
+//!
+//! Point-in-time balance and total supply queries in the style of
+//! ERC20Snapshot. Taking a snapshot only bumps a per-token counter; the
+//! old value of a balance (or of the total supply) is checkpointed the
+//! first time it changes after a snapshot. Snapshot cost therefore does
+//! not depend on the number of holders.
+//!
+//! A query for snapshot `n` looks for the first checkpoint with id `>= n`.
+//! If there is none, the value has not changed since snapshot `n` and the
+//! current value is returned.
+
+use crate::storage;
+use crate::types::{Checkpoint, Error};
+use soroban_sdk::{Address, Env};
+
+/// Take a snapshot of a token's balances and total supply
+///
+/// # Returns
+/// * `Ok(snapshot_id)` - Id of the new snapshot (ids start at 1)
+/// * `Err(Error::TokenNotFound)` - Token doesn't exist
+/// * `Err(Error::Unauthorized)` - Caller is neither the token creator nor the admin
+pub fn snapshot(env: &Env, caller: &Address, token_index: u32) -> Result<u32, Error> {
+    caller.require_auth();
+
+    let token_info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+    if token_info.creator != *caller && *caller != storage::get_admin(env) {
+        return Err(Error::Unauthorized);
+    }
+
+    take_snapshot(env, token_index)
+}
+
+/// Bump the snapshot id of a token without authorization checks
+///
+/// Used by features that pin balances at a point in time, e.g. holder
+/// distributions.
+pub fn take_snapshot(env: &Env, token_index: u32) -> Result<u32, Error> {
+    let snapshot_id = storage::get_snapshot_id(env, token_index)
+        .checked_add(1)
+        .ok_or(Error::ArithmeticError)?;
+    storage::set_snapshot_id(env, token_index, snapshot_id);
+
+    crate::events::emit_snapshot_taken(env, token_index, snapshot_id);
+
+    Ok(snapshot_id)
+}
+
+/// Balance of `holder` at `snapshot_id`
+///
+/// # Returns
+/// * `Ok(balance)` - Balance when the snapshot was taken
+/// * `Err(Error::InvalidParameters)` - Snapshot id is 0 or not taken yet
+pub fn balance_of_at(
+    env: &Env,
+    token_index: u32,
+    holder: &Address,
+    snapshot_id: u32,
+) -> Result<i128, Error> {
+    validate_snapshot_id(env, token_index, snapshot_id)?;
+
+    let count = storage::get_balance_checkpoint_count(env, token_index, holder);
+    let value = find_checkpoint(count, snapshot_id, |position| {
+        storage::get_balance_checkpoint(env, token_index, holder, position)
+    });
+
+    Ok(value.unwrap_or_else(|| storage::get_balance(env, token_index, holder)))
+}
+
+/// Total supply of a token at `snapshot_id`
+///
+/// # Returns
+/// * `Ok(supply)` - Total supply when the snapshot was taken
+/// * `Err(Error::TokenNotFound)` - Token doesn't exist
+/// * `Err(Error::InvalidParameters)` - Snapshot id is 0 or not taken yet
+pub fn total_supply_at(env: &Env, token_index: u32, snapshot_id: u32) -> Result<i128, Error> {
+    let token_info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+    validate_snapshot_id(env, token_index, snapshot_id)?;
+
+    let count = storage::get_supply_checkpoint_count(env, token_index);
+    let value = find_checkpoint(count, snapshot_id, |position| {
+        storage::get_supply_checkpoint(env, token_index, position)
+    });
+
+    Ok(value.unwrap_or(token_info.total_supply))
+}
+
+fn validate_snapshot_id(env: &Env, token_index: u32, snapshot_id: u32) -> Result<(), Error> {
+    if snapshot_id == 0 || snapshot_id > storage::get_snapshot_id(env, token_index) {
+        return Err(Error::InvalidParameters);
+    }
+    Ok(())
+}
+
+/// Binary search for the first checkpoint with id >= `snapshot_id`
+fn find_checkpoint(
+    count: u32,
+    snapshot_id: u32,
+    get: impl Fn(u32) -> Option<Checkpoint>,
+) -> Option<i128> {
+    let (mut low, mut high) = (0u32, count);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        match get(mid) {
+            Some(checkpoint) if checkpoint.snapshot_id >= snapshot_id => high = mid,
+            _ => low = mid + 1,
+        }
+    }
+
+    if low < count {
+        get(low).map(|checkpoint| checkpoint.value)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checkpoints() -> [Checkpoint; 3] {
+        [
+            Checkpoint { snapshot_id: 1, value: 10 },
+            Checkpoint { snapshot_id: 3, value: 30 },
+            Checkpoint { snapshot_id: 4, value: 40 },
+        ]
+    }
+
+    #[test]
+    fn test_find_checkpoint() {
+        let list = checkpoints();
+        let get = |i: u32| list.get(i as usize).cloned();
+
+        assert_eq!(find_checkpoint(3, 1, get), Some(10));
+        // Snapshot 2 had the same value as snapshot 3
+        assert_eq!(find_checkpoint(3, 2, get), Some(30));
+        assert_eq!(find_checkpoint(3, 4, get), Some(40));
+        // Unchanged since snapshot 5
+        assert_eq!(find_checkpoint(3, 5, get), None);
+        assert_eq!(find_checkpoint(0, 1, get), None);
+    }
+}
@@ -0,0 +1,109 @@
+//! Snapshot Tests
+//!
+//! Tests for point-in-time balance and total supply queries
+
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+use crate::test_helpers::{create_token, TestEnv};
+use crate::types::Error;
+use crate::TokenFactoryClient;
+
+fn setup() -> (Env, TokenFactoryClient<'static>, Address, Address) {
+    let TestEnv {
+        env, client, admin, ..
+    } = TestEnv::initialized();
+
+    let creator = Address::generate(&env);
+    create_token(&client, &creator, "SNAP", 1_000);
+
+    (env, client, admin, creator)
+}
+
+#[test]
+fn test_snapshot_ids_increment() {
+    let (_env, client, admin, creator) = setup();
+
+    assert_eq!(client.get_current_snapshot_id(&0), 0);
+    assert_eq!(client.snapshot(&creator, &0), 1);
+    assert_eq!(client.snapshot(&admin, &0), 2);
+    assert_eq!(client.get_current_snapshot_id(&0), 2);
+}
+
+#[test]
+fn test_only_creator_or_admin_can_snapshot() {
+    let (env, client, _admin, _creator) = setup();
+
+    assert_eq!(
+        client.try_snapshot(&Address::generate(&env), &0),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_balance_of_at_after_transfers() {
+    let (env, client, _admin, creator) = setup();
+    let alice = Address::generate(&env);
+
+    client.transfer(&creator, &0, &alice, &100);
+    let first = client.snapshot(&creator, &0);
+
+    client.transfer(&creator, &0, &alice, &200);
+    client.transfer(&creator, &0, &alice, &50);
+    let second = client.snapshot(&creator, &0);
+
+    client.transfer(&alice, &0, &creator, &350);
+
+    assert_eq!(client.balance_of_at(&0, &alice, &first), 100);
+    assert_eq!(client.balance_of_at(&0, &creator, &first), 900);
+    assert_eq!(client.balance_of_at(&0, &alice, &second), 350);
+    assert_eq!(client.balance_of_at(&0, &creator, &second), 650);
+    assert_eq!(client.get_balance(&0, &alice), 0);
+}
+
+#[test]
+fn test_untouched_balance_reads_current_value() {
+    let (env, client, _admin, creator) = setup();
+    let alice = Address::generate(&env);
+    client.transfer(&creator, &0, &alice, &100);
+
+    let id = client.snapshot(&creator, &0);
+    assert_eq!(client.balance_of_at(&0, &alice, &id), 100);
+    assert_eq!(client.balance_of_at(&0, &Address::generate(&env), &id), 0);
+}
+
+#[test]
+fn test_total_supply_at_tracks_mint_and_burn() {
+    let (env, client, _admin, creator) = setup();
+
+    let before_mint = client.snapshot(&creator, &0);
+    client.mint(&creator, &0, &Address::generate(&env), &500);
+    let before_burn = client.snapshot(&creator, &0);
+    client.burn(&creator, &0, &200);
+
+    assert_eq!(client.total_supply_at(&0, &before_mint), 1_000);
+    assert_eq!(client.total_supply_at(&0, &before_burn), 1_500);
+    assert_eq!(client.get_token_info(&0).total_supply, 1_300);
+}
+
+#[test]
+fn test_unknown_snapshot_rejected() {
+    let (env, client, _admin, creator) = setup();
+
+    assert_eq!(
+        client.try_balance_of_at(&0, &creator, &1),
+        Err(Ok(Error::InvalidParameters))
+    );
+    client.snapshot(&creator, &0);
+    assert_eq!(
+        client.try_total_supply_at(&0, &0),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(
+        client.try_total_supply_at(&0, &2),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(
+        client.try_balance_of_at(&0, &Address::generate(&env), &1),
+        Ok(Ok(0))
+    );
+}
@@ -0,0 +1,545 @@
+//! Staking Pools
+//!
+//! Holders stake a factory token and earn rewards in the same or another
+//! factory token. Rewards are emitted at `reward_rate` per second until
+//! `period_finish` and shared between stakers through a reward-per-token
+//! accumulator:
+//!
+//! ```text
+//! reward_per_token += elapsed * reward_rate * REWARD_PRECISION / total_staked
+//! earned = amount * (reward_per_token - reward_per_token_paid) / REWARD_PRECISION
+//! ```
+//!
+//! Both are computed with a 256-bit intermediate product and round down,
+//! so the pool never pays more than it was funded with. Emission while
+//! nothing is staked is not allocated to anyone; once the period is over
+//! the creator recovers it, along with the remainder of `amount / duration`,
+//! through `recover_unallocated_rewards`. Should the accumulator still leave
+//! the `i128` range, `emergency_unstake` returns principal without
+//! touching rewards.
+//!
+//! Staked tokens stay in the holder's balance (so snapshots and holder
+//! counts still see them) but are excluded from the transferable balance.
+
+use crate::math::mul_div;
+use crate::storage;
+use crate::streaming::release_to;
+use crate::types::{Error, StakePosition, StakingPool, PAUSE_TRANSFER};
+use soroban_sdk::{Address, Env};
+
+/// Fixed-point scale of the reward-per-token accumulator
+pub const REWARD_PRECISION: i128 = 1_000_000_000_000_000_000;
+/// Maximum early-exit penalty: 5000 bps = 50%
+pub const MAX_EARLY_EXIT_PENALTY_BPS: u32 = 5_000;
+
+const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Create a staking pool for a token (token creator only)
+///
+/// # Returns
+/// * `Ok(pool_id)` - Pool created
+/// * `Err(Error::TokenNotFound)` - Staked or reward token doesn't exist
+/// * `Err(Error::Unauthorized)` - Caller is not the staked token's creator
+/// * `Err(Error::InvalidParameters)` - Penalty above 50%
+pub fn create_pool(
+    env: &Env,
+    creator: &Address,
+    token_index: u32,
+    reward_token_index: u32,
+    lockup_seconds: u64,
+    early_exit_penalty_bps: u32,
+) -> Result<u32, Error> {
+    creator.require_auth();
+
+    let token_info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+    if token_info.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+    storage::get_token_info(env, reward_token_index).ok_or(Error::TokenNotFound)?;
+
+    if early_exit_penalty_bps > MAX_EARLY_EXIT_PENALTY_BPS {
+        return Err(Error::InvalidParameters);
+    }
+
+    let pool_id = storage::get_staking_pool_count(env);
+    let next_id = pool_id.checked_add(1).ok_or(Error::ArithmeticError)?;
+
+    storage::set_staking_pool(
+        env,
+        pool_id,
+        &StakingPool {
+            token_index,
+            reward_token_index,
+            creator: creator.clone(),
+            reward_rate: 0,
+            period_finish: 0,
+            last_update: env.ledger().timestamp(),
+            reward_per_token: 0,
+            total_staked: 0,
+            reward_reserve: 0,
+            reward_owed: 0,
+            lockup_seconds,
+            early_exit_penalty_bps,
+        },
+    );
+    storage::set_staking_pool_count(env, next_id);
+
+    crate::events::emit_staking_pool_created(env, pool_id, token_index, reward_token_index);
+
+    Ok(pool_id)
+}
+
+/// Fund a new reward period
+///
+/// Moves `amount` of the reward token from the creator into the pool and
+/// emits it over `duration` seconds starting now. Rewards left over from
+/// a running period are rolled into the new rate.
+///
+/// # Returns
+/// * `Ok(())` - Rewards funded
+/// * `Err(Error::InvalidParameters)` - Pool doesn't exist or duration is 0
+/// * `Err(Error::Unauthorized)` - Caller is not the pool creator
+/// * `Err(Error::InvalidAmount)` - Amount is not positive or too small for the duration
+/// * `Err(Error::InsufficientBalance)` - Creator's transferable reward balance is too low
+pub fn fund_rewards(
+    env: &Env,
+    creator: &Address,
+    pool_id: u32,
+    amount: i128,
+    duration: u64,
+) -> Result<(), Error> {
+    creator.require_auth();
+
+    let mut pool = storage::get_staking_pool(env, pool_id).ok_or(Error::InvalidParameters)?;
+    if pool.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+    if duration == 0 {
+        return Err(Error::InvalidParameters);
+    }
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    let now = env.ledger().timestamp();
+    update_pool(&mut pool, now)?;
+
+    let leftover = if now < pool.period_finish {
+        ((pool.period_finish - now) as i128)
+            .checked_mul(pool.reward_rate)
+            .ok_or(Error::ArithmeticError)?
+    } else {
+        0
+    };
+    let reward_rate =
+        amount.checked_add(leftover).ok_or(Error::ArithmeticError)? / duration as i128;
+    if reward_rate == 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    let balance = storage::get_balance(env, pool.reward_token_index, creator);
+    if storage::get_transferable_balance(env, pool.reward_token_index, creator) < amount {
+        return Err(Error::InsufficientBalance);
+    }
+    storage::set_balance(env, pool.reward_token_index, creator, balance - amount);
+    storage::adjust_escrowed_supply(env, pool.reward_token_index, amount)?;
+
+    pool.reward_rate = reward_rate;
+    pool.period_finish = now.checked_add(duration).ok_or(Error::ArithmeticError)?;
+    pool.reward_reserve = pool
+        .reward_reserve
+        .checked_add(amount)
+        .ok_or(Error::ArithmeticError)?;
+    storage::set_staking_pool(env, pool_id, &pool);
+
+    crate::events::emit_staking_rewards_funded(
+        env,
+        pool_id,
+        amount,
+        reward_rate,
+        pool.period_finish,
+    );
+
+    Ok(())
+}
+
+/// Stake tokens into a pool
+///
+/// Each stake restarts the position's lockup.
+///
+/// # Returns
+/// * `Ok(())` - Tokens staked
+/// * `Err(Error::ContractPaused)` - Transfers are paused
+/// * `Err(Error::InvalidParameters)` - Pool doesn't exist
+/// * `Err(Error::TokenPaused)` - Staked token is paused
+/// * `Err(Error::InvalidAmount)` - Amount is zero or negative
+/// * `Err(Error::InsufficientBalance)` - Transferable balance is less than amount
+pub fn stake(env: &Env, staker: &Address, pool_id: u32, amount: i128) -> Result<(), Error> {
+    staker.require_auth();
+
+    if storage::is_operation_paused(env, PAUSE_TRANSFER) {
+        return Err(Error::ContractPaused);
+    }
+
+    let mut pool = storage::get_staking_pool(env, pool_id).ok_or(Error::InvalidParameters)?;
+    if storage::is_token_paused(env, pool.token_index) {
+        return Err(Error::TokenPaused);
+    }
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+    if storage::get_transferable_balance(env, pool.token_index, staker) < amount {
+        return Err(Error::InsufficientBalance);
+    }
+
+    let now = env.ledger().timestamp();
+    let mut position = load_position(env, &mut pool, pool_id, staker, now)?;
+
+    position.amount = position
+        .amount
+        .checked_add(amount)
+        .ok_or(Error::ArithmeticError)?;
+    position.locked_until = now.saturating_add(pool.lockup_seconds);
+    pool.total_staked = pool
+        .total_staked
+        .checked_add(amount)
+        .ok_or(Error::ArithmeticError)?;
+
+    let staked = storage::get_staked_balance(env, pool.token_index, staker);
+    storage::set_staked_balance(env, pool.token_index, staker, staked + amount);
+    storage::set_stake_position(env, pool_id, staker, &position);
+    storage::set_staking_pool(env, pool_id, &pool);
+
+    crate::events::emit_staked(env, pool_id, staker, amount);
+
+    Ok(())
+}
+
+/// Unstake tokens from a pool
+///
+/// Unstaking before `locked_until` pays `early_exit_penalty_bps` of the
+/// amount to the pool creator. Accrued rewards are kept.
+///
+/// # Returns
+/// * `Ok(penalty)` - Penalty charged (0 once unlocked)
+/// * `Err(Error::InvalidParameters)` - Pool doesn't exist
+/// * `Err(Error::InvalidAmount)` - Amount is zero or negative
+/// * `Err(Error::InsufficientBalance)` - Amount exceeds the staked position
+pub fn unstake(env: &Env, staker: &Address, pool_id: u32, amount: i128) -> Result<i128, Error> {
+    staker.require_auth();
+
+    let mut pool = storage::get_staking_pool(env, pool_id).ok_or(Error::InvalidParameters)?;
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    let now = env.ledger().timestamp();
+    let mut position = load_position(env, &mut pool, pool_id, staker, now)?;
+    if amount > position.amount {
+        return Err(Error::InsufficientBalance);
+    }
+
+    let penalty = withdraw(env, &mut pool, &mut position, staker, amount, now)?;
+
+    storage::set_stake_position(env, pool_id, staker, &position);
+    storage::set_staking_pool(env, pool_id, &pool);
+
+    crate::events::emit_unstaked(env, pool_id, staker, amount, penalty);
+
+    Ok(penalty)
+}
+
+/// Unstake a whole position without settling its rewards
+///
+/// Escape hatch for when reward accrual fails, so principal can never be
+/// locked by the accumulator. Settled rewards are forfeited and become
+/// recoverable by the creator; the early-exit penalty still applies.
+///
+/// # Returns
+/// * `Ok(amount)` - Amount unstaked
+/// * `Err(Error::InvalidParameters)` - Pool doesn't exist
+/// * `Err(Error::NothingToClaim)` - Nothing staked in the pool
+pub fn emergency_unstake(env: &Env, staker: &Address, pool_id: u32) -> Result<i128, Error> {
+    staker.require_auth();
+
+    let mut pool = storage::get_staking_pool(env, pool_id).ok_or(Error::InvalidParameters)?;
+    let mut position = storage::get_stake_position(env, pool_id, staker)
+        .filter(|position| position.amount > 0)
+        .ok_or(Error::NothingToClaim)?;
+
+    // Settle other stakers up to now when possible; a pool whose
+    // accumulator overflowed is left as it is
+    let now = env.ledger().timestamp();
+    let _ = update_pool(&mut pool, now);
+
+    let amount = position.amount;
+    let penalty = withdraw(env, &mut pool, &mut position, staker, amount, now)?;
+    let forfeited = position.rewards;
+    pool.reward_owed = pool
+        .reward_owed
+        .checked_sub(forfeited)
+        .ok_or(Error::ArithmeticError)?;
+    position.rewards = 0;
+    position.reward_per_token_paid = pool.reward_per_token;
+
+    storage::set_stake_position(env, pool_id, staker, &position);
+    storage::set_staking_pool(env, pool_id, &pool);
+
+    crate::events::emit_emergency_unstaked(env, pool_id, staker, amount, penalty, forfeited);
+
+    Ok(amount)
+}
+
+/// Pay out a staker's accrued rewards
+///
+/// # Returns
+/// * `Ok(amount)` - Rewards credited in the reward token
+/// * `Err(Error::InvalidParameters)` - Pool doesn't exist
+/// * `Err(Error::NothingToClaim)` - No rewards accrued
+/// * `Err(Error::RecipientNotAllowed)` - Staker fails the reward token's compliance mode
+pub fn claim_rewards(env: &Env, staker: &Address, pool_id: u32) -> Result<i128, Error> {
+    staker.require_auth();
+
+    let mut pool = storage::get_staking_pool(env, pool_id).ok_or(Error::InvalidParameters)?;
+
+    let now = env.ledger().timestamp();
+    let mut position = load_position(env, &mut pool, pool_id, staker, now)?;
+    let reward = position.rewards;
+    if reward == 0 {
+        return Err(Error::NothingToClaim);
+    }
+
+    crate::compliance::require_eligible(env, pool.reward_token_index, staker)?;
+
+    pool.reward_reserve = pool
+        .reward_reserve
+        .checked_sub(reward)
+        .ok_or(Error::ArithmeticError)?;
+    pool.reward_owed = pool
+        .reward_owed
+        .checked_sub(reward)
+        .ok_or(Error::ArithmeticError)?;
+    position.rewards = 0;
+
+    release_to(env, pool.reward_token_index, staker, reward)?;
+
+    storage::set_stake_position(env, pool_id, staker, &position);
+    storage::set_staking_pool(env, pool_id, &pool);
+
+    crate::events::emit_staking_rewards_claimed(env, pool_id, staker, reward);
+
+    Ok(reward)
+}
+
+/// Return funded rewards that were never allocated to stakers (pool
+/// creator only)
+///
+/// Covers emission while nothing was staked and the rounding remainder of
+/// each funding. Only callable once the reward period is over, when the
+/// reserve no longer has to cover future emission.
+///
+/// # Returns
+/// * `Ok(amount)` - Rewards returned to the creator
+/// * `Err(Error::InvalidParameters)` - Pool doesn't exist
+/// * `Err(Error::Unauthorized)` - Caller is not the pool creator
+/// * `Err(Error::InvalidStateTransition)` - Reward period still running
+/// * `Err(Error::NothingToClaim)` - Every funded reward is owed to stakers
+pub fn recover_unallocated_rewards(
+    env: &Env,
+    creator: &Address,
+    pool_id: u32,
+) -> Result<i128, Error> {
+    creator.require_auth();
+
+    let mut pool = storage::get_staking_pool(env, pool_id).ok_or(Error::InvalidParameters)?;
+    if pool.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+
+    let now = env.ledger().timestamp();
+    if now < pool.period_finish {
+        return Err(Error::InvalidStateTransition);
+    }
+    update_pool(&mut pool, now)?;
+
+    let amount = pool.reward_reserve - pool.reward_owed;
+    if amount <= 0 {
+        return Err(Error::NothingToClaim);
+    }
+
+    pool.reward_reserve = pool.reward_owed;
+    release_to(env, pool.reward_token_index, creator, amount)?;
+    storage::set_staking_pool(env, pool_id, &pool);
+
+    crate::events::emit_staking_rewards_recovered(env, pool_id, amount);
+
+    Ok(amount)
+}
+
+/// Rewards a staker could claim right now
+pub fn earned(env: &Env, pool_id: u32, staker: &Address) -> Result<i128, Error> {
+    let mut pool = storage::get_staking_pool(env, pool_id).ok_or(Error::InvalidParameters)?;
+    update_pool(&mut pool, env.ledger().timestamp())?;
+
+    match storage::get_stake_position(env, pool_id, staker) {
+        Some(position) => accrue(&pool, &position),
+        None => Ok(0),
+    }
+}
+
+/// Pool state with the accumulator brought up to date
+pub fn get_pool(env: &Env, pool_id: u32) -> Result<StakingPool, Error> {
+    let mut pool = storage::get_staking_pool(env, pool_id).ok_or(Error::InvalidParameters)?;
+    update_pool(&mut pool, env.ledger().timestamp())?;
+    Ok(pool)
+}
+
+/// Bring the pool accumulator and the staker's accrued rewards up to `now`
+fn load_position(
+    env: &Env,
+    pool: &mut StakingPool,
+    pool_id: u32,
+    staker: &Address,
+    now: u64,
+) -> Result<StakePosition, Error> {
+    update_pool(pool, now)?;
+
+    let mut position = storage::get_stake_position(env, pool_id, staker).unwrap_or(StakePosition {
+        amount: 0,
+        reward_per_token_paid: pool.reward_per_token,
+        rewards: 0,
+        locked_until: 0,
+    });
+    position.rewards = accrue(pool, &position)?;
+    position.reward_per_token_paid = pool.reward_per_token;
+
+    Ok(position)
+}
+
+/// Take `amount` out of a position, charging the early-exit penalty while
+/// it is locked
+///
+/// Returns the penalty paid to the pool creator.
+fn withdraw(
+    env: &Env,
+    pool: &mut StakingPool,
+    position: &mut StakePosition,
+    staker: &Address,
+    amount: i128,
+    now: u64,
+) -> Result<i128, Error> {
+    let penalty = if now < position.locked_until {
+        amount
+            .checked_mul(pool.early_exit_penalty_bps as i128)
+            .ok_or(Error::ArithmeticError)?
+            / BPS_DENOMINATOR
+    } else {
+        0
+    };
+
+    position.amount -= amount;
+    pool.total_staked -= amount;
+
+    let staked = storage::get_staked_balance(env, pool.token_index, staker);
+    storage::set_staked_balance(env, pool.token_index, staker, staked - amount);
+
+    if penalty > 0 {
+        let balance = storage::get_balance(env, pool.token_index, staker);
+        storage::set_balance(env, pool.token_index, staker, balance - penalty);
+        let creator_balance = storage::get_balance(env, pool.token_index, &pool.creator);
+        let new_creator_balance = creator_balance
+            .checked_add(penalty)
+            .ok_or(Error::ArithmeticError)?;
+        storage::set_balance(env, pool.token_index, &pool.creator, new_creator_balance);
+    }
+
+    Ok(penalty)
+}
+
+fn update_pool(pool: &mut StakingPool, now: u64) -> Result<(), Error> {
+    let applicable = now.min(pool.period_finish);
+    if pool.total_staked > 0 && applicable > pool.last_update {
+        let emitted = ((applicable - pool.last_update) as i128)
+            .checked_mul(pool.reward_rate)
+            .ok_or(Error::ArithmeticError)?;
+        let increment = mul_div(emitted, REWARD_PRECISION, pool.total_staked)?;
+        pool.reward_per_token = pool
+            .reward_per_token
+            .checked_add(increment)
+            .ok_or(Error::ArithmeticError)?;
+        // Counted in full; per-staker rounding keeps what is paid out below it
+        pool.reward_owed = pool
+            .reward_owed
+            .checked_add(emitted)
+            .ok_or(Error::ArithmeticError)?;
+    }
+    pool.last_update = now;
+    Ok(())
+}
+
+fn accrue(pool: &StakingPool, position: &StakePosition) -> Result<i128, Error> {
+    let delta = pool.reward_per_token - position.reward_per_token_paid;
+    mul_div(position.amount, delta, REWARD_PRECISION)?
+        .checked_add(position.rewards)
+        .ok_or(Error::ArithmeticError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    fn pool(env: &Env, reward_rate: i128, total_staked: i128) -> StakingPool {
+        StakingPool {
+            token_index: 0,
+            reward_token_index: 0,
+            creator: Address::generate(env),
+            reward_rate,
+            period_finish: 100,
+            last_update: 0,
+            reward_per_token: 0,
+            total_staked,
+            reward_reserve: 0,
+            reward_owed: 0,
+            lockup_seconds: 0,
+            early_exit_penalty_bps: 0,
+        }
+    }
+
+    #[test]
+    fn test_update_pool_stops_at_period_finish() {
+        let env = Env::default();
+        let mut p = pool(&env, 10, 1_000);
+
+        update_pool(&mut p, 500).unwrap();
+
+        // 100s * 10/s spread over 1_000 staked
+        assert_eq!(p.reward_per_token, REWARD_PRECISION);
+        assert_eq!(p.last_update, 500);
+    }
+
+    #[test]
+    fn test_nothing_accrues_without_stake() {
+        let env = Env::default();
+        let mut p = pool(&env, 10, 0);
+
+        update_pool(&mut p, 50).unwrap();
+        assert_eq!(p.reward_per_token, 0);
+    }
+
+    #[test]
+    fn test_accrue_rounds_down() {
+        let env = Env::default();
+        let mut p = pool(&env, 1, 3);
+        update_pool(&mut p, 1).unwrap();
+
+        let position = StakePosition {
+            amount: 1,
+            reward_per_token_paid: 0,
+            rewards: 0,
+            locked_until: 0,
+        };
+        // 1/3 of one unit -> 0
+        assert_eq!(accrue(&p, &position), Ok(0));
+    }
+}
@@ -0,0 +1,295 @@
+//! Staking Tests
+//!
+//! Tests for staking pools, reward emission, lockups and the exclusion of
+//! staked tokens from transferable balances
+
+use soroban_sdk::{testutils::Address as _, vec, Address, Env};
+
+use crate::storage;
+use crate::test_helpers::{create_tokens, token_params, TestEnv, TimeController};
+use crate::types::{Error, TokenCreationParams};
+use crate::TokenFactoryClient;
+
+const E18: i128 = 1_000_000_000_000_000_000;
+
+struct Setup {
+    env: Env,
+    client: TokenFactoryClient<'static>,
+    creator: Address,
+    alice: Address,
+    bob: Address,
+}
+
+/// Token 0 (staked) and token 1 (rewards), both owned by the creator;
+/// alice and bob hold 1_000 of token 0 each
+fn setup() -> Setup {
+    let TestEnv { env, client, .. } = TestEnv::initialized();
+
+    let creator = Address::generate(&env);
+    create_tokens(
+        &client,
+        &creator,
+        vec![
+            &env,
+            token_params(&env, "STK", 1_000_000),
+            token_params(&env, "RWD", 1_000_000),
+        ],
+    );
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.transfer(&creator, &0, &alice, &1_000);
+    client.transfer(&creator, &0, &bob, &1_000);
+
+    Setup {
+        env,
+        client,
+        creator,
+        alice,
+        bob,
+    }
+}
+
+#[test]
+fn test_single_staker_earns_full_emission() {
+    let s = setup();
+    let pool = s.client.create_staking_pool(&s.creator, &0, &1, &0, &0);
+    s.client
+        .fund_staking_rewards(&s.creator, &pool, &10_000, &100);
+    assert_eq!(s.client.get_balance(&1, &s.creator), 1_000_000 - 10_000);
+
+    s.client.stake(&s.alice, &pool, &500);
+    TimeController::new(&s.env).advance(50);
+    assert_eq!(s.client.get_earned_rewards(&pool, &s.alice), 5_000);
+
+    // Emission stops at period_finish
+    TimeController::new(&s.env).advance(1_000);
+    assert_eq!(s.client.claim_rewards(&s.alice, &pool), 10_000);
+    assert_eq!(s.client.get_balance(&1, &s.alice), 10_000);
+    assert_eq!(s.client.get_staking_pool(&pool).reward_reserve, 0);
+}
+
+#[test]
+fn test_rewards_split_by_stake_and_time() {
+    let s = setup();
+    let pool = s.client.create_staking_pool(&s.creator, &0, &1, &0, &0);
+    s.client
+        .fund_staking_rewards(&s.creator, &pool, &10_000, &100);
+
+    s.client.stake(&s.alice, &pool, &300);
+    TimeController::new(&s.env).advance(40);
+    s.client.stake(&s.bob, &pool, &100);
+    TimeController::new(&s.env).advance(60);
+
+    // Alice: 40s alone (4_000) + 60s at 3/4 (4_500); bob: 60s at 1/4 (1_500).
+    // Reward per token rounds down, so alice gets one unit less.
+    assert_eq!(s.client.claim_rewards(&s.alice, &pool), 8_499);
+    assert_eq!(s.client.claim_rewards(&s.bob, &pool), 1_500);
+    assert_eq!(
+        s.client.try_claim_rewards(&s.bob, &pool),
+        Err(Ok(Error::NothingToClaim))
+    );
+}
+
+#[test]
+fn test_creator_recovers_unallocated_rewards() {
+    let s = setup();
+    let pool = s.client.create_staking_pool(&s.creator, &0, &1, &0, &0);
+    // 10/s over 100s leaves a remainder of 3
+    s.client
+        .fund_staking_rewards(&s.creator, &pool, &1_003, &100);
+
+    // Nothing staked for the first 40s
+    TimeController::new(&s.env).advance(40);
+    s.client.stake(&s.alice, &pool, &500);
+    TimeController::new(&s.env).advance(50);
+    assert_eq!(
+        s.client.try_recover_unallocated_rewards(&s.creator, &pool),
+        Err(Ok(Error::InvalidStateTransition))
+    );
+
+    TimeController::new(&s.env).advance(10);
+    assert_eq!(
+        s.client.try_recover_unallocated_rewards(&s.alice, &pool),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(s.client.recover_unallocated_rewards(&s.creator, &pool), 403);
+    assert_eq!(s.client.get_balance(&1, &s.creator), 1_000_000 - 600);
+    assert_eq!(
+        s.client.try_recover_unallocated_rewards(&s.creator, &pool),
+        Err(Ok(Error::NothingToClaim))
+    );
+
+    // Alice's 600 is still covered and the escrow empties once it is paid
+    assert_eq!(s.client.claim_rewards(&s.alice, &pool), 600);
+    assert_eq!(s.client.get_staking_pool(&pool).reward_reserve, 0);
+    let escrowed = s.env.as_contract(&s.client.address, || {
+        storage::get_escrowed_supply(&s.env, 1)
+    });
+    assert_eq!(escrowed, 0);
+}
+
+#[test]
+fn test_staked_tokens_are_not_transferable() {
+    let s = setup();
+    let pool = s.client.create_staking_pool(&s.creator, &0, &0, &0, &0);
+
+    s.client.stake(&s.alice, &pool, &800);
+    assert_eq!(s.client.get_staked_balance(&0, &s.alice), 800);
+    assert_eq!(s.client.get_balance(&0, &s.alice), 1_000);
+
+    assert_eq!(
+        s.client.try_transfer(&s.alice, &0, &s.bob, &201),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    assert_eq!(
+        s.client.try_burn(&s.alice, &0, &201),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    s.client.transfer(&s.alice, &0, &s.bob, &200);
+
+    assert_eq!(
+        s.client.try_stake(&s.alice, &pool, &1),
+        Err(Ok(Error::InsufficientBalance))
+    );
+
+    s.client.unstake(&s.alice, &pool, &800);
+    s.client.transfer(&s.alice, &0, &s.bob, &800);
+    assert_eq!(s.client.get_balance(&0, &s.alice), 0);
+}
+
+#[test]
+fn test_early_exit_penalty_during_lockup() {
+    let s = setup();
+    let pool = s
+        .client
+        .create_staking_pool(&s.creator, &0, &0, &100, &1_000);
+    let creator_before = s.client.get_balance(&0, &s.creator);
+
+    s.client.stake(&s.alice, &pool, &500);
+    TimeController::new(&s.env).advance(50);
+    assert_eq!(s.client.unstake(&s.alice, &pool, &200), 20);
+    assert_eq!(s.client.get_balance(&0, &s.alice), 980);
+    assert_eq!(s.client.get_balance(&0, &s.creator), creator_before + 20);
+
+    TimeController::new(&s.env).advance(50);
+    assert_eq!(s.client.unstake(&s.alice, &pool, &300), 0);
+    assert_eq!(s.client.get_staked_balance(&0, &s.alice), 0);
+}
+
+/// Tokens 0 (staked) and 1 (rewards) with 18 decimals and a supply of
+/// one billion, both owned by the creator
+fn setup_18_decimals() -> (Env, TokenFactoryClient<'static>, Address) {
+    let TestEnv { env, client, .. } = TestEnv::initialized();
+
+    let creator = Address::generate(&env);
+    let params = |symbol: &str| TokenCreationParams {
+        decimals: 18,
+        ..token_params(&env, symbol, 1_000_000_000 * E18)
+    };
+    create_tokens(&client, &creator, vec![&env, params("STK"), params("RWD")]);
+
+    (env, client, creator)
+}
+
+#[test]
+fn test_18_decimal_rewards_accrue_without_overflow() {
+    let (env, client, creator) = setup_18_decimals();
+    let alice = Address::generate(&env);
+    client.transfer(&creator, &0, &alice, &(1_000 * E18));
+
+    // One million reward tokens over 30 days
+    let pool = client.create_staking_pool(&creator, &0, &1, &0, &0);
+    client.fund_staking_rewards(&creator, &pool, &(1_000_000 * E18), &2_592_000);
+    client.stake(&alice, &pool, &(1_000 * E18));
+
+    TimeController::new(&env).advance(1_296_000);
+    let emitted = client.get_staking_pool(&pool).reward_rate * 1_296_000;
+    // The accumulator holds whole units per 1_000 staked tokens
+    let earned = emitted / 1_000 * 1_000;
+    assert_eq!(client.get_earned_rewards(&pool, &alice), earned);
+    assert_eq!(client.claim_rewards(&alice, &pool), earned);
+
+    client.unstake(&alice, &pool, &(1_000 * E18));
+    assert_eq!(client.get_balance(&0, &alice), 1_000 * E18);
+}
+
+#[test]
+fn test_emergency_unstake_when_accrual_overflows() {
+    let (env, client, creator) = setup_18_decimals();
+    let alice = Address::generate(&env);
+    client.transfer(&creator, &0, &alice, &(1_000 * E18));
+
+    // A single unit staked against a huge emission overflows the accumulator
+    let pool = client.create_staking_pool(&creator, &0, &1, &0, &0);
+    client.stake(&alice, &pool, &1);
+    client.fund_staking_rewards(&creator, &pool, &(100_000_000 * E18), &1);
+    TimeController::new(&env).advance(1);
+
+    assert_eq!(
+        client.try_unstake(&alice, &pool, &1),
+        Err(Ok(Error::ArithmeticError))
+    );
+    assert_eq!(client.emergency_unstake(&alice, &pool), 1);
+    assert_eq!(client.get_staked_balance(&0, &alice), 0);
+    assert_eq!(client.get_stake(&pool, &alice).unwrap().amount, 0);
+    client.transfer(&alice, &0, &creator, &(1_000 * E18));
+
+    assert_eq!(
+        client.try_emergency_unstake(&alice, &pool),
+        Err(Ok(Error::NothingToClaim))
+    );
+}
+
+#[test]
+fn test_refunding_rolls_over_leftover() {
+    let s = setup();
+    let pool = s.client.create_staking_pool(&s.creator, &0, &1, &0, &0);
+    s.client
+        .fund_staking_rewards(&s.creator, &pool, &1_000, &100);
+    s.client.stake(&s.alice, &pool, &100);
+
+    TimeController::new(&s.env).advance(50);
+    // 500 left over + 1_500 new over 100s
+    s.client
+        .fund_staking_rewards(&s.creator, &pool, &1_500, &100);
+    assert_eq!(s.client.get_staking_pool(&pool).reward_rate, 20);
+
+    TimeController::new(&s.env).advance(100);
+    assert_eq!(s.client.claim_rewards(&s.alice, &pool), 2_500);
+}
+
+#[test]
+fn test_pool_validation() {
+    let s = setup();
+
+    assert_eq!(
+        s.client.try_create_staking_pool(&s.alice, &0, &0, &0, &0),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.client.try_create_staking_pool(&s.creator, &0, &9, &0, &0),
+        Err(Ok(Error::TokenNotFound))
+    );
+    assert_eq!(
+        s.client
+            .try_create_staking_pool(&s.creator, &0, &0, &0, &5_001),
+        Err(Ok(Error::InvalidParameters))
+    );
+
+    let pool = s.client.create_staking_pool(&s.creator, &0, &1, &0, &0);
+    assert_eq!(
+        s.client
+            .try_fund_staking_rewards(&s.alice, &pool, &1_000, &100),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.client
+            .try_fund_staking_rewards(&s.creator, &pool, &99, &100),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        s.client.try_unstake(&s.alice, &pool, &1),
+        Err(Ok(Error::InsufficientBalance))
+    );
+}
@@ -1,6 +1,9 @@
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{Address, BytesN, Env, String};
 
-use crate::types::{DataKey, Error, FactoryState, TokenInfo};
+use crate::types::{
+    ClawbackRecord, DataKey, Error, ExtDataKey, FactoryState, StreamAmendment, SymbolPolicy,
+    TokenInfo, PAUSE_ALL,
+};
 
 // ============================================================
 // Storage Functions - Burn Tracking
@@ -30,20 +33,39 @@ pub fn has_admin(env: &Env) -> bool {
 }
 
 // Pending admin management (two-step transfer)
-pub fn get_pending_admin(env: &Env) -> Option<Address> {
-    env.storage().instance().get(&DataKey::PendingAdmin)
+//
+// Offers are stored under `ExtDataKey::AdminOffer`. Contracts upgraded with
+// an offer pending may still hold a bare address under the legacy
+// `DataKey::PendingAdmin`; it carries no timestamps, so it is read back as
+// an offer that has already expired and can only be cleared or replaced.
+pub fn get_pending_admin(env: &Env) -> Option<crate::types::PendingAdminOffer> {
+    if let Some(offer) = env.storage().instance().get(&ExtDataKey::AdminOffer) {
+        return Some(offer);
+    }
+    env.storage()
+        .instance()
+        .get::<_, Address>(&DataKey::PendingAdmin)
+        .map(|proposed_admin| crate::types::PendingAdminOffer {
+            proposed_admin,
+            proposed_by: get_admin(env),
+            proposed_at: 0,
+            expires_at: 0,
+        })
 }
 
-pub fn set_pending_admin(env: &Env, admin: &Address) {
-    env.storage().instance().set(&DataKey::PendingAdmin, admin);
+pub fn set_pending_admin(env: &Env, offer: &crate::types::PendingAdminOffer) {
+    env.storage().instance().remove(&DataKey::PendingAdmin);
+    env.storage().instance().set(&ExtDataKey::AdminOffer, offer);
 }
 
 pub fn clear_pending_admin(env: &Env) {
     env.storage().instance().remove(&DataKey::PendingAdmin);
+    env.storage().instance().remove(&ExtDataKey::AdminOffer);
 }
 
 pub fn has_pending_admin(env: &Env) -> bool {
-    env.storage().instance().has(&DataKey::PendingAdmin)
+    env.storage().instance().has(&ExtDataKey::AdminOffer)
+        || env.storage().instance().has(&DataKey::PendingAdmin)
 }
 
 // Treasury management
@@ -85,7 +107,7 @@ pub fn get_token_info(env: &Env, index: u32) -> Option<TokenInfo> {
 }
 
 pub fn set_token_info(env: &Env, index: u32, info: &TokenInfo) {
-    env.storage().instance().set(&DataKey::Token(index), info);
+    update_token_info(env, index, info);
 
     // Index by creator for pagination
     add_creator_token(env, &info.creator, index);
@@ -94,6 +116,16 @@ pub fn set_token_info(env: &Env, index: u32, info: &TokenInfo) {
     crate::events::emit_token_registered(env, &info.address, &info.creator);
 }
 
+/// Save changes to a token without indexing or announcing it again
+pub fn update_token_info(env: &Env, index: u32, info: &TokenInfo) {
+    if let Some(previous) = get_token_info(env, index) {
+        if previous.total_supply != info.total_supply {
+            checkpoint_supply(env, index, previous.total_supply);
+        }
+    }
+    env.storage().instance().set(&DataKey::Token(index), info);
+}
+
 pub fn increment_token_count(env: &Env) -> Result<u32, Error> {
     let count = get_token_count(env)
         .checked_add(1)
@@ -594,10 +626,96 @@ pub fn get_balance(env: &Env, token_index: u32, holder: &Address) -> i128 {
 }
 
 pub fn set_balance(env: &Env, token_index: u32, holder: &Address, balance: i128) {
+    let previous = get_balance(env, token_index, holder);
+    if previous != balance {
+        checkpoint_balance(env, token_index, holder, previous);
+    }
     env.storage().persistent().set(
         &crate::types::DataKey::Balance(token_index, holder.clone()),
         &balance,
     );
+
+    // Keep the holder index in sync with zero / non-zero transitions
+    if previous <= 0 && balance > 0 {
+        add_holder(env, token_index, holder);
+    } else if previous > 0 && balance <= 0 {
+        remove_holder(env, token_index, holder);
+    }
+}
+
+/// Balance not locked in staking pools
+pub fn get_transferable_balance(env: &Env, token_index: u32, holder: &Address) -> i128 {
+    get_balance(env, token_index, holder) - get_staked_balance(env, token_index, holder)
+}
+
+// Holder index (swap-and-pop list of addresses with a non-zero balance)
+pub fn get_holder_count(env: &Env, token_index: u32) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&ExtDataKey::HolderCount(token_index))
+        .unwrap_or(0)
+}
+
+pub fn get_holder_at(env: &Env, token_index: u32, position: u32) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&ExtDataKey::HolderAt(token_index, position))
+}
+
+fn add_holder(env: &Env, token_index: u32, holder: &Address) {
+    let count = get_holder_count(env, token_index);
+    let storage = env.storage().persistent();
+    storage.set(&ExtDataKey::HolderAt(token_index, count), holder);
+    storage.set(&ExtDataKey::HolderPosition(token_index, holder.clone()), &count);
+    storage.set(&ExtDataKey::HolderCount(token_index), &(count + 1));
+}
+
+fn remove_holder(env: &Env, token_index: u32, holder: &Address) {
+    let storage = env.storage().persistent();
+    let position_key = ExtDataKey::HolderPosition(token_index, holder.clone());
+    let Some(position) = storage.get::<_, u32>(&position_key) else {
+        return;
+    };
+
+    let last = get_holder_count(env, token_index) - 1;
+    if position != last {
+        let moved: Address = storage
+            .get(&ExtDataKey::HolderAt(token_index, last))
+            .unwrap();
+        storage.set(&ExtDataKey::HolderAt(token_index, position), &moved);
+        storage.set(&ExtDataKey::HolderPosition(token_index, moved), &position);
+    }
+    storage.remove(&ExtDataKey::HolderAt(token_index, last));
+    storage.remove(&position_key);
+    storage.set(&ExtDataKey::HolderCount(token_index), &last);
+}
+
+// Delegated minter management
+pub fn get_minter(
+    env: &Env,
+    token_index: u32,
+    minter: &Address,
+) -> Option<crate::types::MinterAllowance> {
+    env.storage()
+        .persistent()
+        .get(&ExtDataKey::Minter(token_index, minter.clone()))
+}
+
+pub fn set_minter(
+    env: &Env,
+    token_index: u32,
+    minter: &Address,
+    allowance: &crate::types::MinterAllowance,
+) {
+    env.storage()
+        .persistent()
+        .set(&ExtDataKey::Minter(token_index, minter.clone()), allowance);
+}
+
+pub fn remove_minter(env: &Env, token_index: u32, minter: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&ExtDataKey::Minter(token_index, minter.clone()));
 }
 
 pub fn get_burn_count(env: &Env, token_index: u32) -> u32 {
@@ -634,6 +752,19 @@ pub fn set_token_paused(env: &Env, token_index: u32, paused: bool) {
         .set(&crate::types::DataKey::TokenPaused(token_index), &paused);
 }
 
+pub fn is_supply_finalized(env: &Env, token_index: u32) -> bool {
+    env.storage()
+        .persistent()
+        .get(&ExtDataKey::SupplyFinalized(token_index))
+        .unwrap_or(false)
+}
+
+pub fn set_supply_finalized(env: &Env, token_index: u32) {
+    env.storage()
+        .persistent()
+        .set(&ExtDataKey::SupplyFinalized(token_index), &true);
+}
+
 pub fn get_total_burned(env: &Env, token_index: u32) -> i128 {
     env.storage()
         .persistent()
@@ -648,16 +779,636 @@ pub fn add_total_burned(env: &Env, token_index: u32, amount: i128) {
         .persistent()
         .set(&crate::types::DataKey::TotalBurned(token_index), &updated);
 }
-// Pause management
-pub fn is_paused(env: &Env) -> bool {
+// Structured token metadata
+pub fn get_token_metadata(env: &Env, token_index: u32) -> Option<crate::types::TokenMetadata> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TokenMetadata(token_index))
+}
+
+pub fn set_token_metadata(env: &Env, token_index: u32, metadata: &crate::types::TokenMetadata) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::TokenMetadata(token_index), metadata);
+}
+
+pub fn get_metadata_version(
+    env: &Env,
+    token_index: u32,
+    version: u32,
+) -> Option<crate::types::MetadataVersion> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MetadataVersion(token_index, version))
+}
+
+pub fn set_metadata_version(env: &Env, token_index: u32, record: &crate::types::MetadataVersion) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::MetadataVersion(token_index, record.version), record);
+}
+
+// Symbol registry (keys are normalized symbols)
+pub fn get_symbol_policy(env: &Env) -> SymbolPolicy {
     env.storage()
         .instance()
-        .get(&DataKey::Paused)
-        .unwrap_or(false)
+        .get(&ExtDataKey::SymbolPolicy)
+        .unwrap_or(SymbolPolicy::AllowDuplicates)
+}
+
+pub fn set_symbol_policy(env: &Env, policy: SymbolPolicy) {
+    env.storage().instance().set(&ExtDataKey::SymbolPolicy, &policy);
+}
+
+pub fn get_symbol_owner(env: &Env, symbol: &String) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&ExtDataKey::SymbolOwner(symbol.clone()))
+}
+
+pub fn set_symbol_owner(env: &Env, symbol: &String, token_index: u32) {
+    env.storage()
+        .persistent()
+        .set(&ExtDataKey::SymbolOwner(symbol.clone()), &token_index);
+}
+
+pub fn get_verified_symbol(env: &Env, symbol: &String) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&ExtDataKey::VerifiedSymbol(symbol.clone()))
+}
+
+pub fn set_verified_symbol(env: &Env, symbol: &String, token_index: Option<u32>) {
+    let key = ExtDataKey::VerifiedSymbol(symbol.clone());
+    match token_index {
+        Some(index) => env.storage().persistent().set(&key, &index),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+pub fn is_symbol_reserved(env: &Env, symbol: &String) -> bool {
+    env.storage()
+        .persistent()
+        .has(&ExtDataKey::ReservedSymbol(symbol.clone()))
+}
+
+pub fn set_symbol_reserved(env: &Env, symbol: &String, reserved: bool) {
+    let key = ExtDataKey::ReservedSymbol(symbol.clone());
+    if reserved {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+// Airdrop campaigns
+pub fn get_airdrop_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ExtDataKey::AirdropCount)
+        .unwrap_or(0)
+}
+
+pub fn set_airdrop_count(env: &Env, count: u32) {
+    env.storage().instance().set(&ExtDataKey::AirdropCount, &count);
+}
+
+pub fn get_airdrop(env: &Env, campaign_id: u32) -> Option<crate::types::AirdropCampaign> {
+    env.storage()
+        .persistent()
+        .get(&ExtDataKey::Airdrop(campaign_id))
+}
+
+pub fn set_airdrop(env: &Env, campaign_id: u32, campaign: &crate::types::AirdropCampaign) {
+    env.storage()
+        .persistent()
+        .set(&ExtDataKey::Airdrop(campaign_id), campaign);
+}
+
+pub fn is_airdrop_claimed(env: &Env, campaign_id: u32, index: u32) -> bool {
+    let word: u64 = env
+        .storage()
+        .persistent()
+        .get(&ExtDataKey::AirdropClaimed(campaign_id, index / 64))
+        .unwrap_or(0);
+    word & (1 << (index % 64)) != 0
+}
+
+pub fn set_airdrop_claimed(env: &Env, campaign_id: u32, index: u32) {
+    let key = ExtDataKey::AirdropClaimed(campaign_id, index / 64);
+    let word: u64 = env.storage().persistent().get(&key).unwrap_or(0);
+    env.storage()
+        .persistent()
+        .set(&key, &(word | (1 << (index % 64))));
+}
+
+// Snapshots (lazy checkpoints, written before the first change after a snapshot)
+pub fn get_snapshot_id(env: &Env, token_index: u32) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ExtDataKey::SnapshotId(token_index))
+        .unwrap_or(0)
+}
+
+pub fn set_snapshot_id(env: &Env, token_index: u32, snapshot_id: u32) {
+    env.storage()
+        .instance()
+        .set(&ExtDataKey::SnapshotId(token_index), &snapshot_id);
+}
+
+pub fn get_balance_checkpoint_count(env: &Env, token_index: u32, holder: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&ExtDataKey::BalanceCheckpointCount(token_index, holder.clone()))
+        .unwrap_or(0)
+}
+
+pub fn get_balance_checkpoint(
+    env: &Env,
+    token_index: u32,
+    holder: &Address,
+    position: u32,
+) -> Option<crate::types::Checkpoint> {
+    env.storage()
+        .persistent()
+        .get(&ExtDataKey::BalanceCheckpoint(token_index, holder.clone(), position))
+}
+
+pub fn get_supply_checkpoint_count(env: &Env, token_index: u32) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&ExtDataKey::SupplyCheckpointCount(token_index))
+        .unwrap_or(0)
+}
+
+pub fn get_supply_checkpoint(
+    env: &Env,
+    token_index: u32,
+    position: u32,
+) -> Option<crate::types::Checkpoint> {
+    env.storage()
+        .persistent()
+        .get(&ExtDataKey::SupplyCheckpoint(token_index, position))
+}
+
+fn checkpoint_balance(env: &Env, token_index: u32, holder: &Address, previous: i128) {
+    let snapshot_id = get_snapshot_id(env, token_index);
+    if snapshot_id == 0 {
+        return;
+    }
+
+    let count = get_balance_checkpoint_count(env, token_index, holder);
+    let needs_checkpoint = count == 0
+        || get_balance_checkpoint(env, token_index, holder, count - 1)
+            .map(|c| c.snapshot_id < snapshot_id)
+            .unwrap_or(true);
+    if needs_checkpoint {
+        let storage = env.storage().persistent();
+        storage.set(
+            &ExtDataKey::BalanceCheckpoint(token_index, holder.clone(), count),
+            &crate::types::Checkpoint {
+                snapshot_id,
+                value: previous,
+            },
+        );
+        storage.set(
+            &ExtDataKey::BalanceCheckpointCount(token_index, holder.clone()),
+            &(count + 1),
+        );
+    }
+}
+
+fn checkpoint_supply(env: &Env, token_index: u32, previous: i128) {
+    let snapshot_id = get_snapshot_id(env, token_index);
+    if snapshot_id == 0 {
+        return;
+    }
+
+    let count = get_supply_checkpoint_count(env, token_index);
+    let needs_checkpoint = count == 0
+        || get_supply_checkpoint(env, token_index, count - 1)
+            .map(|c| c.snapshot_id < snapshot_id)
+            .unwrap_or(true);
+    if needs_checkpoint {
+        let storage = env.storage().persistent();
+        storage.set(
+            &ExtDataKey::SupplyCheckpoint(token_index, count),
+            &crate::types::Checkpoint {
+                snapshot_id,
+                value: previous,
+            },
+        );
+        storage.set(&ExtDataKey::SupplyCheckpointCount(token_index), &(count + 1));
+    }
+}
+
+// Holder distributions
+pub fn get_distribution_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ExtDataKey::DistributionCount)
+        .unwrap_or(0)
+}
+
+pub fn set_distribution_count(env: &Env, count: u32) {
+    env.storage()
+        .instance()
+        .set(&ExtDataKey::DistributionCount, &count);
+}
+
+pub fn get_distribution(env: &Env, distribution_id: u32) -> Option<crate::types::Distribution> {
+    env.storage()
+        .persistent()
+        .get(&ExtDataKey::Distribution(distribution_id))
+}
+
+pub fn set_distribution(
+    env: &Env,
+    distribution_id: u32,
+    distribution: &crate::types::Distribution,
+) {
+    env.storage()
+        .persistent()
+        .set(&ExtDataKey::Distribution(distribution_id), distribution);
+}
+
+pub fn has_claimed_distribution(env: &Env, distribution_id: u32, holder: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&ExtDataKey::DistributionClaimed(distribution_id, holder.clone()))
+}
+
+pub fn set_distribution_claimed(env: &Env, distribution_id: u32, holder: &Address) {
+    env.storage().persistent().set(
+        &ExtDataKey::DistributionClaimed(distribution_id, holder.clone()),
+        &true,
+    );
+}
+
+/// Amount of a token held in contract escrow (streams, pools, airdrops,
+/// staking reward reserves)
+pub fn get_escrowed_supply(env: &Env, token_index: u32) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&ExtDataKey::EscrowedSupply(token_index))
+        .unwrap_or(0)
+}
+
+/// Add `delta` to a token's escrowed amount (negative when released)
+///
+/// Never goes below zero, so releasing escrow taken before it was tracked
+/// is harmless.
+pub fn adjust_escrowed_supply(env: &Env, token_index: u32, delta: i128) -> Result<(), Error> {
+    let escrowed = get_escrowed_supply(env, token_index)
+        .checked_add(delta)
+        .ok_or(Error::ArithmeticError)?
+        .max(0);
+    env.storage()
+        .persistent()
+        .set(&ExtDataKey::EscrowedSupply(token_index), &escrowed);
+    Ok(())
+}
+
+// Staking pools
+pub fn get_staking_pool_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ExtDataKey::StakingPoolCount)
+        .unwrap_or(0)
+}
+
+pub fn set_staking_pool_count(env: &Env, count: u32) {
+    env.storage()
+        .instance()
+        .set(&ExtDataKey::StakingPoolCount, &count);
+}
+
+pub fn get_staking_pool(env: &Env, pool_id: u32) -> Option<crate::types::StakingPool> {
+    env.storage()
+        .persistent()
+        .get(&ExtDataKey::StakingPool(pool_id))
+}
+
+pub fn set_staking_pool(env: &Env, pool_id: u32, pool: &crate::types::StakingPool) {
+    env.storage()
+        .persistent()
+        .set(&ExtDataKey::StakingPool(pool_id), pool);
+}
+
+pub fn get_stake_position(
+    env: &Env,
+    pool_id: u32,
+    staker: &Address,
+) -> Option<crate::types::StakePosition> {
+    env.storage()
+        .persistent()
+        .get(&ExtDataKey::StakePosition(pool_id, staker.clone()))
+}
+
+pub fn set_stake_position(
+    env: &Env,
+    pool_id: u32,
+    staker: &Address,
+    position: &crate::types::StakePosition,
+) {
+    env.storage()
+        .persistent()
+        .set(&ExtDataKey::StakePosition(pool_id, staker.clone()), position);
+}
+
+pub fn get_staked_balance(env: &Env, token_index: u32, holder: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&ExtDataKey::StakedBalance(token_index, holder.clone()))
+        .unwrap_or(0)
+}
+
+pub fn set_staked_balance(env: &Env, token_index: u32, holder: &Address, amount: i128) {
+    env.storage()
+        .persistent()
+        .set(&ExtDataKey::StakedBalance(token_index, holder.clone()), &amount);
+}
+
+// Flow streams
+pub fn get_flow_stream_count(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&ExtDataKey::FlowStreamCount)
+        .unwrap_or(0)
+}
+
+pub fn set_flow_stream_count(env: &Env, count: u64) {
+    env.storage()
+        .instance()
+        .set(&ExtDataKey::FlowStreamCount, &count);
+}
+
+pub fn get_flow_stream(env: &Env, stream_id: u64) -> Option<crate::types::FlowStream> {
+    env.storage()
+        .persistent()
+        .get(&ExtDataKey::FlowStream(stream_id))
+}
+
+pub fn set_flow_stream(env: &Env, stream_id: u64, stream: &crate::types::FlowStream) {
+    env.storage()
+        .persistent()
+        .set(&ExtDataKey::FlowStream(stream_id), stream);
+}
+
+pub fn get_payroll_pool_count(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&ExtDataKey::PayrollPoolCount)
+        .unwrap_or(0)
+}
+
+pub fn set_payroll_pool_count(env: &Env, count: u64) {
+    env.storage()
+        .persistent()
+        .set(&ExtDataKey::PayrollPoolCount, &count);
+}
+
+pub fn get_payroll_pool(env: &Env, pool_id: u64) -> Option<crate::types::PayrollPool> {
+    env.storage()
+        .persistent()
+        .get(&ExtDataKey::PayrollPool(pool_id))
+}
+
+pub fn set_payroll_pool(env: &Env, pool_id: u64, pool: &crate::types::PayrollPool) {
+    env.storage()
+        .persistent()
+        .set(&ExtDataKey::PayrollPool(pool_id), pool);
+}
+
+pub fn get_pool_member(
+    env: &Env,
+    pool_id: u64,
+    member: &Address,
+) -> Option<crate::types::PoolMember> {
+    env.storage()
+        .persistent()
+        .get(&ExtDataKey::PoolMember(pool_id, member.clone()))
+}
+
+pub fn set_pool_member(
+    env: &Env,
+    pool_id: u64,
+    member: &Address,
+    position: &crate::types::PoolMember,
+) {
+    env.storage()
+        .persistent()
+        .set(&ExtDataKey::PoolMember(pool_id, member.clone()), position);
+}
+
+pub fn remove_pool_member(env: &Env, pool_id: u64, member: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&ExtDataKey::PoolMember(pool_id, member.clone()));
+}
+
+pub fn get_claim_delegation(
+    env: &Env,
+    recipient: &Address,
+) -> Option<crate::types::ClaimDelegation> {
+    env.storage()
+        .persistent()
+        .get(&ExtDataKey::ClaimDelegation(recipient.clone()))
+}
+
+pub fn set_claim_delegation(
+    env: &Env,
+    recipient: &Address,
+    delegation: &crate::types::ClaimDelegation,
+) {
+    env.storage()
+        .persistent()
+        .set(&ExtDataKey::ClaimDelegation(recipient.clone()), delegation);
+}
+
+pub fn remove_claim_delegation(env: &Env, recipient: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&ExtDataKey::ClaimDelegation(recipient.clone()));
+}
+
+/// Ledger time of the last pushed claim under `key`
+/// (`StreamLastPushedClaim` or `VaultLastPushedClaim`)
+pub fn get_last_pushed_claim(env: &Env, key: &ExtDataKey) -> Option<u64> {
+    env.storage().persistent().get(key)
+}
+
+pub fn set_last_pushed_claim(env: &Env, key: &ExtDataKey, timestamp: u64) {
+    env.storage().persistent().set(key, &timestamp);
+}
+
+// Clawback notice period and audit log
+pub fn get_clawback_notice_period(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&ExtDataKey::ClawbackNoticePeriod)
+        .unwrap_or(0)
+}
+
+pub fn set_clawback_notice_period(env: &Env, seconds: u64) {
+    env.storage()
+        .instance()
+        .set(&ExtDataKey::ClawbackNoticePeriod, &seconds);
+}
+
+pub fn get_clawback_active_from(env: &Env, token_index: u32) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&ExtDataKey::ClawbackActiveFrom(token_index))
+}
+
+pub fn set_clawback_active_from(env: &Env, token_index: u32, timestamp: u64) {
+    env.storage()
+        .persistent()
+        .set(&ExtDataKey::ClawbackActiveFrom(token_index), &timestamp);
+}
+
+pub fn remove_clawback_active_from(env: &Env, token_index: u32) {
+    env.storage()
+        .persistent()
+        .remove(&ExtDataKey::ClawbackActiveFrom(token_index));
+}
+
+pub fn get_clawback_log_count(env: &Env, token_index: u32) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&ExtDataKey::ClawbackLogCount(token_index))
+        .unwrap_or(0)
+}
+
+pub fn get_clawback_record(env: &Env, token_index: u32, position: u32) -> Option<ClawbackRecord> {
+    env.storage()
+        .persistent()
+        .get(&ExtDataKey::ClawbackLog(token_index, position))
+}
+
+/// Append a clawback record and return its sequence number
+pub fn append_clawback_record(
+    env: &Env,
+    token_index: u32,
+    record: &ClawbackRecord,
+) -> Result<u32, Error> {
+    let position = get_clawback_log_count(env, token_index);
+    let next = position.checked_add(1).ok_or(Error::ArithmeticError)?;
+    let storage = env.storage().persistent();
+    storage.set(&ExtDataKey::ClawbackLog(token_index, position), record);
+    storage.set(&ExtDataKey::ClawbackLogCount(token_index), &next);
+    Ok(position)
+}
+
+// Stream amendment history
+pub fn get_stream_amendment_count(env: &Env, stream_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&ExtDataKey::StreamAmendmentCount(stream_id))
+        .unwrap_or(0)
+}
+
+pub fn get_stream_amendment(env: &Env, stream_id: u64, position: u32) -> Option<StreamAmendment> {
+    env.storage()
+        .persistent()
+        .get(&ExtDataKey::StreamAmendment(stream_id, position))
+}
+
+/// Append a stream amendment and return its sequence number
+pub fn append_stream_amendment(
+    env: &Env,
+    stream_id: u64,
+    amendment: &StreamAmendment,
+) -> Result<u32, Error> {
+    let position = get_stream_amendment_count(env, stream_id);
+    let next = position.checked_add(1).ok_or(Error::ArithmeticError)?;
+    let storage = env.storage().persistent();
+    storage.set(&ExtDataKey::StreamAmendment(stream_id, position), amendment);
+    storage.set(&ExtDataKey::StreamAmendmentCount(stream_id), &next);
+    Ok(position)
+}
+
+// Transfer tax and allowance management
+pub fn get_transfer_fee(env: &Env, token_index: u32) -> Option<crate::types::TransferFeeConfig> {
+    env.storage()
+        .instance()
+        .get(&DataKey::TransferFee(token_index))
+}
+
+pub fn set_transfer_fee(env: &Env, token_index: u32, config: &crate::types::TransferFeeConfig) {
+    env.storage()
+        .instance()
+        .set(&DataKey::TransferFee(token_index), config);
+}
+
+pub fn get_fees_collected(env: &Env, token_index: u32) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TransferFeesCollected(token_index))
+        .unwrap_or(0)
+}
+
+pub fn set_fees_collected(env: &Env, token_index: u32, amount: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::TransferFeesCollected(token_index), &amount);
+}
+
+pub fn get_allowance(env: &Env, token_index: u32, owner: &Address, spender: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Allowance(token_index, owner.clone(), spender.clone()))
+        .unwrap_or(0)
+}
+
+pub fn set_allowance(env: &Env, token_index: u32, owner: &Address, spender: &Address, amount: i128) {
+    let key = DataKey::Allowance(token_index, owner.clone(), spender.clone());
+    if amount == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &amount);
+    }
+}
+
+// Pause management
+/// Returns true only when every operation is paused (legacy global pause)
+pub fn is_paused(env: &Env) -> bool {
+    get_pause_flags(env) == PAUSE_ALL
 }
 
 pub fn set_paused(env: &Env, paused: bool) {
-    env.storage().instance().set(&DataKey::Paused, &paused);
+    set_pause_flags(env, if paused { PAUSE_ALL } else { 0 });
+}
+
+/// Current pause flags
+///
+/// Until flags are first written, a contract paused under the legacy
+/// global bit reads as `PAUSE_ALL`.
+pub fn get_pause_flags(env: &Env) -> u32 {
+    if let Some(flags) = env.storage().instance().get(&DataKey::PauseFlags) {
+        return flags;
+    }
+    let legacy_paused = env
+        .storage()
+        .instance()
+        .get(&ExtDataKey::Paused)
+        .unwrap_or(false);
+    if legacy_paused {
+        PAUSE_ALL
+    } else {
+        0
+    }
+}
+
+pub fn set_pause_flags(env: &Env, flags: u32) {
+    env.storage().instance().remove(&ExtDataKey::Paused);
+    env.storage().instance().set(&DataKey::PauseFlags, &flags);
+}
+
+/// Check whether any of the given operation flags is paused
+pub fn is_operation_paused(env: &Env, flag: u32) -> bool {
+    get_pause_flags(env) & flag != 0
 }
 
 // Token lookup by address
@@ -673,6 +1424,59 @@ pub fn set_token_info_by_address(env: &Env, token_address: &Address, info: &Toke
         .set(&DataKey::TokenByAddress(token_address.clone()), info);
 }
 
+pub fn get_token_index_by_address(env: &Env, token_address: &Address) -> Option<u32> {
+    env.storage()
+        .instance()
+        .get(&DataKey::TokenIndexByAddress(token_address.clone()))
+}
+
+pub fn set_token_index_by_address(env: &Env, token_address: &Address, index: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::TokenIndexByAddress(token_address.clone()), &index);
+}
+
+// Compliance management
+pub fn get_compliance_mode(env: &Env, token_index: u32) -> crate::types::ComplianceMode {
+    env.storage()
+        .instance()
+        .get(&DataKey::ComplianceMode(token_index))
+        .unwrap_or(crate::types::ComplianceMode::Open)
+}
+
+pub fn set_compliance_mode(env: &Env, token_index: u32, mode: crate::types::ComplianceMode) {
+    env.storage()
+        .instance()
+        .set(&DataKey::ComplianceMode(token_index), &mode);
+}
+
+pub fn get_holder_list_entry(
+    env: &Env,
+    token_index: u32,
+    holder: &Address,
+) -> Option<crate::types::HolderList> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::HolderListEntry(token_index, holder.clone()))
+}
+
+pub fn set_holder_list_entry(
+    env: &Env,
+    token_index: u32,
+    holder: &Address,
+    list: crate::types::HolderList,
+) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::HolderListEntry(token_index, holder.clone()), &list);
+}
+
+pub fn remove_holder_list_entry(env: &Env, token_index: u32, holder: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::HolderListEntry(token_index, holder.clone()));
+}
+
 // Update token supply after burn
 pub fn update_token_supply(env: &Env, token_address: &Address, amount_change: i128) -> Option<()> {
     let mut info = get_token_info_by_address(env, token_address)?;
@@ -713,21 +1517,36 @@ pub fn get_admin_state(env: &Env) -> (Address, bool) {
 }
 
 // ── Timelock storage functions ─────────────────────────────
+//
+// The config and changes are stored under `ExtDataKey`. Contracts upgraded
+// from before change expiry may still hold them in the older shapes under
+// `DataKey`; those are read back with the default expiry window, and are
+// replaced by the new entry the next time they are written.
 
 pub fn get_timelock_config(env: &Env) -> crate::types::TimelockConfig {
-    env.storage()
+    if let Some(config) = env.storage().instance().get(&ExtDataKey::TimelockSettings) {
+        return config;
+    }
+    let legacy = env
+        .storage()
         .instance()
-        .get(&DataKey::TimelockConfig)
-        .unwrap_or(crate::types::TimelockConfig {
-            delay_seconds: 172_800, // 48 hours default
+        .get::<_, crate::types::LegacyTimelockConfig>(&DataKey::TimelockConfig)
+        .unwrap_or(crate::types::LegacyTimelockConfig {
+            delay_seconds: crate::timelock::DEFAULT_TIMELOCK_DELAY,
             enabled: false,
-        })
+        });
+    crate::types::TimelockConfig {
+        delay_seconds: legacy.delay_seconds,
+        enabled: legacy.enabled,
+        expiry_seconds: crate::timelock::DEFAULT_CHANGE_EXPIRY,
+    }
 }
 
 pub fn set_timelock_config(env: &Env, config: &crate::types::TimelockConfig) {
+    env.storage().instance().remove(&DataKey::TimelockConfig);
     env.storage()
         .instance()
-        .set(&DataKey::TimelockConfig, config);
+        .set(&ExtDataKey::TimelockSettings, config);
 }
 
 pub fn get_next_change_id(env: &Env) -> Result<u64, Error> {
@@ -743,22 +1562,59 @@ pub fn get_next_change_id(env: &Env) -> Result<u64, Error> {
     Ok(id)
 }
 
-pub fn get_pending_change(env: &Env, change_id: u64) -> Option<crate::types::PendingChange> {
+/// Get the number of changes ever scheduled (ids are `0..count`)
+pub fn get_change_count(env: &Env) -> u64 {
     env.storage()
-        .persistent()
-        .get(&DataKey::PendingChange(change_id))
+        .instance()
+        .get(&DataKey::NextChangeId)
+        .unwrap_or(0_u64)
 }
 
-pub fn set_pending_change(env: &Env, change_id: u64, change: &crate::types::PendingChange) {
+pub fn get_pending_change(env: &Env, change_id: u64) -> Option<crate::types::PendingChange> {
+    if let Some(change) = env
+        .storage()
+        .persistent()
+        .get(&ExtDataKey::TimelockChange(change_id))
+    {
+        return Some(change);
+    }
     env.storage()
         .persistent()
-        .set(&DataKey::PendingChange(change_id), change);
+        .get::<_, crate::types::LegacyPendingChange>(&DataKey::PendingChange(change_id))
+        .map(|legacy| {
+            let mut change = crate::types::PendingChange {
+                id: legacy.id,
+                change_type: legacy.change_type,
+                scheduled_by: legacy.scheduled_by,
+                scheduled_at: legacy.scheduled_at,
+                execute_at: legacy.execute_at,
+                expires_at: legacy
+                    .execute_at
+                    .saturating_add(get_timelock_config(env).expiry_seconds),
+                executed: legacy.executed,
+                status: if legacy.executed {
+                    crate::types::ChangeStatus::Executed
+                } else {
+                    crate::types::ChangeStatus::Pending
+                },
+                content_hash: BytesN::from_array(env, &[0u8; 32]),
+                base_fee: legacy.base_fee,
+                metadata_fee: legacy.metadata_fee,
+                paused: legacy.paused,
+                treasury: legacy.treasury,
+            };
+            change.content_hash = crate::timelock::compute_content_hash(env, &change);
+            change
+        })
 }
 
-pub fn remove_pending_change(env: &Env, change_id: u64) {
+pub fn set_pending_change(env: &Env, change_id: u64, change: &crate::types::PendingChange) {
     env.storage()
         .persistent()
         .remove(&DataKey::PendingChange(change_id));
+    env.storage()
+        .persistent()
+        .set(&ExtDataKey::TimelockChange(change_id), change);
 }
 
 // ── Creator indexing functions ─────────────────────────────
@@ -800,68 +1656,29 @@ pub fn get_creator_token_count(env: &Env, creator: &Address) -> u32 {
         .unwrap_or(0)
 }
 
-/// Get beneficiary stream count (alias for creator token count for now)
-pub fn get_beneficiary_stream_count(env: &Env, beneficiary: &Address) -> u32 {
-    get_creator_token_count(env, beneficiary)
-}
-
-/// Get beneficiary stream entry (alias for creator token entry for now)
-pub fn get_beneficiary_stream_entry(env: &Env, beneficiary: &Address, index: u32) -> Option<u32> {
-    let tokens = get_creator_tokens(env, beneficiary);
-    if index < tokens.len() {
-        Some(tokens.get(index).unwrap())
-    } else {
-        None
-    }
-}
-
 // ── Token-stream indexing functions ─────────────────────────────
 
-/// Add a stream ID to a token's stream list
+/// Append a stream to its token's stream index
 ///
-/// Appends the stream_id to the token's stream vector and updates
-/// the TokenStreamCount atomically. If the token has no existing
-/// streams, initializes an empty vector first.
+/// Entries are keyed by position in persistent storage, so the index can
+/// grow without loading or rewriting earlier entries.
 ///
 /// # Arguments
 /// * `env` - The contract environment
 /// * `token_index` - Index of the token
 /// * `stream_id` - ID of the stream to add
-pub fn add_token_stream(env: &Env, token_index: u32, stream_id: u32) {
-    let key = DataKey::TokenStreams(token_index);
-    let mut streams: soroban_sdk::Vec<u32> = env
-        .storage()
-        .instance()
-        .get(&key)
-        .unwrap_or(soroban_sdk::Vec::new(env));
-
-    streams.push_back(stream_id);
-
-    env.storage().instance().set(&key, &streams);
-
-    // Update count atomically
-    let count = streams.len();
-    env.storage()
-        .instance()
-        .set(&DataKey::TokenStreamCount(token_index), &count);
+pub fn add_token_stream(env: &Env, token_index: u32, stream_id: u64) {
+    let count = get_token_stream_count(env, token_index);
+    let storage = env.storage().persistent();
+    storage.set(&ExtDataKey::TokenStreamAt(token_index, count), &stream_id);
+    storage.set(&DataKey::TokenStreamCount(token_index), &(count + 1));
 }
 
-/// Get all stream IDs for a token
-///
-/// Retrieves the vector of stream IDs associated with the specified token.
-/// Returns an empty vector if the token has no streams.
-///
-/// # Arguments
-/// * `env` - The contract environment
-/// * `token_index` - Index of the token
-///
-/// # Returns
-/// Vector of stream IDs for this token (empty if none exist)
-pub fn get_token_streams(env: &Env, token_index: u32) -> soroban_sdk::Vec<u32> {
+/// Get the stream id at a position of a token's stream index
+pub fn get_token_stream_at(env: &Env, token_index: u32, position: u32) -> Option<u64> {
     env.storage()
-        .instance()
-        .get(&DataKey::TokenStreams(token_index))
-        .unwrap_or(soroban_sdk::Vec::new(env))
+        .persistent()
+        .get(&ExtDataKey::TokenStreamAt(token_index, position))
 }
 
 /// Get the count of streams for a token
@@ -877,7 +1694,7 @@ pub fn get_token_streams(env: &Env, token_index: u32) -> soroban_sdk::Vec<u32> {
 /// Number of streams for this token
 pub fn get_token_stream_count(env: &Env, token_index: u32) -> u32 {
     env.storage()
-        .instance()
+        .persistent()
         .get(&DataKey::TokenStreamCount(token_index))
         .unwrap_or(0)
 }
@@ -982,6 +1799,87 @@ pub fn get_next_stream_id(env: &Env) -> u64 {
     id
 }
 
+pub fn get_recipient_stream_count(env: &Env, recipient: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&ExtDataKey::RecipientStreamCount(recipient.clone()))
+        .unwrap_or(0)
+}
+
+pub fn get_recipient_stream_at(env: &Env, recipient: &Address, position: u32) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&ExtDataKey::RecipientStreamAt(recipient.clone(), position))
+}
+
+/// Append a stream to its recipient's stream index
+pub fn add_recipient_stream(env: &Env, recipient: &Address, stream_id: u64) {
+    let count = get_recipient_stream_count(env, recipient);
+    let storage = env.storage().persistent();
+    storage.set(
+        &ExtDataKey::RecipientStreamAt(recipient.clone(), count),
+        &stream_id,
+    );
+    storage.set(&ExtDataKey::RecipientStreamPosition(stream_id), &count);
+    storage.set(
+        &ExtDataKey::RecipientStreamCount(recipient.clone()),
+        &(count + 1),
+    );
+}
+
+/// Remove a stream from its recipient's stream index
+///
+/// The last entry is moved into the freed position.
+pub fn remove_recipient_stream(env: &Env, recipient: &Address, stream_id: u64) {
+    let storage = env.storage().persistent();
+    let position_key = ExtDataKey::RecipientStreamPosition(stream_id);
+    let Some(position) = storage.get::<_, u32>(&position_key) else {
+        return;
+    };
+
+    let last = get_recipient_stream_count(env, recipient) - 1;
+    if position != last {
+        let moved: u64 = storage
+            .get(&ExtDataKey::RecipientStreamAt(recipient.clone(), last))
+            .unwrap();
+        storage.set(
+            &ExtDataKey::RecipientStreamAt(recipient.clone(), position),
+            &moved,
+        );
+        storage.set(&ExtDataKey::RecipientStreamPosition(moved), &position);
+    }
+    storage.remove(&ExtDataKey::RecipientStreamAt(recipient.clone(), last));
+    storage.remove(&position_key);
+    storage.set(&ExtDataKey::RecipientStreamCount(recipient.clone()), &last);
+}
+
+pub fn get_creator_stream_count(env: &Env, creator: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&ExtDataKey::CreatorStreamCount(creator.clone()))
+        .unwrap_or(0)
+}
+
+pub fn get_creator_stream_at(env: &Env, creator: &Address, position: u32) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&ExtDataKey::CreatorStreamAt(creator.clone(), position))
+}
+
+/// Append a stream to its creator's stream index
+pub fn add_creator_stream(env: &Env, creator: &Address, stream_id: u64) {
+    let count = get_creator_stream_count(env, creator);
+    let storage = env.storage().persistent();
+    storage.set(
+        &ExtDataKey::CreatorStreamAt(creator.clone(), count),
+        &stream_id,
+    );
+    storage.set(
+        &ExtDataKey::CreatorStreamCount(creator.clone()),
+        &(count + 1),
+    );
+}
+
 // ── Vault storage functions ───────────────────────────────
 
 /// Get the total number of vaults created.
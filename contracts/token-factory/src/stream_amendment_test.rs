@@ -0,0 +1,150 @@
+//! Stream Amendment Tests
+//!
+//! Tests for topping up and extending streams: re-basing preserves the
+//! vested amount, slowing vesting needs the recipient, and every change is
+//! recorded in the amendment history
+
+use soroban_sdk::{testutils::Events, Address, IntoVal, Symbol};
+
+use crate::test_helpers::{create_stream, set_time, StreamFixture};
+use crate::types::Error;
+
+const SUPPLY: i128 = 1_000_000;
+
+/// Token 0 owned by the creator and a stream of 1_000 to alice from t=100
+/// to t=200 with a cliff at t=150 (stream 0)
+fn setup() -> StreamFixture {
+    let s = StreamFixture::new("GRT", SUPPLY);
+    set_time(&s.env, 100);
+    create_stream(&s.client, &s.creator, &s.alice, 1_000, 100, 150, 200);
+    s
+}
+
+fn recipient_authorized(s: &StreamFixture) -> bool {
+    s.env.auths().iter().any(|(address, _)| *address == s.alice)
+}
+
+#[test]
+fn test_top_up_preserves_vested_amount() {
+    let s = setup();
+
+    set_time(&s.env, 160);
+    s.client.top_up_stream(&s.creator, &0, &1_000, &260);
+    assert!(!recipient_authorized(&s));
+    assert_eq!(s.client.get_balance(&0, &s.creator), SUPPLY - 2_000);
+
+    // 600 vested before the top-up; the other 1_400 vest over 100s
+    assert_eq!(s.client.get_stream_claimable(&0), 600);
+    set_time(&s.env, 210);
+    assert_eq!(s.client.claim_stream(&s.alice, &0), 1_300);
+    set_time(&s.env, 260);
+    assert_eq!(s.client.claim_stream(&s.alice, &0), 700);
+
+    let page = s.client.get_stream_amendments(&0, &0, &10);
+    assert_eq!(page.amendments.len(), 1);
+    assert_eq!(page.next_cursor, None);
+    let amendment = page.amendments.get(0).unwrap();
+    assert_eq!(amendment.additional_amount, 1_000);
+    assert_eq!(amendment.previous_end_time, 200);
+    assert_eq!(amendment.new_end_time, 260);
+    assert_eq!(amendment.vested_amount, 600);
+    assert!(!amendment.recipient_consented);
+    assert_eq!(amendment.timestamp, 160);
+}
+
+#[test]
+fn test_slowing_vesting_needs_recipient() {
+    let s = setup();
+
+    // Stretching the remaining 400 over 140s instead of 40s
+    set_time(&s.env, 160);
+    s.client.top_up_stream(&s.creator, &0, &0, &300);
+    assert!(recipient_authorized(&s));
+    assert!(
+        s.client
+            .get_stream_amendments(&0, &0, &10)
+            .amendments
+            .get(0)
+            .unwrap()
+            .recipient_consented
+    );
+
+    set_time(&s.env, 230);
+    assert_eq!(s.client.get_stream_claimable(&0), 800);
+
+    // Shortening speeds vesting up and needs only the creator
+    s.client.top_up_stream(&s.creator, &0, &0, &240);
+    assert!(!recipient_authorized(&s));
+    assert_eq!(s.client.get_stream_claimable(&0), 800);
+    set_time(&s.env, 235);
+    assert_eq!(s.client.get_stream_claimable(&0), 900);
+}
+
+#[test]
+fn test_top_up_after_pause_and_end() {
+    let s = setup();
+
+    // 30s of pausing moves the effective end to 230
+    set_time(&s.env, 160);
+    s.client.pause_stream(&s.creator, &0);
+    assert_eq!(
+        s.client.try_top_up_stream(&s.creator, &0, &100, &300),
+        Err(Ok(Error::InvalidStateTransition))
+    );
+    set_time(&s.env, 190);
+    s.client.unpause_stream(&s.creator, &0);
+
+    set_time(&s.env, 300);
+    assert_eq!(s.client.claim_stream(&s.alice, &0), 1_000);
+
+    // A finished stream restarts from its full amount
+    s.client.top_up_stream(&s.creator, &0, &500, &400);
+    let (_, topics, data) = s.env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (Symbol::new(&s.env, "vlt_tu_v1"), 0u64).into_val(&s.env)
+    );
+    let payload: (Address, i128, u64, i128) = data.into_val(&s.env);
+    assert_eq!(payload, (s.creator.clone(), 500, 400, 1_000));
+
+    assert_eq!(s.client.get_stream_claimable(&0), 0);
+    set_time(&s.env, 350);
+    assert_eq!(s.client.get_stream_claimable(&0), 250);
+}
+
+#[test]
+fn test_top_up_validation() {
+    let s = setup();
+    set_time(&s.env, 160);
+
+    assert_eq!(
+        s.client.try_top_up_stream(&s.alice, &0, &100, &300),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.client.try_top_up_stream(&s.creator, &0, &-1, &300),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        s.client.try_top_up_stream(&s.creator, &0, &100, &160),
+        Err(Ok(Error::InvalidSchedule))
+    );
+    assert_eq!(
+        s.client.try_top_up_stream(&s.creator, &0, &0, &200),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(
+        s.client.try_top_up_stream(&s.creator, &0, &SUPPLY, &300),
+        Err(Ok(Error::InsufficientBalance))
+    );
+
+    s.client.cancel_stream(&s.creator, &0);
+    assert_eq!(
+        s.client.try_top_up_stream(&s.creator, &0, &100, &300),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(
+        s.client.get_stream_amendments(&0, &0, &10).amendments.len(),
+        0
+    );
+}
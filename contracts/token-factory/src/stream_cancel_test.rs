@@ -0,0 +1,132 @@
+//! Stream Cancellation Tests
+//!
+//! Tests for stream escrow and settle-and-refund on cancellation: the
+//! recipient keeps what vested before the cancel, the creator gets back
+//! the unvested remainder
+
+use soroban_sdk::{testutils::Events, vec, Address, IntoVal, Symbol};
+
+use crate::test_helpers::{create_stream, set_time, stream_params, StreamFixture};
+use crate::types::{Error, StreamParams};
+
+const SUPPLY: i128 = 1_000_000;
+
+/// 1_000 streamed to alice from t=100 to t=200 with a cliff at t=150
+fn stream(s: &StreamFixture) -> u64 {
+    set_time(&s.env, 100);
+    create_stream(&s.client, &s.creator, &s.alice, 1_000, 100, 150, 200)
+}
+
+#[test]
+fn test_stream_is_escrowed_and_paid_out() {
+    let s = StreamFixture::new("STR", SUPPLY);
+    let id = stream(&s);
+    assert_eq!(s.client.get_balance(&0, &s.creator), SUPPLY - 1_000);
+
+    set_time(&s.env, 160);
+    assert_eq!(s.client.claim_stream(&s.alice, &id), 600);
+    assert_eq!(s.client.get_balance(&0, &s.alice), 600);
+}
+
+#[test]
+fn test_cancel_settles_vested_and_refunds_rest() {
+    let s = StreamFixture::new("STR", SUPPLY);
+    let id = stream(&s);
+
+    set_time(&s.env, 160);
+    s.client.claim_stream(&s.alice, &id);
+
+    set_time(&s.env, 170);
+    assert_eq!(s.client.cancel_stream(&s.creator, &id), 300);
+    assert_eq!(s.client.get_balance(&0, &s.creator), SUPPLY - 700);
+
+    let info = s.client.get_stream(&id).unwrap();
+    assert!(info.cancelled);
+    assert_eq!(info.cancelled_at, 170);
+    assert_eq!(info.settled_amount, 100);
+    assert_eq!(info.refunded_amount, 300);
+
+    // Vesting stops at the cancel; the settled amount stays claimable
+    set_time(&s.env, 500);
+    assert_eq!(s.client.get_stream_claimable(&id), 100);
+    assert_eq!(s.client.claim_stream(&s.alice, &id), 100);
+    assert_eq!(s.client.get_balance(&0, &s.alice), 700);
+    assert_eq!(
+        s.client.try_claim_stream(&s.alice, &id),
+        Err(Ok(Error::NothingToClaim))
+    );
+}
+
+#[test]
+fn test_cancel_before_cliff_refunds_everything() {
+    let s = StreamFixture::new("STR", SUPPLY);
+    let id = stream(&s);
+
+    set_time(&s.env, 140);
+    assert_eq!(s.client.cancel_stream(&s.creator, &id), 1_000);
+    assert_eq!(s.client.get_balance(&0, &s.creator), SUPPLY);
+    assert_eq!(s.client.get_stream(&id).unwrap().settled_amount, 0);
+
+    set_time(&s.env, 500);
+    assert_eq!(
+        s.client.try_claim_stream(&s.alice, &id),
+        Err(Ok(Error::NothingToClaim))
+    );
+}
+
+#[test]
+fn test_cancel_validation() {
+    let s = StreamFixture::new("STR", SUPPLY);
+    let id = stream(&s);
+
+    assert_eq!(
+        s.client.try_cancel_stream(&s.alice, &id),
+        Err(Ok(Error::Unauthorized))
+    );
+    s.client.cancel_stream(&s.creator, &id);
+    assert_eq!(
+        s.client.try_cancel_stream(&s.creator, &id),
+        Err(Ok(Error::InvalidParameters))
+    );
+}
+
+#[test]
+fn test_create_requires_funds() {
+    let s = StreamFixture::new("STR", SUPPLY);
+    s.client.transfer(&s.creator, &0, &s.alice, &(SUPPLY - 500));
+
+    let params = stream_params(&s.alice, 1_000, 100, 100, 200);
+    assert_eq!(
+        s.client.try_create_stream(&s.creator, &params),
+        Err(Ok(Error::InsufficientBalance))
+    );
+
+    // The batch is rejected as a whole
+    let half = StreamParams {
+        total_amount: 300,
+        ..params
+    };
+    assert_eq!(
+        s.client
+            .try_batch_create_streams(&s.creator, &vec![&s.env, half.clone(), half]),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    assert_eq!(s.client.get_balance(&0, &s.creator), 500);
+}
+
+#[test]
+fn test_cancel_event_carries_settlement() {
+    let s = StreamFixture::new("STR", SUPPLY);
+    let id = stream(&s);
+
+    set_time(&s.env, 175);
+    s.client.cancel_stream(&s.creator, &id);
+
+    let (_, topics, data) = s.env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (Symbol::new(&s.env, "vlt_cn_v1"), id as u32).into_val(&s.env)
+    );
+    let payload: (Address, i128, i128, u64) = data.into_val(&s.env);
+    assert_eq!(payload, (s.creator.clone(), 250, 750, 175));
+}
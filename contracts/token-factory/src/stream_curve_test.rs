@@ -0,0 +1,79 @@
+//! Stream Vesting Curve Tests
+//!
+//! Tests for creating and claiming streams that vest along non-linear
+//! curves, and for curve validation at creation
+
+use soroban_sdk::vec;
+
+use crate::test_helpers::{set_time, stream_params, StreamFixture};
+use crate::types::{Error, StreamParams, VestingCurve};
+
+/// 1_000 streamed to alice from t=100 to t=500 with no cliff
+fn params(s: &StreamFixture, curve: VestingCurve) -> StreamParams {
+    StreamParams {
+        curve,
+        ..stream_params(&s.alice, 1_000, 100, 100, 500)
+    }
+}
+
+#[test]
+fn test_stepped_stream_unlocks_per_step() {
+    let s = StreamFixture::new("CRV", 1_000_000);
+    let id = s
+        .client
+        .create_stream(&s.creator, &params(&s, VestingCurve::Stepped(4)));
+
+    set_time(&s.env, 199);
+    assert_eq!(s.client.get_stream_claimable(&id), 0);
+    set_time(&s.env, 300);
+    assert_eq!(s.client.claim_stream(&s.alice, &id), 500);
+    set_time(&s.env, 500);
+    assert_eq!(s.client.claim_stream(&s.alice, &id), 500);
+    assert_eq!(
+        s.client.get_stream(&id).unwrap().curve,
+        VestingCurve::Stepped(4)
+    );
+}
+
+#[test]
+fn test_piecewise_stream_cancel_settles_along_curve() {
+    let s = StreamFixture::new("CRV", 1_000_000);
+    let curve = VestingCurve::Piecewise(vec![&s.env, (200, 8_000)]);
+    let id = s.client.create_stream(&s.creator, &params(&s, curve));
+
+    // 80% is front-loaded into the first quarter
+    set_time(&s.env, 150);
+    assert_eq!(s.client.cancel_stream(&s.creator, &id), 600);
+    assert_eq!(s.client.claim_stream(&s.alice, &id), 400);
+}
+
+#[test]
+fn test_malformed_curves_are_rejected() {
+    let s = StreamFixture::new("CRV", 1_000_000);
+
+    for curve in [
+        VestingCurve::Stepped(0),
+        VestingCurve::Exponential(0),
+        VestingCurve::Piecewise(vec![&s.env, (600, 5_000)]),
+    ] {
+        assert_eq!(
+            s.client.try_create_stream(&s.creator, &params(&s, curve)),
+            Err(Ok(Error::InvalidSchedule))
+        );
+    }
+}
+
+#[test]
+fn test_only_linear_streams_can_be_topped_up() {
+    let s = StreamFixture::new("CRV", 1_000_000);
+    let id = s
+        .client
+        .create_stream(&s.creator, &params(&s, VestingCurve::Exponential(2)));
+
+    set_time(&s.env, 300);
+    assert_eq!(s.client.get_stream_claimable(&id), 250);
+    assert_eq!(
+        s.client.try_top_up_stream(&s.creator, &id, &1_000, &900),
+        Err(Ok(Error::InvalidParameters))
+    );
+}
@@ -0,0 +1,123 @@
+//! Stream Index Tests
+//!
+//! Tests for the per-recipient, per-sender and per-token stream listings
+//! and their status filters
+
+use soroban_sdk::{testutils::Address as _, vec, Address, Vec};
+
+use crate::test_helpers::{create_stream, create_token, set_time, stream_params, StreamFixture};
+use crate::types::{StreamInfoPage, StreamParams, StreamStatus};
+
+/// Tokens 0 and 1 owned by the creator, ledger time 100
+fn setup() -> StreamFixture {
+    let s = StreamFixture::new("IDA", 1_000_000);
+    create_token(&s.client, &s.creator, "IDB", 1_000_000);
+    set_time(&s.env, 100);
+    s
+}
+
+/// Stream 1_000 of `token_index` from t=100 to t=200
+fn stream(s: &StreamFixture, creator: &Address, recipient: &Address, token_index: u32) -> u64 {
+    let params = StreamParams {
+        token_index,
+        ..stream_params(recipient, 1_000, 100, 100, 200)
+    };
+    s.client.create_stream(creator, &params)
+}
+
+fn ids(s: &StreamFixture, page: &StreamInfoPage) -> Vec<u64> {
+    let mut ids = Vec::new(&s.env);
+    for stream in page.streams.iter() {
+        ids.push_back(stream.id);
+    }
+    ids
+}
+
+#[test]
+fn test_listings_are_keyed_by_recipient_sender_and_token() {
+    let s = setup();
+    let other_creator = Address::generate(&s.env);
+    s.client.transfer(&s.creator, &1, &other_creator, &10_000);
+
+    let a0 = stream(&s, &s.creator, &s.alice, 0);
+    let b1 = stream(&s, &s.creator, &s.bob, 1);
+    let a1 = stream(&s, &other_creator, &s.alice, 1);
+
+    let page = s.client.get_streams_by_recipient(&s.alice, &None, &0, &10);
+    assert_eq!(ids(&s, &page), vec![&s.env, a0, a1]);
+    assert_eq!(page.streams.get(1).unwrap().creator, other_creator);
+    assert_eq!(page.next_cursor, None);
+
+    let page = s.client.get_streams_by_sender(&s.creator, &None, &0, &10);
+    assert_eq!(ids(&s, &page), vec![&s.env, a0, b1]);
+    let page = s.client.get_streams_by_token(&1, &None, &0, &10);
+    assert_eq!(ids(&s, &page), vec![&s.env, b1, a1]);
+
+    // The beneficiary listing returns stream ids, not the creator's tokens
+    let page = s.client.get_streams_by_beneficiary(&s.alice, &0, &10);
+    assert_eq!(page.stream_ids, vec![&s.env, a0, a1]);
+    let page = s.client.get_streams_by_beneficiary(&s.creator, &0, &10);
+    assert!(page.stream_ids.is_empty());
+}
+
+#[test]
+fn test_status_filters() {
+    let s = setup();
+    let active = stream(&s, &s.creator, &s.alice, 0);
+    let paused = stream(&s, &s.creator, &s.alice, 0);
+    let cancelled = stream(&s, &s.creator, &s.alice, 0);
+    let completed = create_stream(&s.client, &s.creator, &s.alice, 1_000, 100, 100, 150);
+
+    set_time(&s.env, 160);
+    s.client.pause_stream(&s.creator, &paused);
+    s.client.cancel_stream(&s.creator, &cancelled);
+    s.client.claim_stream(&s.alice, &completed);
+
+    for (status, expected) in [
+        (StreamStatus::Active, active),
+        (StreamStatus::Paused, paused),
+        (StreamStatus::Cancelled, cancelled),
+        (StreamStatus::Completed, completed),
+    ] {
+        let page = s
+            .client
+            .get_streams_by_recipient(&s.alice, &Some(status), &0, &10);
+        assert_eq!(ids(&s, &page), vec![&s.env, expected]);
+    }
+    let page = s.client.get_streams_by_token(&0, &None, &0, &10);
+    assert_eq!(page.streams.len(), 4);
+}
+
+#[test]
+fn test_pages_and_transfers() {
+    let s = setup();
+    for _ in 0..5 {
+        stream(&s, &s.creator, &s.alice, 0);
+    }
+
+    let page = s.client.get_streams_by_sender(&s.creator, &None, &0, &2);
+    assert_eq!(ids(&s, &page), vec![&s.env, 0, 1]);
+    assert_eq!(page.next_cursor, Some(2));
+    let page = s.client.get_streams_by_sender(&s.creator, &None, &4, &2);
+    assert_eq!(ids(&s, &page), vec![&s.env, 4]);
+    assert_eq!(page.next_cursor, None);
+    let page = s.client.get_streams_by_sender(&s.creator, &None, &9, &2);
+    assert!(page.streams.is_empty());
+
+    // A filtered page reads `limit` positions even if few of them match
+    s.client.cancel_stream(&s.creator, &3);
+    let page = s
+        .client
+        .get_streams_by_sender(&s.creator, &Some(StreamStatus::Cancelled), &0, &2);
+    assert!(page.streams.is_empty());
+    assert_eq!(page.next_cursor, Some(2));
+
+    // The last entry takes the place of a stream transferred away
+    s.client.transfer_stream(&1, &s.alice, &s.bob);
+    let page = s.client.get_streams_by_recipient(&s.alice, &None, &0, &10);
+    assert_eq!(ids(&s, &page), vec![&s.env, 0, 4, 2, 3]);
+    let page = s.client.get_streams_by_recipient(&s.bob, &None, &0, &10);
+    assert_eq!(ids(&s, &page), vec![&s.env, 1]);
+    let page = s.client.get_streams_by_sender(&s.creator, &None, &0, &10);
+    assert_eq!(page.streams.len(), 5);
+}
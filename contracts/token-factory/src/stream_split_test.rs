@@ -0,0 +1,206 @@
+//! Stream Split Tests
+//!
+//! Tests for dividing a stream into child streams, including the
+//! invariant that the children together pay out what the parent would
+
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    vec, Address, IntoVal, Symbol, Vec,
+};
+
+use crate::test_helpers::{set_time, stream_params, StreamFixture};
+use crate::types::{Error, StreamParams, StreamStatus, VestingCurve};
+
+/// Token 0 owned by the creator, ledger time 100
+fn setup() -> StreamFixture {
+    let s = StreamFixture::new("SPL", 1_000_000);
+    set_time(&s.env, 100);
+    s
+}
+
+/// Stream `total_amount` to alice from t=100 to t=200 with a cliff at 120
+fn stream(s: &StreamFixture, total_amount: i128, curve: VestingCurve) -> u64 {
+    let params = StreamParams {
+        curve,
+        ..stream_params(&s.alice, total_amount, 100, 120, 200)
+    };
+    s.client.create_stream(&s.creator, &params)
+}
+
+#[test]
+fn test_split_apportions_unclaimed_and_unvested() {
+    let s = setup();
+    let id = stream(&s, 1_000, VestingCurve::Linear);
+
+    set_time(&s.env, 130);
+    assert_eq!(s.client.claim_stream(&s.alice, &id), 300);
+
+    set_time(&s.env, 150);
+    let parts = vec![&s.env, (s.alice.clone(), 7_000), (s.bob.clone(), 3_000)];
+    let children = s.client.split_stream(&id, &parts);
+
+    let (_, topics, data) = s.env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (Symbol::new(&s.env, "vlt_sp_v1"), id).into_val(&s.env)
+    );
+    let payload: (Address, Vec<u64>, i128) = data.into_val(&s.env);
+    assert_eq!(payload, (s.alice.clone(), children.clone(), 700));
+
+    let parent = s.client.get_stream(&id).unwrap();
+    assert_eq!(parent.children, children);
+    assert_eq!(parent.claimed_amount, 300);
+    assert_eq!(s.client.get_stream_claimable(&id), 0);
+
+    let (a, b) = (children.get(0).unwrap(), children.get(1).unwrap());
+    let child = s.client.get_stream(&b).unwrap();
+    assert_eq!(child.parent_id, Some(id));
+    assert_eq!(child.recipient, s.bob);
+    assert_eq!(
+        (child.start_time, child.cliff_time, child.end_time),
+        (100, 120, 200)
+    );
+
+    // The 200 vested but unclaimed splits 70/30, as does the unvested 500
+    assert_eq!(s.client.get_stream_claimable(&a), 140);
+    assert_eq!(s.client.get_stream_claimable(&b), 60);
+    set_time(&s.env, 200);
+    assert_eq!(s.client.claim_stream(&s.alice, &a), 490);
+    assert_eq!(s.client.claim_stream(&s.bob, &b), 210);
+    assert_eq!(s.client.get_balance(&0, &s.alice), 790);
+
+    let page = s
+        .client
+        .get_streams_by_recipient(&s.alice, &Some(StreamStatus::Split), &0, &10);
+    assert_eq!(page.streams.get(0).unwrap().id, id);
+}
+
+/// A split stream and an untouched twin are claimed side by side: at every
+/// point the children together owe no more than the twin and trail it only
+/// by rounding, and at the end they pay out exactly the same.
+#[test]
+fn test_children_sum_to_parent() {
+    let s = setup();
+    let carol = Address::generate(&s.env);
+
+    for curve in [
+        VestingCurve::Linear,
+        VestingCurve::Stepped(3),
+        VestingCurve::Exponential(2),
+        VestingCurve::Piecewise(vec![&s.env, (130, 6_000)]),
+    ] {
+        // Each rounding step of the curve can lose a unit per child
+        let slack = match curve {
+            VestingCurve::Exponential(exponent) => 3 * exponent as i128,
+            _ => 3,
+        };
+        set_time(&s.env, 100);
+        let split = stream(&s, 1_001, curve.clone());
+        let twin = stream(&s, 1_001, curve);
+
+        set_time(&s.env, 137);
+        let claimed = s.client.claim_stream(&s.alice, &split);
+        assert_eq!(s.client.claim_stream(&s.alice, &twin), claimed);
+
+        let parts = vec![
+            &s.env,
+            (s.alice.clone(), 3_333),
+            (s.bob.clone(), 3_333),
+            (carol.clone(), 3_334),
+        ];
+        let children = s.client.split_stream(&split, &parts);
+
+        let mut total = 0;
+        for child in children.iter() {
+            total += s.client.get_stream(&child).unwrap().total_amount;
+        }
+        assert_eq!(total, 1_001 - claimed);
+
+        for time in [137, 150, 163, 199, 200] {
+            set_time(&s.env, time);
+            let expected = s.client.get_stream_claimable(&twin);
+            let mut owed = 0;
+            for child in children.iter() {
+                owed += s.client.get_stream_claimable(&child);
+            }
+            assert!(owed <= expected && owed > expected - slack);
+            if time == 200 {
+                assert_eq!(owed, expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_split_validation() {
+    let s = setup();
+    let id = stream(&s, 1_000, VestingCurve::Linear);
+    let split = |parts: Vec<(Address, u32)>| s.client.try_split_stream(&id, &parts);
+
+    assert_eq!(
+        split(vec![&s.env, (s.bob.clone(), 10_000)]),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(
+        split(vec![
+            &s.env,
+            (s.alice.clone(), 5_000),
+            (s.bob.clone(), 4_999)
+        ]),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(
+        split(vec![&s.env, (s.alice.clone(), 10_000), (s.bob.clone(), 0)]),
+        Err(Ok(Error::InvalidParameters))
+    );
+
+    // A non-transferable stream can only be split between its recipient
+    s.client.set_stream_transferable(&s.creator, &id, &false);
+    assert_eq!(
+        split(vec![
+            &s.env,
+            (s.alice.clone(), 5_000),
+            (s.bob.clone(), 5_000)
+        ]),
+        Err(Ok(Error::InvalidParameters))
+    );
+
+    s.client.pause_stream(&s.creator, &id);
+    let halves = vec![&s.env, (s.alice.clone(), 5_000), (s.alice.clone(), 5_000)];
+    assert_eq!(
+        split(halves.clone()),
+        Err(Ok(Error::InvalidStateTransition))
+    );
+    s.client.unpause_stream(&s.creator, &id);
+    s.client.split_stream(&id, &halves);
+
+    // The closed parent cannot be split, cancelled or transferred again
+    assert_eq!(split(halves), Err(Ok(Error::InvalidParameters)));
+    assert_eq!(
+        s.client.try_cancel_stream(&s.creator, &id),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(
+        s.client.try_transfer_stream(&id, &s.alice, &s.bob),
+        Err(Ok(Error::InvalidParameters))
+    );
+
+    // Too little left to give every part something
+    let tiny = stream(&s, 1, VestingCurve::Linear);
+    assert_eq!(
+        s.client.try_split_stream(
+            &tiny,
+            &vec![&s.env, (s.alice.clone(), 5_000), (s.bob.clone(), 5_000)]
+        ),
+        Err(Ok(Error::InvalidAmount))
+    );
+    set_time(&s.env, 200);
+    s.client.claim_stream(&s.alice, &tiny);
+    assert_eq!(
+        s.client.try_split_stream(
+            &tiny,
+            &vec![&s.env, (s.alice.clone(), 5_000), (s.bob.clone(), 5_000)]
+        ),
+        Err(Ok(Error::NothingToClaim))
+    );
+}
@@ -0,0 +1,105 @@
+//! Stream Transfer Tests
+//!
+//! Tests for handing a stream's recipient rights to another address and
+//! the creator's per-stream transferable flag
+
+use soroban_sdk::{testutils::Events, Address, IntoVal, Symbol};
+
+use crate::storage;
+use crate::test_helpers::{create_stream, set_time, StreamFixture};
+use crate::types::Error;
+
+/// Token 0 owned by the creator and stream 0 of 1_000 to alice from
+/// t=100 to t=200
+fn setup() -> StreamFixture {
+    let s = StreamFixture::new("VST", 1_000_000);
+    set_time(&s.env, 100);
+    create_stream(&s.client, &s.creator, &s.alice, 1_000, 100, 100, 200);
+    s
+}
+
+fn recipient_streams(s: &StreamFixture, recipient: &Address) -> (u32, Option<u64>) {
+    s.env.as_contract(&s.contract_id, || {
+        (
+            storage::get_recipient_stream_count(&s.env, recipient),
+            storage::get_recipient_stream_at(&s.env, recipient, 0),
+        )
+    })
+}
+
+#[test]
+fn test_transfer_moves_unclaimed_rights() {
+    let s = setup();
+
+    set_time(&s.env, 130);
+    assert_eq!(s.client.claim_stream(&s.alice, &0), 300);
+
+    set_time(&s.env, 150);
+    s.client.transfer_stream(&0, &s.alice, &s.bob);
+
+    let (_, topics, data) = s.env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (Symbol::new(&s.env, "vlt_tf_v1"), 0u64).into_val(&s.env)
+    );
+    let payload: (Address, Address) = data.into_val(&s.env);
+    assert_eq!(payload, (s.alice.clone(), s.bob.clone()));
+
+    assert_eq!(recipient_streams(&s, &s.alice), (0, None));
+    assert_eq!(recipient_streams(&s, &s.bob), (1, Some(0)));
+
+    // Vested but unclaimed amounts follow the stream
+    assert_eq!(
+        s.client.try_claim_stream(&s.alice, &0),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(s.client.claim_stream(&s.bob, &0), 200);
+    assert_eq!(s.client.get_balance(&0, &s.alice), 300);
+    assert_eq!(s.client.get_balance(&0, &s.bob), 200);
+}
+
+#[test]
+fn test_creator_can_lock_transfers() {
+    let s = setup();
+
+    assert_eq!(
+        s.client.try_set_stream_transferable(&s.alice, &0, &false),
+        Err(Ok(Error::Unauthorized))
+    );
+    s.client.set_stream_transferable(&s.creator, &0, &false);
+
+    let (_, topics, data) = s.env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (Symbol::new(&s.env, "vlt_tb_v1"), 0u64).into_val(&s.env)
+    );
+    let payload: (Address, bool) = data.into_val(&s.env);
+    assert_eq!(payload, (s.creator.clone(), false));
+
+    assert_eq!(
+        s.client.try_transfer_stream(&0, &s.alice, &s.bob),
+        Err(Ok(Error::InvalidParameters))
+    );
+
+    s.client.set_stream_transferable(&s.creator, &0, &true);
+    s.client.transfer_stream(&0, &s.alice, &s.bob);
+    assert_eq!(s.client.get_stream(&0).unwrap().recipient, s.bob);
+}
+
+#[test]
+fn test_transfer_validation() {
+    let s = setup();
+
+    assert_eq!(
+        s.client.try_transfer_stream(&0, &s.bob, &s.creator),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.client.try_transfer_stream(&0, &s.alice, &s.alice),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(
+        s.client.try_transfer_stream(&9, &s.alice, &s.bob),
+        Err(Ok(Error::TokenNotFound))
+    );
+}
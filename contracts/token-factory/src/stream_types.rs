@@ -1,4 +1,4 @@
-use crate::types::{Error, PaginationCursor, StreamInfo};
+use crate::types::{Error, PaginationCursor, StreamInfo, StreamStatus};
 use soroban_sdk::{contracttype, Address, String, Vec};
 
 // Stream types are defined in types.rs
@@ -100,11 +100,68 @@ pub fn validate_financial_invariants(
     Ok(())
 }
 
+/// Lifecycle state of a stream
+///
+/// A cancelled stream stays `Cancelled` even once its settled amount has
+/// been claimed; `Completed` means a live stream was claimed in full.
+pub fn stream_status(stream: &StreamInfo) -> StreamStatus {
+    if !stream.children.is_empty() {
+        StreamStatus::Split
+    } else if stream.cancelled {
+        StreamStatus::Cancelled
+    } else if stream.claimed_amount >= stream.total_amount {
+        StreamStatus::Completed
+    } else if stream.paused {
+        StreamStatus::Paused
+    } else {
+        StreamStatus::Active
+    }
+}
+
+/// Point on the vesting schedule reached at `current_time`
+///
+/// Paused time does not count towards vesting: while a stream is paused the
+/// clock stands still at `paused_at`, and every completed pause shifts the
+/// rest of the schedule (cliff and end time) later by its length.
+pub fn vesting_clock(stream: &StreamInfo, current_time: u64) -> u64 {
+    let now = if stream.paused {
+        current_time.min(stream.paused_at)
+    } else {
+        current_time
+    };
+    now.saturating_sub(stream.total_paused_secs)
+}
+
+/// Amount vested at vesting clock time `clock`
+///
+/// Vests `total_amount - vested_base` along the stream's curve from
+/// `start_time` to `end_time` on top of `vested_base`, the amount carried
+/// over when a top-up re-based the schedule. Returns `None` on overflow.
+pub fn vested_amount(stream: &StreamInfo, clock: u64) -> Option<i128> {
+    if clock >= stream.end_time {
+        return Some(stream.total_amount);
+    }
+    if clock <= stream.start_time {
+        return Some(stream.vested_base);
+    }
+
+    let unvested = stream.total_amount.checked_sub(stream.vested_base)?;
+    crate::vesting::curve_vested_amount(
+        unvested,
+        stream.start_time,
+        stream.end_time,
+        clock,
+        &stream.curve,
+    )
+    .ok()?
+    .checked_add(stream.vested_base)
+}
+
 /// Calculate claimable amount for a stream at current time
 ///
 /// This is a pure calculation function that computes how much can be claimed
-/// based on the stream's vesting schedule. It uses linear vesting between
-/// start_time and end_time.
+/// based on the stream's vesting schedule, following the stream's curve
+/// between start_time and end_time.
 ///
 /// # Parameters
 /// - `stream`: The stream information
@@ -116,42 +173,29 @@ pub fn validate_financial_invariants(
 /// # Vesting Logic
 /// - Before start_time: 0 claimable
 /// - At start_time: 0 claimable (vesting starts after start_time)
-/// - Between start and end: Linear vesting proportional to elapsed time
+/// - Between start and end: Vesting along the stream's curve
 /// - At or after end_time: Full amount claimable
 ///
+/// `current_time` is converted with [`vesting_clock`], so paused time
+/// does not vest. A cancelled stream owes what was settled at cancellation
+/// and a split stream owes nothing; its children carry the remainder.
+///
 /// # Formula
 /// ```
-/// vested = (amount * elapsed_time) / total_duration
+/// vested = vested_base + curve(amount - vested_base, elapsed_time / total_duration)
 /// claimable = vested - claimed_amount
 /// ```
 pub fn calculate_claimable_amount(stream: &StreamInfo, current_time: u64) -> i128 {
-    // Before or at start time: nothing vested yet
-    if current_time <= stream.start_time {
+    if !stream.children.is_empty() {
         return 0;
     }
-
-    // After end time: everything is vested
-    if current_time >= stream.end_time {
-        let vested = stream.total_amount;
-        let claimable = vested.saturating_sub(stream.claimed_amount);
-        return claimable.max(0);
-    }
-
-    // During vesting period: linear vesting
-    let elapsed = current_time.saturating_sub(stream.start_time);
-    let duration = stream.end_time.saturating_sub(stream.start_time);
-
-    // Avoid division by zero
-    if duration == 0 {
-        return 0;
+    if stream.cancelled {
+        let owed = stream.total_amount.saturating_sub(stream.refunded_amount);
+        return owed.saturating_sub(stream.claimed_amount).max(0);
     }
 
-    // Calculate vested amount: (total_amount * elapsed) / duration
-    // Use checked arithmetic to prevent overflow
-    let vested = stream
-        .total_amount
-        .saturating_mul(elapsed as i128)
-        .saturating_div(duration as i128);
+    let current_time = vesting_clock(stream, current_time);
+    let vested = vested_amount(stream, current_time).unwrap_or(0);
 
     // Claimable = vested - already claimed
     let claimable = vested.saturating_sub(stream.claimed_amount);
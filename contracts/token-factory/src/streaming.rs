@@ -1,14 +1,26 @@
 use crate::events;
 use crate::storage;
-use crate::types::{Error, StreamInfo, StreamParams};
-use soroban_sdk::{testutils::Ledger, Address, Env, Vec};
+use crate::stream_types;
+use crate::types::{
+    Error, ExtDataKey, StreamAmendment, StreamInfo, StreamParams, VestingCurve,
+    PAUSE_STREAM_CLAIM, PAUSE_STREAM_CREATE,
+};
+use soroban_sdk::{Address, Env, Map, Vec};
 
 /// Maximum number of streams in a batch operation
 const MAX_BATCH_SIZE: u32 = 100;
 
+/// Maximum number of child streams a split can create
+const MAX_SPLIT_PARTS: u32 = 10;
+
+/// Basis points making up a whole stream in a split
+const SPLIT_TOTAL_BPS: u32 = 10_000;
+
 /// Create a single stream
 ///
 /// Creates a payment stream from creator to recipient with vesting schedule.
+/// The full amount is moved from the creator's balance into escrow and
+/// released to the recipient as it is claimed.
 ///
 /// # Arguments
 /// * `env` - The contract environment
@@ -22,17 +34,21 @@ const MAX_BATCH_SIZE: u32 = 100;
 /// * `Error::Unauthorized` - Caller is not the creator
 /// * `Error::InvalidParameters` - Invalid stream parameters
 /// * `Error::ContractPaused` - Contract is paused
+/// * `Error::TokenPaused` - Token is paused
+/// * `Error::InsufficientBalance` - Creator's transferable balance is too low
 pub fn create_stream(env: &Env, creator: &Address, params: &StreamParams) -> Result<u64, Error> {
     creator.require_auth();
 
-    // Check if contract is paused
-    if storage::is_paused(env) {
+    // Check if stream creation is paused
+    if storage::is_operation_paused(env, PAUSE_STREAM_CREATE) {
         return Err(Error::ContractPaused);
     }
 
     // Validate stream parameters
     validate_stream_params(env, params)?;
 
+    escrow_from(env, params.token_index, creator, params.total_amount)?;
+
     // Get next stream ID
     let stream_id = storage::get_next_stream_id(env);
 
@@ -50,10 +66,23 @@ pub fn create_stream(env: &Env, creator: &Address, params: &StreamParams) -> Res
         metadata: None,
         cancelled: false,
         paused: false,
+        paused_at: 0,
+        total_paused_secs: 0,
+        cancelled_at: 0,
+        settled_amount: 0,
+        refunded_amount: 0,
+        vested_base: 0,
+        curve: params.curve.clone(),
+        transferable: true,
+        parent_id: None,
+        children: Vec::new(env),
     };
 
     // Store stream
     storage::set_stream(env, stream_id, &stream);
+    storage::add_recipient_stream(env, &params.recipient, stream_id);
+    storage::add_creator_stream(env, creator, stream_id);
+    storage::add_token_stream(env, params.token_index, stream_id);
 
     // Emit event
     events::emit_stream_created(
@@ -62,6 +91,7 @@ pub fn create_stream(env: &Env, creator: &Address, params: &StreamParams) -> Res
         creator,
         &params.recipient,
         params.total_amount,
+        false,
     );
 
     Ok(stream_id)
@@ -120,6 +150,16 @@ mod deterministic_batch_event_tests {
             metadata: None,
             cancelled: false,
             paused: false,
+            paused_at: 0,
+            total_paused_secs: 0,
+            cancelled_at: 0,
+            settled_amount: 0,
+            refunded_amount: 0,
+            vested_base: 0,
+            curve: VestingCurve::Linear,
+            transferable: true,
+            parent_id: None,
+            children: Vec::new(&env),
         };
         let stream2 = StreamInfo { id: 2, ..stream1.clone() };
 
@@ -136,8 +176,8 @@ mod deterministic_batch_event_tests {
         assert_eq!(delta.len(), 2);
         let t0 = Symbol::try_from_val(&env, &delta.get(0).unwrap().1.get(0).unwrap()).unwrap();
         let t1 = Symbol::try_from_val(&env, &delta.get(1).unwrap().1.get(0).unwrap()).unwrap();
-        assert_eq!(t0, symbol_short!("strm_clm"));
-        assert_eq!(t1, symbol_short!("strm_clm"));
+        assert_eq!(t0, symbol_short!("vlt_cl_v1"));
+        assert_eq!(t1, symbol_short!("vlt_cl_v1"));
     }
 
     #[test]
@@ -159,6 +199,16 @@ mod deterministic_batch_event_tests {
             metadata: None,
             cancelled: false,
             paused: false,
+            paused_at: 0,
+            total_paused_secs: 0,
+            cancelled_at: 0,
+            settled_amount: 0,
+            refunded_amount: 0,
+            vested_base: 0,
+            curve: VestingCurve::Linear,
+            transferable: true,
+            parent_id: None,
+            children: Vec::new(&env),
         };
         let stream2 = StreamInfo {
             id: 12,
@@ -187,7 +237,7 @@ mod deterministic_batch_event_tests {
 ///
 /// # Event ordering contract (deterministic)
 /// Successful batch emits:
-/// 1. one `strm_crt` event per created stream in input order
+/// 1. one `vlt_cr_v1` event per created stream in input order
 /// 2. one trailing `bch_strm` summary event
 ///
 /// Failed batch emits none of these success events.
@@ -205,6 +255,8 @@ mod deterministic_batch_event_tests {
 /// * `Error::InvalidParameters` - Invalid parameters or batch too large
 /// * `Error::ContractPaused` - Contract is paused
 /// * `Error::BatchTooLarge` - Batch exceeds maximum size
+/// * `Error::TokenPaused` - A streamed token is paused
+/// * `Error::InsufficientBalance` - Creator cannot fund every stream
 ///
 /// # Examples
 /// ```
@@ -222,8 +274,8 @@ pub fn batch_create_streams(
 ) -> Result<Vec<u64>, Error> {
     creator.require_auth();
 
-    // Check if contract is paused
-    if storage::is_paused(env) {
+    // Check if stream creation is paused
+    if storage::is_operation_paused(env, PAUSE_STREAM_CREATE) {
         return Err(Error::ContractPaused);
     }
 
@@ -237,14 +289,36 @@ pub fn batch_create_streams(
     }
 
     // Phase 1: Validate all streams before creating any
+    let mut required: Map<u32, i128> = Map::new(env);
     for stream_params in streams.iter() {
         validate_stream_params(env, &stream_params)?;
+
+        let total = required
+            .get(stream_params.token_index)
+            .unwrap_or(0)
+            .checked_add(stream_params.total_amount)
+            .ok_or(Error::ArithmeticError)?;
+        required.set(stream_params.token_index, total);
+    }
+    for (token_index, total) in required.iter() {
+        if storage::is_token_paused(env, token_index) {
+            return Err(Error::TokenPaused);
+        }
+        if storage::get_transferable_balance(env, token_index, creator) < total {
+            return Err(Error::InsufficientBalance);
+        }
     }
 
     // Phase 2: Create all streams (validation passed)
     let mut stream_ids = Vec::new(env);
 
     for stream_params in streams.iter() {
+        escrow_from(
+            env,
+            stream_params.token_index,
+            creator,
+            stream_params.total_amount,
+        )?;
         let stream_id = storage::get_next_stream_id(env);
 
         let stream = StreamInfo {
@@ -260,9 +334,30 @@ pub fn batch_create_streams(
             metadata: None,
             cancelled: false,
             paused: false,
+            paused_at: 0,
+            total_paused_secs: 0,
+            cancelled_at: 0,
+            settled_amount: 0,
+            refunded_amount: 0,
+            vested_base: 0,
+            curve: stream_params.curve.clone(),
+            transferable: true,
+            parent_id: None,
+            children: Vec::new(env),
         };
 
         storage::set_stream(env, stream_id, &stream);
+        storage::add_recipient_stream(env, &stream_params.recipient, stream_id);
+        storage::add_creator_stream(env, creator, stream_id);
+        storage::add_token_stream(env, stream_params.token_index, stream_id);
+        events::emit_stream_created(
+            env,
+            stream_id,
+            creator,
+            &stream_params.recipient,
+            stream_params.total_amount,
+            false,
+        );
         stream_ids.push_back(stream_id);
     }
 
@@ -310,6 +405,9 @@ fn validate_stream_params(env: &Env, params: &StreamParams) -> Result<(), Error>
         return Err(Error::InvalidSchedule);
     }
 
+    crate::vesting::validate_curve(&params.curve, params.start_time, params.end_time)
+        .map_err(|_| Error::InvalidSchedule)?;
+
     // Validate token exists
     if storage::get_token_info(env, params.token_index).is_none() {
         return Err(Error::TokenNotFound);
@@ -318,6 +416,44 @@ fn validate_stream_params(env: &Env, params: &StreamParams) -> Result<(), Error>
     Ok(())
 }
 
+/// Move `amount` from `holder`'s transferable balance into stream escrow
+///
+/// # Errors
+/// * `Error::TokenPaused` - Token is paused
+/// * `Error::InsufficientBalance` - Transferable balance is less than amount
+pub(crate) fn escrow_from(
+    env: &Env,
+    token_index: u32,
+    holder: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    if storage::is_token_paused(env, token_index) {
+        return Err(Error::TokenPaused);
+    }
+
+    if storage::get_transferable_balance(env, token_index, holder) < amount {
+        return Err(Error::InsufficientBalance);
+    }
+
+    let balance = storage::get_balance(env, token_index, holder);
+    storage::set_balance(env, token_index, holder, balance - amount);
+    storage::adjust_escrowed_supply(env, token_index, amount)
+}
+
+/// Release `amount` from stream escrow to `to`'s balance
+pub(crate) fn release_to(
+    env: &Env,
+    token_index: u32,
+    to: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    let balance = storage::get_balance(env, token_index, to)
+        .checked_add(amount)
+        .ok_or(Error::ArithmeticError)?;
+    storage::set_balance(env, token_index, to, balance);
+    storage::adjust_escrowed_supply(env, token_index, -amount)
+}
+
 /// Claim vested tokens from a stream
 ///
 /// Allows recipient to claim tokens that have vested according to schedule.
@@ -326,6 +462,15 @@ fn validate_stream_params(env: &Env, params: &StreamParams) -> Result<(), Error>
 /// Claims before cliff_time are rejected with CliffNotReached error.
 /// This check occurs after authorization but before cancellation checks,
 /// ensuring the cliff is enforced universally regardless of stream state.
+/// The cliff is measured on the vesting clock, so pauses delay it.
+///
+/// # Paused Streams
+/// A paused stream can still be claimed up to what vested before the
+/// pause; nothing accrues until it is unpaused.
+///
+/// # Cancelled Streams
+/// A cancelled stream stays claimable up to the amount settled to the
+/// recipient at cancellation.
 ///
 /// # Arguments
 /// * `env` - The contract environment
@@ -339,34 +484,85 @@ fn validate_stream_params(env: &Env, params: &StreamParams) -> Result<(), Error>
 /// * `Error::StreamNotFound` - Stream not found
 /// * `Error::Unauthorized` - Caller is not the recipient
 /// * `Error::CliffNotReached` - Current time before cliff_time
-/// * `Error::StreamCancelled` - Stream cancelled
+/// * `Error::TokenPaused` - Token is paused
 /// * `Error::NothingToClaim` - No claimable amount
 pub fn claim_stream(env: &Env, recipient: &Address, stream_id: u64) -> Result<i128, Error> {
     recipient.require_auth();
 
+    if storage::is_operation_paused(env, PAUSE_STREAM_CLAIM) {
+        return Err(Error::ContractPaused);
+    }
+
     // Get stream
-    let mut stream = storage::get_stream(env, stream_id).ok_or(Error::StreamNotFound)?;
+    let stream = storage::get_stream(env, stream_id).ok_or(Error::StreamNotFound)?;
 
     // Verify recipient
     if stream.recipient != *recipient {
         return Err(Error::Unauthorized);
     }
 
+    pay_claim(env, stream_id, stream)
+}
+
+/// Claim vested tokens for a stream's recipient on their behalf
+///
+/// Any keeper may call this once the recipient opted into auto-claim, or
+/// the recipient's operator otherwise. The claim is paid to the recipient
+/// exactly as if they had claimed themselves.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `keeper` - Address pushing the claim (must authorize)
+/// * `stream_id` - ID of the stream
+///
+/// # Returns
+/// Returns the amount paid to the recipient
+///
+/// # Errors
+/// * `Error::ContractPaused` - Stream claims are paused
+/// * `Error::StreamNotFound` - Stream not found
+/// * `Error::Unauthorized` - Recipient has not delegated claims to `keeper`
+/// * `Error::InvalidTimeWindow` - Recipient's minimum claim interval not elapsed
+/// * `Error::CliffNotReached` - Current time before cliff_time
+/// * `Error::TokenPaused` - Token is paused
+/// * `Error::NothingToClaim` - No claimable amount
+pub fn push_claim(env: &Env, keeper: &Address, stream_id: u64) -> Result<i128, Error> {
+    keeper.require_auth();
+
+    if storage::is_operation_paused(env, PAUSE_STREAM_CLAIM) {
+        return Err(Error::ContractPaused);
+    }
+
+    let stream = storage::get_stream(env, stream_id).ok_or(Error::StreamNotFound)?;
+
+    crate::keeper::authorize_push(
+        env,
+        keeper,
+        &stream.recipient,
+        &ExtDataKey::StreamLastPushedClaim(stream_id),
+    )?;
+
+    let claimable = pay_claim(env, stream_id, stream)?;
+    events::emit_claim_pushed(env, stream_id, false, keeper, claimable);
+
+    Ok(claimable)
+}
+
+/// Pay everything claimable on a stream to its recipient
+fn pay_claim(env: &Env, stream_id: u64, mut stream: StreamInfo) -> Result<i128, Error> {
+    let recipient = stream.recipient.clone();
+    crate::compliance::require_eligible(env, stream.token_index, &recipient)?;
+
     // Enforce cliff: no claims before cliff_time
     // This check occurs before cancellation check to ensure temporal constraints
     // are enforced universally, regardless of stream operational state
-    let current_time = env.ledger().timestamp();
-    if current_time < stream.cliff_time {
+    let clock = stream_types::vesting_clock(&stream, env.ledger().timestamp());
+    if clock < stream.cliff_time {
         return Err(Error::CliffNotReached);
     }
 
-    // Check if cancelled
-    if stream.cancelled {
-        return Err(Error::StreamCancelled);
-    }
-
-    if stream.paused {
-        return Err(Error::StreamPaused);
+    if storage::is_token_paused(env, stream.token_index) {
+        return Err(Error::TokenPaused);
     }
 
     // Calculate claimable amount
@@ -383,9 +579,10 @@ pub fn claim_stream(env: &Env, recipient: &Address, stream_id: u64) -> Result<i1
         .ok_or(Error::ArithmeticError)?;
 
     storage::set_stream(env, stream_id, &stream);
+    release_to(env, stream.token_index, &recipient, claimable)?;
 
     // Emit event
-    events::emit_stream_claimed(env, stream_id, recipient, claimable);
+    events::emit_stream_claimed(env, stream_id as u32, &recipient, claimable);
 
     Ok(claimable)
 }
@@ -397,9 +594,9 @@ pub fn claim_stream(env: &Env, recipient: &Address, stream_id: u64) -> Result<i1
 /// claimed (e.g. before cliff or zero remaining) are skipped without error.
 ///
 /// # Event ordering contract (deterministic)
-/// Successful batch emits `strm_clm` events in the same order as `stream_ids`
+/// Successful batch emits `vlt_cl_v1` events in the same order as `stream_ids`
 /// for claimable streams only (non-claimable streams are skipped without event).
-/// If validation fails in phase 1, no `strm_clm` events are emitted.
+/// If validation fails in phase 1, no `vlt_cl_v1` events are emitted.
 ///
 /// # Arguments
 /// * `env` - The contract environment
@@ -412,7 +609,7 @@ pub fn claim_stream(env: &Env, recipient: &Address, stream_id: u64) -> Result<i1
 /// # Errors
 /// * `Error::Unauthorized` - Caller is not the recipient for one of the streams
 /// * `Error::TokenNotFound` - Stream not found
-/// * `Error::InvalidParameters` - Stream cancelled
+/// * `Error::TokenPaused` - A streamed token is paused
 pub fn batch_claim(
     env: &Env,
     recipient: &Address,
@@ -420,6 +617,10 @@ pub fn batch_claim(
 ) -> Result<Vec<i128>, Error> {
     recipient.require_auth();
 
+    if storage::is_operation_paused(env, PAUSE_STREAM_CLAIM) {
+        return Err(Error::ContractPaused);
+    }
+
     // First pass: validate all streams
     for stream_id in stream_ids.iter() {
         let stream = storage::get_stream(env, stream_id).ok_or(Error::TokenNotFound)?;
@@ -429,9 +630,10 @@ pub fn batch_claim(
             return Err(Error::Unauthorized);
         }
 
-        // Check if cancelled
-        if stream.cancelled {
-            return Err(Error::InvalidParameters);
+        crate::compliance::require_eligible(env, stream.token_index, recipient)?;
+
+        if storage::is_token_paused(env, stream.token_index) {
+            return Err(Error::TokenPaused);
         }
     }
 
@@ -452,9 +654,10 @@ pub fn batch_claim(
                 .ok_or(Error::ArithmeticError)?;
 
             storage::set_stream(env, stream_id, &stream);
+            release_to(env, stream.token_index, recipient, claimable)?;
 
             // Emit event
-            events::emit_stream_claimed(env, stream_id, recipient, claimable);
+            events::emit_stream_claimed(env, stream_id as u32, recipient, claimable);
         }
 
         claimed_amounts.push_back(claimable);
@@ -475,6 +678,9 @@ pub fn batch_claim(
 /// Example: start=100, cliff=150, end=200, current=150
 ///   → elapsed=50, duration=100 → 50% vested at cliff unlock
 ///
+/// Time is read from the vesting clock (`stream_types::vesting_clock`),
+/// which stands still while the stream is paused.
+///
 /// # Arguments
 /// * `env` - The contract environment
 /// * `stream` - The stream to calculate for
@@ -482,7 +688,22 @@ pub fn batch_claim(
 /// # Returns
 /// Returns the claimable amount (0 if before cliff or start)
 fn calculate_claimable(env: &Env, stream: &StreamInfo) -> Result<i128, Error> {
-    let current_time = env.ledger().timestamp();
+    // Split: everything left moved to the child streams
+    if !stream.children.is_empty() {
+        return Ok(0);
+    }
+
+    // Settled at cancellation: only the unrefunded remainder is owed
+    if stream.cancelled {
+        return stream
+            .total_amount
+            .checked_sub(stream.refunded_amount)
+            .and_then(|owed| owed.checked_sub(stream.claimed_amount))
+            .map(|claimable| claimable.max(0))
+            .ok_or(Error::ArithmeticError);
+    }
+
+    let current_time = stream_types::vesting_clock(stream, env.ledger().timestamp());
 
     // Before cliff: nothing claimable (cliff acts as release gate)
     if current_time < stream.cliff_time {
@@ -497,25 +718,8 @@ fn calculate_claimable(env: &Env, stream: &StreamInfo) -> Result<i128, Error> {
     // Calculate vested amount
     // Note: Vesting starts at start_time, not cliff_time
     // The cliff is a release gate - tokens vest continuously but are locked until cliff_time
-    let vested = if current_time >= stream.end_time {
-        // After end: everything is vested
-        stream.total_amount
-    } else {
-        // During vesting: linear vesting from start_time
-        let elapsed = current_time - stream.start_time;
-        let duration = stream.end_time - stream.start_time;
-
-        // Handle zero-duration edge case
-        if duration == 0 {
-            stream.total_amount
-        } else {
-            stream
-                .total_amount
-                .checked_mul(elapsed as i128)
-                .and_then(|v| v.checked_div(duration as i128))
-                .ok_or(Error::ArithmeticError)?
-        }
-    };
+    let vested =
+        stream_types::vested_amount(stream, current_time).ok_or(Error::ArithmeticError)?;
 
     // Claimable = vested - already claimed
     let claimable = vested
@@ -527,13 +731,24 @@ fn calculate_claimable(env: &Env, stream: &StreamInfo) -> Result<i128, Error> {
 
 /// Cancel a stream
 ///
-/// Allows creator to cancel a stream. Recipient can claim vested amount.
+/// Settles the stream at the cancel timestamp: the amount vested but not
+/// yet claimed stays claimable by the recipient, and the unvested
+/// remainder is returned to the creator's balance. Before the cliff
+/// nothing has vested, so everything unclaimed is refunded.
 ///
 /// # Arguments
 /// * `env` - The contract environment
 /// * `creator` - Address cancelling the stream (must authorize)
 /// * `stream_id` - ID of the stream to cancel
-pub fn cancel_stream(env: &Env, creator: &Address, stream_id: u64) -> Result<(), Error> {
+///
+/// # Returns
+/// Returns the amount refunded to the creator
+///
+/// # Errors
+/// * `Error::TokenNotFound` - Stream not found
+/// * `Error::Unauthorized` - Caller is not the creator
+/// * `Error::InvalidParameters` - Stream already cancelled
+pub fn cancel_stream(env: &Env, creator: &Address, stream_id: u64) -> Result<i128, Error> {
     creator.require_auth();
 
     // Get stream
@@ -549,19 +764,45 @@ pub fn cancel_stream(env: &Env, creator: &Address, stream_id: u64) -> Result<(),
         return Err(Error::InvalidParameters);
     }
 
-    // Mark as cancelled
+    let settled = calculate_claimable(env, &stream)?;
+    let refunded = stream
+        .total_amount
+        .checked_sub(stream.claimed_amount)
+        .and_then(|unclaimed| unclaimed.checked_sub(settled))
+        .ok_or(Error::ArithmeticError)?;
+
     stream.cancelled = true;
+    stream.cancelled_at = env.ledger().timestamp();
+    stream.settled_amount = settled;
+    stream.refunded_amount = refunded;
     storage::set_stream(env, stream_id, &stream);
 
-    // Emit event
-    events::emit_stream_cancelled(env, stream_id, creator);
+    if refunded > 0 {
+        release_to(env, stream.token_index, creator, refunded)?;
+    }
 
-    Ok(())
+    events::emit_stream_cancelled(
+        env,
+        stream_id as u32,
+        creator,
+        refunded,
+        settled,
+        stream.cancelled_at,
+    );
+
+    Ok(refunded)
 }
 
 /// Pause a stream
 ///
-/// Allows creator to temporarily suspend claims on a stream.
+/// Stops the stream's vesting clock. Amounts vested before the pause stay
+/// claimable; the time spent paused is added to the schedule on unpause.
+///
+/// # Errors
+/// * `Error::TokenNotFound` - Stream not found
+/// * `Error::Unauthorized` - Caller is not the creator
+/// * `Error::InvalidParameters` - Stream cancelled
+/// * `Error::InvalidStateTransition` - Stream already paused
 pub fn pause_stream(env: &Env, creator: &Address, stream_id: u64) -> Result<(), Error> {
     creator.require_auth();
 
@@ -575,15 +816,29 @@ pub fn pause_stream(env: &Env, creator: &Address, stream_id: u64) -> Result<(),
         return Err(Error::InvalidParameters);
     }
 
+    if stream.paused {
+        return Err(Error::InvalidStateTransition);
+    }
+
     stream.paused = true;
+    stream.paused_at = env.ledger().timestamp();
     storage::set_stream(env, stream_id, &stream);
 
+    events::emit_stream_paused(env, stream_id, creator);
+
     Ok(())
 }
 
 /// Unpause a stream
 ///
-/// Allows creator to resume a paused stream.
+/// Restarts the vesting clock. The remaining schedule, including the cliff
+/// and end time, is shifted by the length of the pause.
+///
+/// # Errors
+/// * `Error::TokenNotFound` - Stream not found
+/// * `Error::Unauthorized` - Caller is not the creator
+/// * `Error::InvalidParameters` - Stream cancelled
+/// * `Error::InvalidStateTransition` - Stream not paused
 pub fn unpause_stream(env: &Env, creator: &Address, stream_id: u64) -> Result<(), Error> {
     creator.require_auth();
 
@@ -597,9 +852,416 @@ pub fn unpause_stream(env: &Env, creator: &Address, stream_id: u64) -> Result<()
         return Err(Error::InvalidParameters);
     }
 
+    if !stream.paused {
+        return Err(Error::InvalidStateTransition);
+    }
+
+    let paused_secs = env.ledger().timestamp().saturating_sub(stream.paused_at);
+    stream.total_paused_secs = stream
+        .total_paused_secs
+        .checked_add(paused_secs)
+        .ok_or(Error::ArithmeticError)?;
     stream.paused = false;
+    stream.paused_at = 0;
+    storage::set_stream(env, stream_id, &stream);
+
+    events::emit_stream_resumed(env, stream_id, creator, paused_secs);
+
+    Ok(())
+}
+
+/// Allow or forbid transferring a stream's recipient rights (creator only)
+///
+/// Streams are transferable when created.
+///
+/// # Errors
+/// * `Error::TokenNotFound` - Stream not found
+/// * `Error::Unauthorized` - Caller is not the creator
+pub fn set_transferable(
+    env: &Env,
+    creator: &Address,
+    stream_id: u64,
+    transferable: bool,
+) -> Result<(), Error> {
+    creator.require_auth();
+
+    let mut stream = storage::get_stream(env, stream_id).ok_or(Error::TokenNotFound)?;
+
+    if stream.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+
+    stream.transferable = transferable;
+    storage::set_stream(env, stream_id, &stream);
+
+    events::emit_stream_transferable_set(env, stream_id, creator, transferable);
+
+    Ok(())
+}
+
+/// Hand a stream's recipient rights to another address
+///
+/// Everything not yet claimed, including amounts already vested, moves to
+/// `to`; amounts claimed before the transfer stay with `from`.
+///
+/// # Errors
+/// * `Error::ContractPaused` - Stream claims are paused
+/// * `Error::TokenNotFound` - Stream not found
+/// * `Error::Unauthorized` - Caller is not the current recipient
+/// * `Error::InvalidParameters` - Stream is not transferable or was split, or `to` is `from`
+/// * `Error::TokenPaused` - Token is paused
+/// * Compliance errors if `to` may not hold the token
+pub fn transfer_stream(
+    env: &Env,
+    stream_id: u64,
+    from: &Address,
+    to: &Address,
+) -> Result<(), Error> {
+    from.require_auth();
+
+    if storage::is_operation_paused(env, PAUSE_STREAM_CLAIM) {
+        return Err(Error::ContractPaused);
+    }
+
+    let mut stream = storage::get_stream(env, stream_id).ok_or(Error::TokenNotFound)?;
+
+    if stream.recipient != *from {
+        return Err(Error::Unauthorized);
+    }
+
+    if !stream.transferable || from == to || !stream.children.is_empty() {
+        return Err(Error::InvalidParameters);
+    }
+
+    if storage::is_token_paused(env, stream.token_index) {
+        return Err(Error::TokenPaused);
+    }
+
+    crate::compliance::require_eligible(env, stream.token_index, to)?;
+
+    stream.recipient = to.clone();
+    storage::set_stream(env, stream_id, &stream);
+    storage::remove_recipient_stream(env, from, stream_id);
+    storage::add_recipient_stream(env, to, stream_id);
+
+    events::emit_stream_transferred(env, stream_id, from, to);
+
+    Ok(())
+}
+
+/// Divide a stream between several recipients
+///
+/// Closes the parent and creates one child stream per part, each with the
+/// parent's creator, token, schedule, cliff and curve. Amounts claimed
+/// before the split stay on the parent; what is left is apportioned by
+/// basis points, with rounding remainders going to the last part:
+///
+/// ```text
+/// remaining = total - claimed          (child totals sum to this)
+/// unvested  = total - vested_base      (child unvested amounts sum to this)
+/// ```
+///
+/// A child starts with nothing claimed and a `vested_base` of its share of
+/// the remaining amount less its share of the unvested amount, which is
+/// negative when more was claimed than the base. Each child then vests
+/// `share * (parent vested - claimed)`, rounded down, so the children
+/// together never release more than the parent would have; mid-schedule
+/// they can trail it by a unit per child for each rounding step of the
+/// curve, and they match it at the end.
+///
+/// Parts may repeat an address, including the current recipient. Parts
+/// going to other addresses need the stream to be transferable.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `stream_id` - ID of the stream to split (recipient must authorize)
+/// * `parts` - `(recipient, bps)` pairs summing to 10_000 bps
+///
+/// # Returns
+/// IDs of the child streams, in part order
+///
+/// # Errors
+/// * `Error::ContractPaused` - Stream creation is paused
+/// * `Error::TokenNotFound` - Stream not found
+/// * `Error::InvalidParameters` - Stream cancelled, split or not transferable,
+///   fewer than 2 or more than 10 parts, or bps not positive or not summing to 10_000
+/// * `Error::InvalidStateTransition` - Stream is paused
+/// * `Error::NothingToClaim` - Everything was already claimed
+/// * `Error::InvalidAmount` - A part would receive nothing
+/// * `Error::TokenPaused` - Token is paused
+/// * Compliance errors if a new recipient may not hold the token
+pub fn split_stream(
+    env: &Env,
+    stream_id: u64,
+    parts: &Vec<(Address, u32)>,
+) -> Result<Vec<u64>, Error> {
+    let mut parent = storage::get_stream(env, stream_id).ok_or(Error::TokenNotFound)?;
+    parent.recipient.require_auth();
+
+    if storage::is_operation_paused(env, PAUSE_STREAM_CREATE) {
+        return Err(Error::ContractPaused);
+    }
+
+    if parent.cancelled {
+        return Err(Error::InvalidParameters);
+    }
+    if parent.paused {
+        return Err(Error::InvalidStateTransition);
+    }
+
+    if parts.len() < 2 || parts.len() > MAX_SPLIT_PARTS {
+        return Err(Error::InvalidParameters);
+    }
+    let mut total_bps = 0_u32;
+    for (recipient, bps) in parts.iter() {
+        if bps == 0 || (recipient != parent.recipient && !parent.transferable) {
+            return Err(Error::InvalidParameters);
+        }
+        total_bps = total_bps.saturating_add(bps);
+    }
+    if total_bps != SPLIT_TOTAL_BPS {
+        return Err(Error::InvalidParameters);
+    }
+
+    if storage::is_token_paused(env, parent.token_index) {
+        return Err(Error::TokenPaused);
+    }
+
+    let remaining = parent
+        .total_amount
+        .checked_sub(parent.claimed_amount)
+        .ok_or(Error::ArithmeticError)?;
+    if remaining <= 0 {
+        return Err(Error::NothingToClaim);
+    }
+    let unvested = parent
+        .total_amount
+        .checked_sub(parent.vested_base)
+        .ok_or(Error::ArithmeticError)?;
+
+    let remaining_shares = apportion(env, remaining, parts)?;
+    let unvested_shares = apportion(env, unvested, parts)?;
+
+    let mut children = Vec::new(env);
+    for (i, (recipient, _)) in parts.iter().enumerate() {
+        let i = i as u32;
+        let child_total = remaining_shares.get(i).unwrap();
+        if child_total == 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if recipient != parent.recipient {
+            crate::compliance::require_eligible(env, parent.token_index, &recipient)?;
+        }
+
+        let child_id = storage::get_next_stream_id(env);
+        let child = StreamInfo {
+            id: child_id,
+            creator: parent.creator.clone(),
+            recipient: recipient.clone(),
+            token_index: parent.token_index,
+            total_amount: child_total,
+            claimed_amount: 0,
+            start_time: parent.start_time,
+            end_time: parent.end_time,
+            cliff_time: parent.cliff_time,
+            metadata: parent.metadata.clone(),
+            cancelled: false,
+            paused: false,
+            paused_at: 0,
+            total_paused_secs: parent.total_paused_secs,
+            cancelled_at: 0,
+            settled_amount: 0,
+            refunded_amount: 0,
+            vested_base: child_total - unvested_shares.get(i).unwrap(),
+            curve: parent.curve.clone(),
+            transferable: parent.transferable,
+            parent_id: Some(stream_id),
+            children: Vec::new(env),
+        };
+
+        storage::set_stream(env, child_id, &child);
+        storage::add_recipient_stream(env, &recipient, child_id);
+        storage::add_creator_stream(env, &parent.creator, child_id);
+        storage::add_token_stream(env, parent.token_index, child_id);
+        events::emit_stream_created(
+            env,
+            child_id,
+            &parent.creator,
+            &recipient,
+            child_total,
+            child.metadata.is_some(),
+        );
+        children.push_back(child_id);
+    }
+
+    parent.cancelled = true;
+    parent.cancelled_at = env.ledger().timestamp();
+    parent.children = children.clone();
+    storage::set_stream(env, stream_id, &parent);
+
+    events::emit_stream_split(env, stream_id, &parent.recipient, &children, remaining);
+
+    Ok(children)
+}
+
+/// Divide `amount` by the parts' basis points, rounding down, with the
+/// remainder added to the last part so the shares sum to `amount`
+fn apportion(env: &Env, amount: i128, parts: &Vec<(Address, u32)>) -> Result<Vec<i128>, Error> {
+    let mut shares = Vec::new(env);
+    let mut allocated = 0_i128;
+    for (i, (_, bps)) in parts.iter().enumerate() {
+        let share = if i as u32 == parts.len() - 1 {
+            amount - allocated
+        } else {
+            amount
+                .checked_mul(bps as i128)
+                .ok_or(Error::ArithmeticError)?
+                / SPLIT_TOTAL_BPS as i128
+        };
+        allocated += share;
+        shares.push_back(share);
+    }
+    Ok(shares)
+}
+
+/// Top up and/or extend a stream
+///
+/// Escrows `additional_amount` from the creator and re-bases the schedule
+/// at the current vesting clock: the amount vested so far is carried over
+/// unchanged, and the rest of the new total vests linearly until
+/// `new_end_time`. The cliff is kept.
+///
+/// A change that would leave less vested than the old schedule at any
+/// future time (a lower vesting rate) also needs the recipient's
+/// authorization. Every amendment is appended to the stream's history.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `creator` - Stream creator (must authorize)
+/// * `stream_id` - ID of the stream
+/// * `additional_amount` - Amount to add (may be 0 to only move the end)
+/// * `new_end_time` - Ledger time the re-based schedule ends at
+///
+/// # Errors
+/// * `Error::ContractPaused` - Stream creation is paused
+/// * `Error::TokenNotFound` - Stream not found
+/// * `Error::Unauthorized` - Caller is not the creator
+/// * `Error::InvalidParameters` - Stream cancelled or not linear, or nothing changes
+/// * `Error::InvalidStateTransition` - Stream is paused
+/// * `Error::InvalidAmount` - Negative amount
+/// * `Error::InvalidSchedule` - New end is not after now and the cliff
+/// * `Error::InsufficientBalance` - Creator cannot fund the top-up
+pub fn top_up_stream(
+    env: &Env,
+    creator: &Address,
+    stream_id: u64,
+    additional_amount: i128,
+    new_end_time: u64,
+) -> Result<(), Error> {
+    creator.require_auth();
+
+    if storage::is_operation_paused(env, PAUSE_STREAM_CREATE) {
+        return Err(Error::ContractPaused);
+    }
+
+    let mut stream = storage::get_stream(env, stream_id).ok_or(Error::TokenNotFound)?;
+
+    if stream.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+
+    if stream.cancelled {
+        return Err(Error::InvalidParameters);
+    }
+
+    if stream.paused {
+        return Err(Error::InvalidStateTransition);
+    }
+
+    // Re-basing is only defined for linear vesting
+    if stream.curve != VestingCurve::Linear {
+        return Err(Error::InvalidParameters);
+    }
+
+    if additional_amount < 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    // Schedule times are kept on the vesting clock, behind ledger time by
+    // the completed pauses
+    let end_time = new_end_time
+        .checked_sub(stream.total_paused_secs)
+        .ok_or(Error::InvalidSchedule)?;
+    let clock = stream_types::vesting_clock(&stream, env.ledger().timestamp());
+    let rebase_at = clock.max(stream.start_time);
+    if end_time <= rebase_at || end_time < stream.cliff_time {
+        return Err(Error::InvalidSchedule);
+    }
+
+    if additional_amount == 0 && end_time == stream.end_time {
+        return Err(Error::InvalidParameters);
+    }
+
+    let vested =
+        stream_types::vested_amount(&stream, rebase_at).ok_or(Error::ArithmeticError)?;
+    let new_total = stream
+        .total_amount
+        .checked_add(additional_amount)
+        .ok_or(Error::ArithmeticError)?;
+
+    // Vesting slows when the new rate (new_total - vested) / (end_time - rebase_at)
+    // is below the old one; compared cross-multiplied to stay exact
+    let slows_vesting = if stream.end_time <= rebase_at {
+        false
+    } else {
+        let new_rate_scaled = new_total
+            .checked_sub(vested)
+            .and_then(|rest| rest.checked_mul((stream.end_time - rebase_at) as i128))
+            .ok_or(Error::ArithmeticError)?;
+        let old_rate_scaled = stream
+            .total_amount
+            .checked_sub(vested)
+            .and_then(|rest| rest.checked_mul((end_time - rebase_at) as i128))
+            .ok_or(Error::ArithmeticError)?;
+        new_rate_scaled < old_rate_scaled
+    };
+    if slows_vesting {
+        stream.recipient.require_auth();
+    }
+
+    if additional_amount > 0 {
+        escrow_from(env, stream.token_index, creator, additional_amount)?;
+    }
+
+    let previous_end_time = stream
+        .end_time
+        .checked_add(stream.total_paused_secs)
+        .ok_or(Error::ArithmeticError)?;
+    stream.vested_base = vested;
+    stream.start_time = rebase_at;
+    stream.end_time = end_time;
+    stream.total_amount = new_total;
     storage::set_stream(env, stream_id, &stream);
 
+    let amendment = StreamAmendment {
+        additional_amount,
+        previous_end_time,
+        new_end_time,
+        vested_amount: vested,
+        recipient_consented: slows_vesting,
+        timestamp: env.ledger().timestamp(),
+    };
+    storage::append_stream_amendment(env, stream_id, &amendment)?;
+
+    events::emit_stream_topped_up(
+        env,
+        stream_id,
+        creator,
+        additional_amount,
+        new_end_time,
+        vested,
+    );
+
     Ok(())
 }
 
@@ -704,6 +1366,16 @@ mod tests {
             metadata: None,
             cancelled: false,
             paused: false,
+            paused_at: 0,
+            total_paused_secs: 0,
+            cancelled_at: 0,
+            settled_amount: 0,
+            refunded_amount: 0,
+            vested_base: 0,
+            curve: VestingCurve::Linear,
+            transferable: true,
+            parent_id: None,
+            children: Vec::new(&env),
         };
         set_stream(&env, &contract_id, 0, &stream);
         // Set time just before cliff
@@ -728,6 +1400,16 @@ mod tests {
             metadata: None,
             cancelled: false,
             paused: false,
+            paused_at: 0,
+            total_paused_secs: 0,
+            cancelled_at: 0,
+            settled_amount: 0,
+            refunded_amount: 0,
+            vested_base: 0,
+            curve: VestingCurve::Linear,
+            transferable: true,
+            parent_id: None,
+            children: Vec::new(&env),
         };
         set_stream(&env, &contract_id, 0, &stream);
         // Set time at cliff
@@ -747,6 +1429,7 @@ mod tests {
             start_time: 100,
             end_time: 200,
             cliff_time: 150,
+            curve: VestingCurve::Linear,
         };
 
         // This will fail because token doesn't exist, but tests validation logic
@@ -765,6 +1448,7 @@ mod tests {
             start_time: 100,
             end_time: 200,
             cliff_time: 150,
+            curve: VestingCurve::Linear,
         };
 
         let result = validate_params(&env, &contract_id, &params);
@@ -782,6 +1466,7 @@ mod tests {
             start_time: 200,
             end_time: 100, // End before start
             cliff_time: 150,
+            curve: VestingCurve::Linear,
         };
 
         let result = validate_params(&env, &contract_id, &params);
@@ -805,6 +1490,16 @@ mod tests {
             metadata: None,
             cancelled: false,
             paused: false,
+            paused_at: 0,
+            total_paused_secs: 0,
+            cancelled_at: 0,
+            settled_amount: 0,
+            refunded_amount: 0,
+            vested_base: 0,
+            curve: VestingCurve::Linear,
+            transferable: true,
+            parent_id: None,
+            children: Vec::new(&env),
         };
 
         // Set time before cliff
@@ -832,6 +1527,16 @@ mod tests {
             cliff_time: 150,
             cancelled: false,
             paused: false,
+            paused_at: 0,
+            total_paused_secs: 0,
+            cancelled_at: 0,
+            settled_amount: 0,
+            refunded_amount: 0,
+            vested_base: 0,
+            curve: VestingCurve::Linear,
+            transferable: true,
+            parent_id: None,
+            children: Vec::new(&env),
             metadata: None,
         };
 
@@ -860,6 +1565,16 @@ mod tests {
             cliff_time: 150,
             cancelled: false,
             paused: false,
+            paused_at: 0,
+            total_paused_secs: 0,
+            cancelled_at: 0,
+            settled_amount: 0,
+            refunded_amount: 0,
+            vested_base: 0,
+            curve: VestingCurve::Linear,
+            transferable: true,
+            parent_id: None,
+            children: Vec::new(&env),
             metadata: None,
         };
 
@@ -889,6 +1604,16 @@ mod tests {
             metadata: None,
             cancelled: false,
             paused: false,
+            paused_at: 0,
+            total_paused_secs: 0,
+            cancelled_at: 0,
+            settled_amount: 0,
+            refunded_amount: 0,
+            vested_base: 0,
+            curve: VestingCurve::Linear,
+            transferable: true,
+            parent_id: None,
+            children: Vec::new(&env),
         };
 
         // Mock save stream to storage
@@ -901,10 +1626,18 @@ mod tests {
 
         // 1. Pause the stream
         assert!(pause(&env, &contract_id, &creator, 1).is_ok());
-
-        // 2. Verify claims are blocked
-        let claim_res = claim(&env, &contract_id, &recipient, 1);
-        assert_eq!(claim_res, Err(Error::StreamPaused));
+        assert_eq!(
+            pause(&env, &contract_id, &creator, 1),
+            Err(Error::InvalidStateTransition)
+        );
+
+        // 2. Amounts vested before the pause stay claimable, nothing accrues
+        env.ledger().with_mut(|li| li.timestamp = 190);
+        assert_eq!(claim(&env, &contract_id, &recipient, 1), Ok(600));
+        assert_eq!(
+            claim(&env, &contract_id, &recipient, 1),
+            Err(Error::NothingToClaim)
+        );
 
         // 3. Verify Authorization (recipient cannot unpause)
         let unpause_res = unpause(&env, &contract_id, &recipient, 1);
@@ -912,11 +1645,15 @@ mod tests {
 
         // 4. Unpause the stream as creator
         assert!(unpause(&env, &contract_id, &creator, 1).is_ok());
+        let resumed = get_stream(&env, &contract_id, 1);
+        assert!(!resumed.paused);
+        assert_eq!(resumed.total_paused_secs, 30);
 
-        // 5. Verify claims resume normally
-        let claim_success = claim(&env, &contract_id, &recipient, 1);
-        assert!(claim_success.is_ok());
-        assert!(claim_success.unwrap() > 0);
+        // 5. Vesting resumes where it stopped; the end moves from 200 to 230
+        env.ledger().with_mut(|li| li.timestamp = 200);
+        assert_eq!(claim(&env, &contract_id, &recipient, 1), Ok(100));
+        env.ledger().with_mut(|li| li.timestamp = 230);
+        assert_eq!(claim(&env, &contract_id, &recipient, 1), Ok(300));
     }
 
     // ========================================================================
@@ -940,6 +1677,16 @@ mod tests {
             metadata: None,
             cancelled: false,
             paused: false,
+            paused_at: 0,
+            total_paused_secs: 0,
+            cancelled_at: 0,
+            settled_amount: 0,
+            refunded_amount: 0,
+            vested_base: 0,
+            curve: VestingCurve::Linear,
+            transferable: true,
+            parent_id: None,
+            children: Vec::new(&env),
         };
         set_stream(&env, &contract_id, 0, &stream);
 
@@ -951,7 +1698,7 @@ mod tests {
         assert_eq!(result, Err(Error::CliffNotReached));
 
         // Keep numeric mapping assertion in sync with current enum layout.
-        assert_eq!(Error::CliffNotReached as u32, 28);
+        assert_eq!(Error::CliffNotReached as u32, 20);
     }
 
     #[test]
@@ -971,6 +1718,16 @@ mod tests {
             metadata: None,
             cancelled: false,
             paused: false,
+            paused_at: 0,
+            total_paused_secs: 0,
+            cancelled_at: 0,
+            settled_amount: 0,
+            refunded_amount: 0,
+            vested_base: 0,
+            curve: VestingCurve::Linear,
+            transferable: true,
+            parent_id: None,
+            children: Vec::new(&env),
         };
         set_stream(&env, &contract_id, 0, &stream);
 
@@ -1000,6 +1757,16 @@ mod tests {
             metadata: None,
             cancelled: false,
             paused: false,
+            paused_at: 0,
+            total_paused_secs: 0,
+            cancelled_at: 0,
+            settled_amount: 0,
+            refunded_amount: 0,
+            vested_base: 0,
+            curve: VestingCurve::Linear,
+            transferable: true,
+            parent_id: None,
+            children: Vec::new(&env),
         };
         set_stream(&env, &contract_id, 0, &stream);
 
@@ -1025,6 +1792,7 @@ mod tests {
             start_time: 100,
             end_time: 200,
             cliff_time: 100, // Same as start_time
+            curve: VestingCurve::Linear,
         };
 
         // Validation should accept this configuration
@@ -1046,13 +1814,23 @@ mod tests {
             metadata: None,
             cancelled: false,
             paused: false,
+            paused_at: 0,
+            total_paused_secs: 0,
+            cancelled_at: 0,
+            settled_amount: 0,
+            refunded_amount: 0,
+            vested_base: 0,
+            curve: VestingCurve::Linear,
+            transferable: true,
+            parent_id: None,
+            children: Vec::new(&env),
         };
         set_stream(&env, &contract_id, 0, &stream);
 
-        // At exact start_time vested amount is 0, so claim returns InvalidAmount.
+        // At exact start_time vested amount is 0, so claim returns NothingToClaim.
         env.ledger().with_mut(|li| li.timestamp = 100);
         let result = claim(&env, &contract_id, &recipient, 0);
-        assert_eq!(result, Err(Error::InvalidAmount));
+        assert_eq!(result, Err(Error::NothingToClaim));
 
         // At 25% through
         env.ledger().with_mut(|li| li.timestamp = 125);
@@ -1072,6 +1850,7 @@ mod tests {
             start_time: 100,
             end_time: 200,
             cliff_time: 200, // Same as end_time
+            curve: VestingCurve::Linear,
         };
 
         // Validation should accept this configuration
@@ -1092,6 +1871,16 @@ mod tests {
             metadata: None,
             cancelled: false,
             paused: false,
+            paused_at: 0,
+            total_paused_secs: 0,
+            cancelled_at: 0,
+            settled_amount: 0,
+            refunded_amount: 0,
+            vested_base: 0,
+            curve: VestingCurve::Linear,
+            transferable: true,
+            parent_id: None,
+            children: Vec::new(&env),
         };
         set_stream(&env, &contract_id, 0, &stream);
 
@@ -1128,6 +1917,16 @@ mod tests {
             metadata: None,
             cancelled: false,
             paused: false,
+            paused_at: 0,
+            total_paused_secs: 0,
+            cancelled_at: 0,
+            settled_amount: 0,
+            refunded_amount: 0,
+            vested_base: 0,
+            curve: VestingCurve::Linear,
+            transferable: true,
+            parent_id: None,
+            children: Vec::new(&env),
         };
         set_stream(&env, &contract_id, 0, &stream);
 
@@ -1176,6 +1975,16 @@ mod tests {
             metadata: None,
             cancelled: false,
             paused: false,
+            paused_at: 0,
+            total_paused_secs: 0,
+            cancelled_at: 0,
+            settled_amount: 0,
+            refunded_amount: 0,
+            vested_base: 0,
+            curve: VestingCurve::Linear,
+            transferable: true,
+            parent_id: None,
+            children: Vec::new(&env),
         };
         set_stream(&env, &contract_id, 0, &stream);
 
@@ -1221,6 +2030,16 @@ mod tests {
             metadata: None,
             cancelled: true, // Stream is cancelled
             paused: false,
+            paused_at: 0,
+            total_paused_secs: 0,
+            cancelled_at: 0,
+            settled_amount: 0,
+            refunded_amount: 0,
+            vested_base: 0,
+            curve: VestingCurve::Linear,
+            transferable: true,
+            parent_id: None,
+            children: Vec::new(&env),
         };
         set_stream(&env, &contract_id, 0, &stream);
 
@@ -1249,18 +2068,30 @@ mod tests {
             end_time: 200,
             cliff_time: 150,
             metadata: None,
-            cancelled: true, // Stream is cancelled
+            cancelled: true, // Cancelled at 150 with half vested
             paused: false,
+            paused_at: 0,
+            total_paused_secs: 0,
+            cancelled_at: 150,
+            settled_amount: 500,
+            refunded_amount: 500,
+            vested_base: 0,
+            curve: VestingCurve::Linear,
+            transferable: true,
+            parent_id: None,
+            children: Vec::new(&env),
         };
         set_stream(&env, &contract_id, 0, &stream);
 
-        // Set time at or after cliff
-        env.ledger().with_mut(|li| li.timestamp = 150);
+        // Long after the original end time
+        env.ledger().with_mut(|li| li.timestamp = 300);
 
-        // Attempt claim - should return StreamCancelled
-        // Cliff check passes, so cancellation check is reached
-        let result = claim(&env, &contract_id, &recipient, 0);
-        assert_eq!(result, Err(Error::StreamCancelled));
+        // Only the amount settled at cancellation is claimable
+        assert_eq!(claim(&env, &contract_id, &recipient, 0), Ok(500));
+        assert_eq!(
+            claim(&env, &contract_id, &recipient, 0),
+            Err(Error::NothingToClaim)
+        );
     }
 
     // ========================================================================
@@ -1279,6 +2110,7 @@ mod tests {
             start_time: 100,
             end_time: 100,   // Same as start
             cliff_time: 100, // Same as start
+            curve: VestingCurve::Linear,
         };
 
         // Validation should accept this configuration
@@ -1300,6 +2132,16 @@ mod tests {
             metadata: None,
             cancelled: false,
             paused: false,
+            paused_at: 0,
+            total_paused_secs: 0,
+            cancelled_at: 0,
+            settled_amount: 0,
+            refunded_amount: 0,
+            vested_base: 0,
+            curve: VestingCurve::Linear,
+            transferable: true,
+            parent_id: None,
+            children: Vec::new(&env),
         };
         set_stream(&env, &contract_id, 0, &stream);
 
@@ -1324,6 +2166,7 @@ mod tests {
             start_time: 100,
             end_time: 100,  // Same as start
             cliff_time: 50, // Before start - invalid
+            curve: VestingCurve::Linear,
         };
 
         // Current implementation rejects start_time >= end_time first.
@@ -1352,6 +2195,16 @@ mod tests {
             metadata: None,
             cancelled: false,
             paused: false,
+            paused_at: 0,
+            total_paused_secs: 0,
+            cancelled_at: 0,
+            settled_amount: 0,
+            refunded_amount: 0,
+            vested_base: 0,
+            curve: VestingCurve::Linear,
+            transferable: true,
+            parent_id: None,
+            children: Vec::new(&env),
         };
         set_stream(&env, &contract_id, 0, &stream);
 
@@ -1381,6 +2234,16 @@ mod tests {
             metadata: None,
             cancelled: false,
             paused: false,
+            paused_at: 0,
+            total_paused_secs: 0,
+            cancelled_at: 0,
+            settled_amount: 0,
+            refunded_amount: 0,
+            vested_base: 0,
+            curve: VestingCurve::Linear,
+            transferable: true,
+            parent_id: None,
+            children: Vec::new(&env),
         };
         set_stream(&env, &contract_id, 0, &stream);
 
@@ -1421,6 +2284,16 @@ mod tests {
             metadata: None,
             cancelled: false,
             paused: false,
+            paused_at: 0,
+            total_paused_secs: 0,
+            cancelled_at: 0,
+            settled_amount: 0,
+            refunded_amount: 0,
+            vested_base: 0,
+            curve: VestingCurve::Linear,
+            transferable: true,
+            parent_id: None,
+            children: Vec::new(&env),
         };
         set_stream(&env, &contract_id, 0, &stream);
 
@@ -1437,3 +2310,82 @@ mod tests {
         assert_eq!(stream2.cliff_time, stream3.cliff_time);
     }
 }
+
+#[cfg(test)]
+mod pause_clock_tests {
+    use super::*;
+    use crate::vesting;
+    use proptest::prelude::*;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        /// PROPERTY: after a pause of `pause_len` seconds the on-chain claim
+        /// path, `stream_types` and `vesting` all report the linear schedule
+        /// frozen during the pause and shifted by `pause_len` after it
+        #[test]
+        fn prop_pause_shifts_schedule(
+            total in 1i128..=1_000_000_000_000i128,
+            start in 0u64..=1_000_000u64,
+            duration in 1u64..=1_000_000u64,
+            pause_offset in 0u64..=2_000_000u64,
+            pause_len in 0u64..=1_000_000u64,
+            query_offset in 0u64..=4_000_000u64,
+        ) {
+            let env = Env::default();
+            env.mock_all_auths();
+            let contract_id = env.register(crate::TokenFactory, ());
+            let creator = Address::generate(&env);
+
+            let end = start + duration;
+            let pause_at = start + pause_offset;
+            let resume_at = pause_at + pause_len;
+            let query = pause_at + query_offset;
+
+            let stream = StreamInfo {
+                id: 0,
+                creator: creator.clone(),
+                recipient: Address::generate(&env),
+                token_index: 0,
+                total_amount: total,
+                claimed_amount: 0,
+                start_time: start,
+                end_time: end,
+                cliff_time: start,
+                metadata: None,
+                cancelled: false,
+                paused: false,
+                paused_at: 0,
+                total_paused_secs: 0,
+                cancelled_at: 0,
+                settled_amount: 0,
+                refunded_amount: 0,
+                vested_base: 0,
+                curve: VestingCurve::Linear,
+                transferable: true,
+                parent_id: None,
+                children: Vec::new(&env),
+            };
+
+            env.as_contract(&contract_id, || storage::set_stream(&env, 0, &stream));
+            env.ledger().with_mut(|li| li.timestamp = pause_at);
+            env.as_contract(&contract_id, || pause_stream(&env, &creator, 0)).unwrap();
+            if query >= resume_at {
+                env.ledger().with_mut(|li| li.timestamp = resume_at);
+                env.as_contract(&contract_id, || unpause_stream(&env, &creator, 0)).unwrap();
+            }
+            env.ledger().with_mut(|li| li.timestamp = query);
+            let (on_chain, stored) = env.as_contract(&contract_id, || {
+                let stored = storage::get_stream(&env, 0).unwrap();
+                (calculate_claimable(&env, &stored).unwrap(), stored)
+            });
+
+            let clock = if query < resume_at { pause_at } else { query - pause_len };
+            let expected = vesting::vested_amount(total, start, end, clock).unwrap();
+
+            prop_assert_eq!(on_chain, expected);
+            prop_assert_eq!(stream_types::calculate_claimable_amount(&stored, query), expected);
+        }
+    }
+}
@@ -0,0 +1,157 @@
+//! Supply Policy Tests
+//!
+//! Tests for lowering the supply cap and permanently finalizing supply
+
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    vec, Address, Env, IntoVal, Symbol,
+};
+
+use crate::test_helpers::{create_tokens, token_params, TestEnv, BASE_FEE};
+use crate::types::{Error, TokenCreationParams};
+use crate::TokenFactoryClient;
+
+fn setup(max_supply: Option<i128>) -> (Env, TokenFactoryClient<'static>, Address) {
+    let TestEnv { env, client, .. } = TestEnv::initialized();
+
+    let creator = Address::generate(&env);
+    let params = TokenCreationParams {
+        max_supply,
+        ..token_params(&env, "CAP", 1_000)
+    };
+    create_tokens(&client, &creator, vec![&env, params]);
+
+    (env, client, creator)
+}
+
+#[test]
+fn test_max_supply_is_recorded_at_creation() {
+    let (_env, client, _creator) = setup(Some(5_000));
+
+    assert_eq!(client.get_token_info(&0).max_supply, Some(5_000));
+    assert_eq!(client.get_remaining_mintable(&0), Some(4_000));
+}
+
+#[test]
+fn test_set_max_supply_lowers_cap() {
+    let (_env, client, creator) = setup(Some(5_000));
+
+    client.set_max_supply(&creator, &0, &2_000);
+
+    assert_eq!(client.get_token_info(&0).max_supply, Some(2_000));
+    assert_eq!(client.get_remaining_mintable(&0), Some(1_000));
+}
+
+#[test]
+fn test_set_max_supply_cannot_raise_cap() {
+    let (_env, client, creator) = setup(Some(5_000));
+
+    assert_eq!(
+        client.try_set_max_supply(&creator, &0, &5_001),
+        Err(Ok(Error::InvalidMaxSupply))
+    );
+}
+
+#[test]
+fn test_set_max_supply_not_below_current_supply() {
+    let (_env, client, creator) = setup(None);
+
+    assert_eq!(
+        client.try_set_max_supply(&creator, &0, &999),
+        Err(Ok(Error::InvalidMaxSupply))
+    );
+
+    // Capping an uncapped token at exactly the current supply is allowed
+    client.set_max_supply(&creator, &0, &1_000);
+    assert_eq!(client.get_remaining_mintable(&0), Some(0));
+}
+
+#[test]
+fn test_set_max_supply_requires_creator() {
+    let (env, client, _creator) = setup(None);
+    let stranger = Address::generate(&env);
+
+    assert_eq!(
+        client.try_set_max_supply(&stranger, &0, &2_000),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_lowered_cap_binds_delegated_minters() {
+    let (env, client, creator) = setup(None);
+    let minter = Address::generate(&env);
+    let to = Address::generate(&env);
+
+    client.add_minter(&creator, &0, &minter, &500, &0);
+    client.set_max_supply(&creator, &0, &1_100);
+
+    assert_eq!(
+        client.try_mint(&minter, &0, &to, &200),
+        Err(Ok(Error::MaxSupplyExceeded))
+    );
+    assert_eq!(client.get_minter_allowance(&0, &minter), 500);
+}
+
+#[test]
+fn test_finalize_supply_disables_minting() {
+    let (env, client, creator) = setup(None);
+    let to = Address::generate(&env);
+
+    client.mint(&creator, &0, &to, &500);
+    client.finalize_supply(&creator, &0);
+
+    assert!(client.is_supply_finalized(&0));
+    assert_eq!(client.get_token_info(&0).max_supply, Some(1_500));
+    assert_eq!(client.get_remaining_mintable(&0), Some(0));
+    assert_eq!(
+        client.try_mint(&creator, &0, &to, &1),
+        Err(Ok(Error::MintingDisabled))
+    );
+}
+
+#[test]
+fn test_finalize_supply_is_permanent() {
+    let (_env, client, creator) = setup(Some(5_000));
+
+    client.finalize_supply(&creator, &0);
+
+    assert_eq!(
+        client.try_finalize_supply(&creator, &0),
+        Err(Ok(Error::MintingDisabled))
+    );
+    assert_eq!(
+        client.try_set_max_supply(&creator, &0, &1_000),
+        Err(Ok(Error::MintingDisabled))
+    );
+}
+
+#[test]
+fn test_policy_changes_do_not_reregister_token() {
+    let (env, client, creator) = setup(Some(5_000));
+    let before = env.events().all().len();
+
+    client.set_max_supply(&creator, &0, &2_000);
+    client.finalize_supply(&creator, &0);
+
+    assert_eq!(client.get_creator_token_count(&creator), 1);
+    let registered = Symbol::new(&env, "tok_rg_v1");
+    for (_, topics, _) in env.events().all().iter().skip(before as usize) {
+        let topic: Symbol = topics.get(0).unwrap().into_val(&env);
+        assert_ne!(topic, registered);
+    }
+}
+
+#[test]
+fn test_max_supply_below_initial_rejected_at_creation() {
+    let (env, client, creator) = setup(None);
+
+    let params = TokenCreationParams {
+        max_supply: Some(999),
+        ..token_params(&env, "BAD", 1_000)
+    };
+    assert_eq!(
+        client.try_set_metadata(&creator, &vec![&env, params], &BASE_FEE),
+        Err(Ok(Error::InvalidMaxSupply))
+    );
+}
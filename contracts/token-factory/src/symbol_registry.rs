@@ -0,0 +1,217 @@
+use crate::storage;
+use crate::types::{Error, SymbolPolicy};
+use soroban_sdk::{Address, Env, String, Vec};
+
+/// Maximum symbol length in bytes
+pub const MAX_SYMBOL_LEN: u32 = 12;
+/// Maximum number of symbols reserved or released per call
+const MAX_RESERVE_BATCH: u32 = 50;
+
+/// Normalize a token symbol for registry lookups
+///
+/// Symbols are restricted to ASCII letters and digits so look-alike
+/// characters cannot be used to impersonate another ticker. Lookups are
+/// case-insensitive: the normalized form is the upper-cased symbol.
+///
+/// # Returns
+/// * `Ok(normalized)` - Upper-cased symbol
+/// * `Err(Error::InvalidTokenParams)` - Empty, longer than 12 bytes, or outside `[A-Za-z0-9]`
+pub fn normalize_symbol(env: &Env, symbol: &String) -> Result<String, Error> {
+    let len = symbol.len();
+    if len == 0 || len > MAX_SYMBOL_LEN {
+        return Err(Error::InvalidTokenParams);
+    }
+
+    let mut buf = [0u8; MAX_SYMBOL_LEN as usize];
+    let bytes = &mut buf[..len as usize];
+    symbol.copy_into_slice(bytes);
+
+    for byte in bytes.iter_mut() {
+        if !byte.is_ascii_alphanumeric() {
+            return Err(Error::InvalidTokenParams);
+        }
+        byte.make_ascii_uppercase();
+    }
+
+    Ok(String::from_bytes(env, bytes))
+}
+
+/// Check that `creator` may launch a token under a normalized symbol
+///
+/// Reserved symbols can only be used by the admin. Under the `FirstCome`
+/// policy a symbol that is already registered is rejected.
+///
+/// # Returns
+/// * `Ok(())` - Symbol is available
+/// * `Err(Error::InvalidTokenParams)` - Symbol is reserved or already taken
+pub fn check_available(env: &Env, creator: &Address, normalized: &String) -> Result<(), Error> {
+    if storage::is_symbol_reserved(env, normalized) && *creator != storage::get_admin(env) {
+        return Err(Error::InvalidTokenParams);
+    }
+
+    if storage::get_symbol_policy(env) == SymbolPolicy::FirstCome
+        && storage::get_symbol_owner(env, normalized).is_some()
+    {
+        return Err(Error::InvalidTokenParams);
+    }
+
+    Ok(())
+}
+
+/// Record a newly created token under its normalized symbol
+///
+/// Only the first token registered under a symbol becomes its owner.
+pub fn register(env: &Env, normalized: &String, token_index: u32) {
+    if storage::get_symbol_owner(env, normalized).is_none() {
+        storage::set_symbol_owner(env, normalized, token_index);
+    }
+}
+
+/// Resolve a symbol (case-insensitive) to a token index
+///
+/// Returns the verified token for the symbol if there is one, otherwise
+/// the first token registered under it.
+pub fn get_token_by_symbol(env: &Env, symbol: &String) -> Option<u32> {
+    let normalized = normalize_symbol(env, symbol).ok()?;
+    storage::get_verified_symbol(env, &normalized)
+        .or_else(|| storage::get_symbol_owner(env, &normalized))
+}
+
+/// Set the symbol uniqueness policy (admin only)
+///
+/// Only affects tokens created afterwards.
+///
+/// # Returns
+/// * `Ok(())` - Policy updated
+/// * `Err(Error::Unauthorized)` - Caller is not the admin
+pub fn set_symbol_policy(env: &Env, admin: &Address, policy: SymbolPolicy) -> Result<(), Error> {
+    require_admin(env, admin)?;
+
+    storage::set_symbol_policy(env, policy);
+
+    crate::events::emit_symbol_policy_updated(env, policy);
+
+    Ok(())
+}
+
+/// Reserve or release symbols (admin only)
+///
+/// Reserved symbols can only be used by the admin when creating tokens.
+/// Tokens already using a symbol are not affected.
+///
+/// # Returns
+/// * `Ok(())` - Symbols updated
+/// * `Err(Error::Unauthorized)` - Caller is not the admin
+/// * `Err(Error::InvalidParameters)` - Batch is empty
+/// * `Err(Error::BatchTooLarge)` - More than 50 symbols
+/// * `Err(Error::InvalidTokenParams)` - A symbol fails normalization
+pub fn set_symbols_reserved(
+    env: &Env,
+    admin: &Address,
+    symbols: &Vec<String>,
+    reserved: bool,
+) -> Result<(), Error> {
+    require_admin(env, admin)?;
+
+    if symbols.is_empty() {
+        return Err(Error::InvalidParameters);
+    }
+    if symbols.len() > MAX_RESERVE_BATCH {
+        return Err(Error::BatchTooLarge);
+    }
+
+    for symbol in symbols.iter() {
+        let normalized = normalize_symbol(env, &symbol)?;
+        storage::set_symbol_reserved(env, &normalized, reserved);
+        crate::events::emit_symbol_reserved(env, &normalized, reserved);
+    }
+
+    Ok(())
+}
+
+/// Mark a token as the verified holder of its symbol (admin only)
+///
+/// At most one token per symbol is verified; verifying another token
+/// replaces the previous one. Unverifying a token that is not the
+/// verified one is a no-op.
+///
+/// # Returns
+/// * `Ok(())` - Verification updated
+/// * `Err(Error::Unauthorized)` - Caller is not the admin
+/// * `Err(Error::TokenNotFound)` - Token doesn't exist
+/// * `Err(Error::InvalidTokenParams)` - Token symbol fails normalization
+pub fn set_token_verified(
+    env: &Env,
+    admin: &Address,
+    token_index: u32,
+    verified: bool,
+) -> Result<(), Error> {
+    require_admin(env, admin)?;
+
+    let token_info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+    let normalized = normalize_symbol(env, &token_info.symbol)?;
+
+    if verified {
+        storage::set_verified_symbol(env, &normalized, Some(token_index));
+    } else if storage::get_verified_symbol(env, &normalized) == Some(token_index) {
+        storage::set_verified_symbol(env, &normalized, None);
+    } else {
+        return Ok(());
+    }
+
+    crate::events::emit_token_verified(env, token_index, verified);
+
+    Ok(())
+}
+
+/// Whether a token is the verified holder of its symbol
+pub fn is_token_verified(env: &Env, token_index: u32) -> bool {
+    let Some(token_info) = storage::get_token_info(env, token_index) else {
+        return false;
+    };
+    match normalize_symbol(env, &token_info.symbol) {
+        Ok(normalized) => storage::get_verified_symbol(env, &normalized) == Some(token_index),
+        Err(_) => false,
+    }
+}
+
+fn require_admin(env: &Env, admin: &Address) -> Result<(), Error> {
+    admin.require_auth();
+    if *admin != storage::get_admin(env) {
+        return Err(Error::Unauthorized);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_uppercases() {
+        let env = Env::default();
+        let symbol = String::from_str(&env, "usdC1");
+        assert_eq!(
+            normalize_symbol(&env, &symbol),
+            Ok(String::from_str(&env, "USDC1"))
+        );
+    }
+
+    #[test]
+    fn test_normalize_rejects_non_alphanumeric() {
+        let env = Env::default();
+        for symbol in ["US-DC", "USD C", "USDÇ", ""] {
+            assert_eq!(
+                normalize_symbol(&env, &String::from_str(&env, symbol)),
+                Err(Error::InvalidTokenParams)
+            );
+        }
+    }
+
+    #[test]
+    fn test_normalize_rejects_too_long() {
+        let env = Env::default();
+        let symbol = String::from_str(&env, "ABCDEFGHIJKLM");
+        assert_eq!(normalize_symbol(&env, &symbol), Err(Error::InvalidTokenParams));
+    }
+}
@@ -0,0 +1,187 @@
+//! Symbol Registry Tests
+//!
+//! Tests for case-insensitive symbol lookup, reserved tickers and the
+//! symbol uniqueness policy
+
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String, Vec};
+
+use crate::test_helpers::{token_params, TestEnv, BASE_FEE};
+use crate::types::{Error, SymbolPolicy};
+use crate::TokenFactoryClient;
+
+fn create(
+    env: &Env,
+    client: &TokenFactoryClient,
+    creator: &Address,
+    symbol: &str,
+) -> Result<(), Error> {
+    match client.try_set_metadata(
+        creator,
+        &vec![env, token_params(env, symbol, 1_000)],
+        &BASE_FEE,
+    ) {
+        Ok(_) => Ok(()),
+        Err(Ok(e)) => Err(e),
+        Err(Err(_)) => panic!("unexpected host error"),
+    }
+}
+
+fn sym(env: &Env, symbol: &str) -> String {
+    String::from_str(env, symbol)
+}
+
+#[test]
+fn test_lookup_is_case_insensitive() {
+    let TestEnv { env, client, .. } = TestEnv::initialized();
+    let creator = Address::generate(&env);
+
+    create(&env, &client, &creator, "Nova").unwrap();
+
+    assert_eq!(client.get_token_by_symbol(&sym(&env, "NOVA")), Some(0));
+    assert_eq!(client.get_token_by_symbol(&sym(&env, "nova")), Some(0));
+    assert_eq!(client.get_token_by_symbol(&sym(&env, "OTHER")), None);
+}
+
+#[test]
+fn test_non_ascii_symbols_rejected() {
+    let TestEnv { env, client, .. } = TestEnv::initialized();
+    let creator = Address::generate(&env);
+
+    assert_eq!(
+        create(&env, &client, &creator, "U$DC"),
+        Err(Error::InvalidTokenParams)
+    );
+    assert_eq!(
+        create(&env, &client, &creator, "USDС"),
+        Err(Error::InvalidTokenParams)
+    );
+    assert_eq!(
+        create(&env, &client, &creator, "US DC"),
+        Err(Error::InvalidTokenParams)
+    );
+}
+
+#[test]
+fn test_reserved_symbol_blocks_non_admin() {
+    let TestEnv {
+        env, client, admin, ..
+    } = TestEnv::initialized();
+    let creator = Address::generate(&env);
+
+    client.reserve_symbols(&admin, &vec![&env, sym(&env, "usdc")]);
+    assert!(client.is_symbol_reserved(&sym(&env, "USDC")));
+
+    assert_eq!(
+        create(&env, &client, &creator, "UsDc"),
+        Err(Error::InvalidTokenParams)
+    );
+    create(&env, &client, &admin, "USDC").unwrap();
+
+    client.release_symbols(&admin, &vec![&env, sym(&env, "USDC")]);
+    assert!(!client.is_symbol_reserved(&sym(&env, "usdc")));
+    create(&env, &client, &creator, "USDC").unwrap();
+}
+
+#[test]
+fn test_only_admin_manages_registry() {
+    let TestEnv { env, client, .. } = TestEnv::initialized();
+    let stranger = Address::generate(&env);
+    create(&env, &client, &stranger, "MINE").unwrap();
+
+    assert_eq!(
+        client.try_reserve_symbols(&stranger, &vec![&env, sym(&env, "XLM")]),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_symbol_policy(&stranger, &SymbolPolicy::FirstCome),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_token_verified(&stranger, &0, &true),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_duplicates_allowed_by_default() {
+    let TestEnv { env, client, .. } = TestEnv::initialized();
+
+    create(&env, &client, &Address::generate(&env), "DUP").unwrap();
+    create(&env, &client, &Address::generate(&env), "dup").unwrap();
+
+    assert_eq!(client.get_symbol_policy(), SymbolPolicy::AllowDuplicates);
+    assert_eq!(client.get_token_by_symbol(&sym(&env, "DUP")), Some(0));
+}
+
+#[test]
+fn test_first_come_policy_rejects_taken_symbol() {
+    let TestEnv {
+        env, client, admin, ..
+    } = TestEnv::initialized();
+    client.set_symbol_policy(&admin, &SymbolPolicy::FirstCome);
+
+    create(&env, &client, &Address::generate(&env), "ONCE").unwrap();
+    assert_eq!(
+        create(&env, &client, &Address::generate(&env), "once"),
+        Err(Error::InvalidTokenParams)
+    );
+}
+
+#[test]
+fn test_first_come_policy_rejects_duplicates_within_batch() {
+    let TestEnv {
+        env, client, admin, ..
+    } = TestEnv::initialized();
+    client.set_symbol_policy(&admin, &SymbolPolicy::FirstCome);
+
+    let batch = vec![
+        &env,
+        token_params(&env, "PAIR", 1_000),
+        token_params(&env, "pair", 1_000),
+    ];
+    assert_eq!(
+        client.try_set_metadata(&Address::generate(&env), &batch, &(2 * BASE_FEE)),
+        Err(Ok(Error::InvalidTokenParams))
+    );
+    assert_eq!(client.get_token_by_symbol(&sym(&env, "PAIR")), None);
+}
+
+#[test]
+fn test_verified_token_wins_lookup() {
+    let TestEnv {
+        env, client, admin, ..
+    } = TestEnv::initialized();
+
+    create(&env, &client, &Address::generate(&env), "USDC").unwrap();
+    create(&env, &client, &Address::generate(&env), "USDC").unwrap();
+
+    client.set_token_verified(&admin, &1, &true);
+    assert!(client.is_token_verified(&1));
+    assert!(!client.is_token_verified(&0));
+    assert_eq!(client.get_token_by_symbol(&sym(&env, "usdc")), Some(1));
+
+    client.set_token_verified(&admin, &1, &false);
+    assert!(!client.is_token_verified(&1));
+    assert_eq!(client.get_token_by_symbol(&sym(&env, "usdc")), Some(0));
+}
+
+#[test]
+fn test_reserve_batch_limits() {
+    let TestEnv {
+        env, client, admin, ..
+    } = TestEnv::initialized();
+
+    assert_eq!(
+        client.try_reserve_symbols(&admin, &Vec::new(&env)),
+        Err(Ok(Error::InvalidParameters))
+    );
+
+    let mut symbols = Vec::new(&env);
+    for _ in 0..51 {
+        symbols.push_back(sym(&env, "XLM"));
+    }
+    assert_eq!(
+        client.try_reserve_symbols(&admin, &symbols),
+        Err(Ok(Error::BatchTooLarge))
+    );
+}
@@ -4,46 +4,82 @@ extern crate alloc;
 use alloc::vec::Vec;
 
 use soroban_sdk::testutils::{Address as _, Events, Ledger};
-use soroban_sdk::{Address, Bytes, Env, Symbol, TryFromVal, Val};
+use soroban_sdk::{vec, Address, Bytes, Env, String, Symbol, TryFromVal, Val};
 
 use crate::storage;
 use crate::timelock;
-use crate::types::{ActionType, Error, VoteChoice};
+use crate::types::{
+    ActionType, ComplianceMode, Error, StreamParams, TokenCreationParams, TransferFeeConfig,
+    VestingCurve, VoteChoice,
+};
+use crate::{TokenFactory, TokenFactoryClient};
+
+/// Base fee charged per token by `TestEnv::initialized`
+pub const BASE_FEE: i128 = 100_000_000;
+/// Metadata fee charged by `TestEnv::initialized`
+pub const METADATA_FEE: i128 = 50_000_000;
 
 pub struct TestEnv {
     pub env: Env,
     pub admin: Address,
     pub treasury: Address,
+    pub contract_id: Address,
+    pub client: TokenFactoryClient<'static>,
 }
 
 impl TestEnv {
     pub fn new() -> Self {
+        let test_env = Self::deploy();
+
+        test_env.env.as_contract(&test_env.contract_id, || {
+            storage::set_admin(&test_env.env, &test_env.admin);
+            storage::set_treasury(&test_env.env, &test_env.treasury);
+            storage::set_base_fee(&test_env.env, 1_000_000);
+            storage::set_metadata_fee(&test_env.env, 500_000);
+        });
+
+        test_env
+    }
+
+    /// Factory set up through `initialize` with `BASE_FEE` and `METADATA_FEE`
+    pub fn initialized() -> Self {
+        let test_env = Self::deploy();
+        test_env.client.initialize(
+            &test_env.admin,
+            &test_env.treasury,
+            &BASE_FEE,
+            &METADATA_FEE,
+        );
+        test_env
+    }
+
+    fn deploy() -> Self {
         let env = Env::default();
         env.mock_all_auths();
 
         let admin = Address::generate(&env);
         let treasury = Address::generate(&env);
-
-        env.as_contract(&env.register_contract(None, crate::TokenFactory), || {
-            storage::set_admin(&env, &admin);
-            storage::set_treasury(&env, &treasury);
-            storage::set_base_fee(&env, 1_000_000);
-            storage::set_metadata_fee(&env, 500_000);
-        });
+        let contract_id = env.register(TokenFactory, ());
+        let client = TokenFactoryClient::new(&env, &contract_id);
 
         Self {
             env,
             admin,
             treasury,
+            contract_id,
+            client,
         }
     }
 
     pub fn with_timelock(delay_seconds: u64) -> Self {
         let test_env = Self::new();
-        test_env.env.as_contract(&test_env.env.current_contract_address(), || {
-            timelock::initialize_timelock(&test_env.env, Some(delay_seconds)).unwrap();
-            crate::governance::initialize_governance(&test_env.env, Some(30), Some(51)).unwrap();
-        });
+        test_env
+            .env
+            .as_contract(&test_env.env.current_contract_address(), || {
+                timelock::initialize_timelock(&test_env.env, Some(delay_seconds)).unwrap();
+                crate::governance::initialize_governance(&test_env.env, Some(30), Some(51))
+                    .unwrap();
+            });
         test_env
     }
 }
@@ -102,6 +138,112 @@ impl<'a> TimeController<'a> {
     }
 }
 
+/// Create one token with `token_params` defaults for `creator`
+pub fn create_token(
+    client: &TokenFactoryClient,
+    creator: &Address,
+    symbol: &str,
+    initial_supply: i128,
+) -> Address {
+    let params = token_params(&client.env, symbol, initial_supply);
+    create_tokens(client, creator, vec![&client.env, params])
+        .get(0)
+        .unwrap()
+}
+
+/// Create tokens for `creator`, paying `BASE_FEE` for each
+pub fn create_tokens(
+    client: &TokenFactoryClient,
+    creator: &Address,
+    params: soroban_sdk::Vec<TokenCreationParams>,
+) -> soroban_sdk::Vec<Address> {
+    let fee = BASE_FEE * params.len() as i128;
+    client.set_metadata(creator, &params, &fee)
+}
+
+/// Open, uncapped, fee-free token with 7 decimals
+pub fn token_params(env: &Env, symbol: &str, initial_supply: i128) -> TokenCreationParams {
+    TokenCreationParams {
+        name: String::from_str(env, "Test Token"),
+        symbol: String::from_str(env, symbol),
+        decimals: 7,
+        initial_supply,
+        max_supply: None,
+        metadata_uri: None,
+        compliance_mode: ComplianceMode::Open,
+        transfer_fee: TransferFeeConfig::none(env),
+    }
+}
+
+/// Factory with token 0 owned by `creator`, and `alice` and `bob` to
+/// stream to
+pub struct StreamFixture {
+    pub env: Env,
+    pub contract_id: Address,
+    pub client: TokenFactoryClient<'static>,
+    pub creator: Address,
+    pub alice: Address,
+    pub bob: Address,
+}
+
+impl StreamFixture {
+    pub fn new(symbol: &str, initial_supply: i128) -> Self {
+        let TestEnv {
+            env,
+            contract_id,
+            client,
+            ..
+        } = TestEnv::initialized();
+
+        let creator = Address::generate(&env);
+        create_token(&client, &creator, symbol, initial_supply);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+
+        Self {
+            env,
+            contract_id,
+            client,
+            creator,
+            alice,
+            bob,
+        }
+    }
+}
+
+/// Create a linear stream of token 0 from `creator`
+pub fn create_stream(
+    client: &TokenFactoryClient,
+    creator: &Address,
+    recipient: &Address,
+    total_amount: i128,
+    start_time: u64,
+    cliff_time: u64,
+    end_time: u64,
+) -> u64 {
+    let params = stream_params(recipient, total_amount, start_time, cliff_time, end_time);
+    client.create_stream(creator, &params)
+}
+
+/// Linear stream of token 0
+pub fn stream_params(
+    recipient: &Address,
+    total_amount: i128,
+    start_time: u64,
+    cliff_time: u64,
+    end_time: u64,
+) -> StreamParams {
+    StreamParams {
+        recipient: recipient.clone(),
+        token_index: 0,
+        total_amount,
+        start_time,
+        end_time,
+        cliff_time,
+        curve: VestingCurve::Linear,
+    }
+}
+
 pub fn set_time(env: &Env, timestamp: u64) {
     env.ledger().with_mut(|li| {
         li.timestamp = timestamp;
@@ -1,18 +1,33 @@
 use crate::events;
 use crate::storage;
 use crate::types::{
-    ActionType, ChangeType, Error, PendingChange, Proposal, TimelockConfig, VoteChoice,
+    ActionType, ChangeStatus, ChangeType, Error, PendingChange, PendingChangesPage, Proposal,
+    TimelockConfig, VoteChoice,
 };
 #[cfg(test)]
 use soroban_sdk::testutils::Ledger;
-use soroban_sdk::{Address, Bytes, Env, Vec};
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, Vec};
 
 /// Default timelock delay in seconds (48 hours)
-const DEFAULT_TIMELOCK_DELAY: u64 = 172_800;
+pub(crate) const DEFAULT_TIMELOCK_DELAY: u64 = 172_800;
 
 /// Maximum timelock delay in seconds (30 days)
 const MAX_TIMELOCK_DELAY: u64 = 2_592_000;
 
+/// Default execution window after `execute_at` in seconds (7 days)
+pub(crate) const DEFAULT_CHANGE_EXPIRY: u64 = 604_800;
+
+/// Maximum execution window after `execute_at` in seconds (90 days)
+const MAX_CHANGE_EXPIRY: u64 = 7_776_000;
+
+/// Maximum number of changes returned per page
+const MAX_CHANGES_PAGE_SIZE: u32 = 50;
+
+/// Maximum number of change ids inspected per page
+///
+/// Bounds the cost of filtered listings; callers continue from `next_cursor`.
+const MAX_CHANGES_SCAN: u64 = 200;
+
 /// Initialize timelock configuration
 ///
 /// Sets up the timelock delay for sensitive operations.
@@ -34,6 +49,7 @@ pub fn initialize_timelock(env: &Env, delay_seconds: Option<u64>) -> Result<(),
     let config = TimelockConfig {
         delay_seconds: delay,
         enabled: true,
+        expiry_seconds: DEFAULT_CHANGE_EXPIRY,
     };
 
     storage::set_timelock_config(env, &config);
@@ -94,24 +110,38 @@ pub fn schedule_fee_update(
     let execute_at = current_time
         .checked_add(config.delay_seconds)
         .ok_or(Error::ArithmeticError)?;
+    let expires_at = execute_at
+        .checked_add(config.expiry_seconds)
+        .ok_or(Error::ArithmeticError)?;
 
     let change_id = storage::get_next_change_id(env)?;
 
-    let pending_change = PendingChange {
+    let mut pending_change = PendingChange {
         id: change_id,
         change_type: ChangeType::FeeUpdate,
         scheduled_by: admin.clone(),
         scheduled_at: current_time,
         execute_at,
+        expires_at,
         executed: false,
+        status: ChangeStatus::Pending,
+        content_hash: BytesN::from_array(env, &[0u8; 32]),
         base_fee,
         metadata_fee,
         paused: None,
         treasury: None,
     };
+    pending_change.content_hash = compute_content_hash(env, &pending_change);
 
     storage::set_pending_change(env, change_id, &pending_change);
-    events::emit_change_scheduled(env, change_id, ChangeType::FeeUpdate, execute_at);
+    events::emit_change_scheduled(
+        env,
+        change_id,
+        ChangeType::FeeUpdate,
+        execute_at,
+        expires_at,
+        &pending_change.content_hash,
+    );
 
     Ok(change_id)
 }
@@ -143,24 +173,38 @@ pub fn schedule_pause_update(env: &Env, admin: &Address, paused: bool) -> Result
     let execute_at = current_time
         .checked_add(config.delay_seconds)
         .ok_or(Error::ArithmeticError)?;
+    let expires_at = execute_at
+        .checked_add(config.expiry_seconds)
+        .ok_or(Error::ArithmeticError)?;
 
     let change_id = storage::get_next_change_id(env)?;
 
-    let pending_change = PendingChange {
+    let mut pending_change = PendingChange {
         id: change_id,
         change_type: ChangeType::PauseUpdate,
         scheduled_by: admin.clone(),
         scheduled_at: current_time,
         execute_at,
+        expires_at,
         executed: false,
+        status: ChangeStatus::Pending,
+        content_hash: BytesN::from_array(env, &[0u8; 32]),
         base_fee: None,
         metadata_fee: None,
         paused: Some(paused),
         treasury: None,
     };
+    pending_change.content_hash = compute_content_hash(env, &pending_change);
 
     storage::set_pending_change(env, change_id, &pending_change);
-    events::emit_change_scheduled(env, change_id, ChangeType::PauseUpdate, execute_at);
+    events::emit_change_scheduled(
+        env,
+        change_id,
+        ChangeType::PauseUpdate,
+        execute_at,
+        expires_at,
+        &pending_change.content_hash,
+    );
 
     Ok(change_id)
 }
@@ -196,32 +240,82 @@ pub fn schedule_treasury_update(
     let execute_at = current_time
         .checked_add(config.delay_seconds)
         .ok_or(Error::ArithmeticError)?;
+    let expires_at = execute_at
+        .checked_add(config.expiry_seconds)
+        .ok_or(Error::ArithmeticError)?;
 
     let change_id = storage::get_next_change_id(env)?;
 
-    let pending_change = PendingChange {
+    let mut pending_change = PendingChange {
         id: change_id,
         change_type: ChangeType::TreasuryUpdate,
         scheduled_by: admin.clone(),
         scheduled_at: current_time,
         execute_at,
+        expires_at,
         executed: false,
+        status: ChangeStatus::Pending,
+        content_hash: BytesN::from_array(env, &[0u8; 32]),
         base_fee: None,
         metadata_fee: None,
         paused: None,
         treasury: Some(new_treasury.clone()),
     };
+    pending_change.content_hash = compute_content_hash(env, &pending_change);
 
     storage::set_pending_change(env, change_id, &pending_change);
-    events::emit_change_scheduled(env, change_id, ChangeType::TreasuryUpdate, execute_at);
+    events::emit_change_scheduled(
+        env,
+        change_id,
+        ChangeType::TreasuryUpdate,
+        execute_at,
+        expires_at,
+        &pending_change.content_hash,
+    );
 
     Ok(change_id)
 }
 
+/// Compute the content hash of a change
+///
+/// Hashes the XDR encoding of the change type and its new values, so two
+/// changes with identical effects share a hash regardless of id or timing.
+pub(crate) fn compute_content_hash(env: &Env, change: &PendingChange) -> BytesN<32> {
+    let payload = (
+        change.change_type.clone(),
+        change.base_fee,
+        change.metadata_fee,
+        change.paused,
+        change.treasury.clone(),
+    );
+    env.crypto().sha256(&payload.to_xdr(env)).into()
+}
+
+/// Resolve the effective status of a change at the current ledger time
+///
+/// A `Pending` change whose `expires_at` has passed is reported as
+/// `Expired` even if `expire_change` has not been called yet.
+fn effective_status(env: &Env, change: &PendingChange) -> ChangeStatus {
+    if change.status == ChangeStatus::Pending && env.ledger().timestamp() > change.expires_at {
+        ChangeStatus::Expired
+    } else {
+        change.status
+    }
+}
+
+/// Ensure a change can still be executed or cancelled
+fn ensure_pending(env: &Env, change: &PendingChange) -> Result<(), Error> {
+    match effective_status(env, change) {
+        ChangeStatus::Pending => Ok(()),
+        ChangeStatus::Executed => Err(Error::ChangeAlreadyExecuted),
+        ChangeStatus::Cancelled | ChangeStatus::Expired => Err(Error::InvalidStateTransition),
+    }
+}
+
 /// Execute a pending change
 ///
 /// Executes a previously scheduled change after the timelock has expired.
-/// Can only be called after the execute_at timestamp has passed.
+/// Can only be called between the execute_at and expires_at timestamps.
 ///
 /// # Arguments
 /// * `env` - The contract environment
@@ -231,13 +325,12 @@ pub fn schedule_treasury_update(
 /// * `Error::TokenNotFound` - Change ID not found
 /// * `Error::TimelockNotExpired` - Timelock period has not elapsed
 /// * `Error::ChangeAlreadyExecuted` - Change has already been executed
+/// * `Error::InvalidStateTransition` - Change was cancelled or has expired
 pub fn execute_change(env: &Env, change_id: u64) -> Result<(), Error> {
     let mut pending_change =
         storage::get_pending_change(env, change_id).ok_or(Error::TokenNotFound)?;
 
-    if pending_change.executed {
-        return Err(Error::ChangeAlreadyExecuted);
-    }
+    ensure_pending(env, &pending_change)?;
 
     let current_time = env.ledger().timestamp();
     if current_time < pending_change.execute_at {
@@ -282,6 +375,7 @@ pub fn execute_change(env: &Env, change_id: u64) -> Result<(), Error> {
 
     // Mark as executed
     pending_change.executed = true;
+    pending_change.status = ChangeStatus::Executed;
     storage::set_pending_change(env, change_id, &pending_change);
 
     events::emit_change_executed(env, change_id, pending_change.change_type);
@@ -291,8 +385,8 @@ pub fn execute_change(env: &Env, change_id: u64) -> Result<(), Error> {
 
 /// Cancel a pending change
 ///
-/// Cancels a scheduled change before it is executed.
-/// Only the admin who scheduled it can cancel.
+/// Cancels a scheduled change before it is executed. The change is kept
+/// in storage with `ChangeStatus::Cancelled` so it remains listable.
 ///
 /// # Arguments
 /// * `env` - The contract environment
@@ -303,6 +397,7 @@ pub fn execute_change(env: &Env, change_id: u64) -> Result<(), Error> {
 /// * `Error::Unauthorized` - Caller is not the admin
 /// * `Error::TokenNotFound` - Change ID not found
 /// * `Error::ChangeAlreadyExecuted` - Change has already been executed
+/// * `Error::InvalidStateTransition` - Change was cancelled or has expired
 pub fn cancel_change(env: &Env, admin: &Address, change_id: u64) -> Result<(), Error> {
     admin.require_auth();
 
@@ -311,21 +406,55 @@ pub fn cancel_change(env: &Env, admin: &Address, change_id: u64) -> Result<(), E
         return Err(Error::Unauthorized);
     }
 
-    let pending_change = storage::get_pending_change(env, change_id).ok_or(Error::TokenNotFound)?;
+    let mut pending_change =
+        storage::get_pending_change(env, change_id).ok_or(Error::TokenNotFound)?;
 
-    if pending_change.executed {
-        return Err(Error::ChangeAlreadyExecuted);
-    }
+    ensure_pending(env, &pending_change)?;
 
-    storage::remove_pending_change(env, change_id);
+    pending_change.status = ChangeStatus::Cancelled;
+    storage::set_pending_change(env, change_id, &pending_change);
     events::emit_change_cancelled(env, change_id, pending_change.change_type);
 
     Ok(())
 }
 
+/// Mark an expired change as `Expired`
+///
+/// Persists the expiry of a change whose execution window has passed and
+/// emits a `ch_expd` event. Callable by anyone.
+///
+/// # Errors
+/// * `Error::TokenNotFound` - Change ID not found
+/// * `Error::TimelockNotExpired` - Execution window has not passed yet
+/// * `Error::ChangeAlreadyExecuted` - Change has already been executed
+/// * `Error::InvalidStateTransition` - Change was cancelled or already expired
+pub fn expire_change(env: &Env, change_id: u64) -> Result<(), Error> {
+    let mut pending_change =
+        storage::get_pending_change(env, change_id).ok_or(Error::TokenNotFound)?;
+
+    match pending_change.status {
+        ChangeStatus::Pending => {}
+        ChangeStatus::Executed => return Err(Error::ChangeAlreadyExecuted),
+        ChangeStatus::Cancelled | ChangeStatus::Expired => {
+            return Err(Error::InvalidStateTransition)
+        }
+    }
+
+    if effective_status(env, &pending_change) != ChangeStatus::Expired {
+        return Err(Error::TimelockNotExpired);
+    }
+
+    pending_change.status = ChangeStatus::Expired;
+    storage::set_pending_change(env, change_id, &pending_change);
+    events::emit_change_expired(env, change_id, pending_change.change_type);
+
+    Ok(())
+}
+
 /// Get pending change details
 ///
-/// Retrieves information about a scheduled change.
+/// Retrieves information about a scheduled change. The returned `status`
+/// reflects expiry at the current ledger time.
 ///
 /// # Arguments
 /// * `env` - The contract environment
@@ -334,7 +463,76 @@ pub fn cancel_change(env: &Env, admin: &Address, change_id: u64) -> Result<(), E
 /// # Returns
 /// Returns the PendingChange if found
 pub fn get_pending_change(env: &Env, change_id: u64) -> Option<PendingChange> {
-    storage::get_pending_change(env, change_id)
+    storage::get_pending_change(env, change_id).map(|mut change| {
+        change.status = effective_status(env, &change);
+        change
+    })
+}
+
+/// List scheduled changes in ascending id order
+///
+/// Scans at most `MAX_CHANGES_SCAN` ids starting at `cursor` and returns
+/// those matching `status` (None = any status). A page may therefore hold
+/// fewer than `limit` entries while `next_cursor` is still `Some`.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `status` - Optional status filter (evaluated at the current ledger time)
+/// * `cursor` - First change id to inspect (0 for the first page)
+/// * `limit` - Maximum entries to return (capped at 50)
+pub fn list_pending_changes(
+    env: &Env,
+    status: Option<ChangeStatus>,
+    cursor: u64,
+    limit: u32,
+) -> PendingChangesPage {
+    let limit = limit.min(MAX_CHANGES_PAGE_SIZE);
+    let total = storage::get_change_count(env);
+    let scan_end = cursor.saturating_add(MAX_CHANGES_SCAN).min(total);
+
+    let mut changes = Vec::new(env);
+    let mut id = cursor;
+
+    while id < scan_end && changes.len() < limit {
+        if let Some(change) = get_pending_change(env, id) {
+            if status.is_none() || status == Some(change.status) {
+                changes.push_back(change);
+            }
+        }
+        id += 1;
+    }
+
+    let next_cursor = if id < total { Some(id) } else { None };
+
+    PendingChangesPage {
+        changes,
+        next_cursor,
+    }
+}
+
+/// Update the execution window applied to newly scheduled changes
+///
+/// # Errors
+/// * `Error::Unauthorized` - Caller is not the admin
+/// * `Error::InvalidParameters` - Window is zero or exceeds 90 days
+pub fn set_change_expiry(env: &Env, admin: &Address, expiry_seconds: u64) -> Result<(), Error> {
+    admin.require_auth();
+
+    let current_admin = storage::get_admin(env);
+    if *admin != current_admin {
+        return Err(Error::Unauthorized);
+    }
+
+    if expiry_seconds == 0 || expiry_seconds > MAX_CHANGE_EXPIRY {
+        return Err(Error::InvalidParameters);
+    }
+
+    let mut config = storage::get_timelock_config(env);
+    config.expiry_seconds = expiry_seconds;
+    storage::set_timelock_config(env, &config);
+    events::emit_change_expiry_updated(env, expiry_seconds);
+
+    Ok(())
 }
 
 /// Get timelock configuration
@@ -444,7 +642,179 @@ mod tests {
 
         cancel(&env, &contract_id, &admin, change_id).unwrap();
 
-        assert!(pending(&env, &contract_id, change_id).is_none());
+        let pending = pending(&env, &contract_id, change_id).unwrap();
+        assert_eq!(pending.status, ChangeStatus::Cancelled);
+        assert!(!pending.executed);
+        assert_eq!(execute(&env, &contract_id, change_id), Err(Error::InvalidStateTransition));
+    }
+
+    #[test]
+    fn test_change_expires_after_window() {
+        let (env, admin, contract_id) = setup();
+
+        let change_id = schedule(&env, &contract_id, &admin, Some(2_000_000), None).unwrap();
+        let change = pending(&env, &contract_id, change_id).unwrap();
+        assert_eq!(change.expires_at, change.execute_at + DEFAULT_CHANGE_EXPIRY);
+
+        env.ledger().with_mut(|li| {
+            li.timestamp = change.expires_at + 1;
+        });
+
+        assert_eq!(
+            pending(&env, &contract_id, change_id).unwrap().status,
+            ChangeStatus::Expired
+        );
+        assert_eq!(execute(&env, &contract_id, change_id), Err(Error::InvalidStateTransition));
+        assert_eq!(
+            cancel(&env, &contract_id, &admin, change_id),
+            Err(Error::InvalidStateTransition)
+        );
+
+        env.as_contract(&contract_id, || expire_change(&env, change_id)).unwrap();
+        assert_eq!(
+            env.as_contract(&contract_id, || expire_change(&env, change_id)),
+            Err(Error::InvalidStateTransition)
+        );
+    }
+
+    #[test]
+    fn test_expire_change_before_window_fails() {
+        let (env, admin, contract_id) = setup();
+
+        let change_id = schedule(&env, &contract_id, &admin, Some(2_000_000), None).unwrap();
+
+        assert_eq!(
+            env.as_contract(&contract_id, || expire_change(&env, change_id)),
+            Err(Error::TimelockNotExpired)
+        );
+    }
+
+    #[test]
+    fn test_content_hash_tracks_payload() {
+        let (env, admin, contract_id) = setup();
+
+        let a = schedule(&env, &contract_id, &admin, Some(2_000_000), None).unwrap();
+        let b = schedule(&env, &contract_id, &admin, Some(2_000_000), None).unwrap();
+        let c = schedule(&env, &contract_id, &admin, Some(3_000_000), None).unwrap();
+
+        let hash_a = pending(&env, &contract_id, a).unwrap().content_hash;
+        let hash_b = pending(&env, &contract_id, b).unwrap().content_hash;
+        let hash_c = pending(&env, &contract_id, c).unwrap().content_hash;
+
+        assert_eq!(hash_a, hash_b);
+        assert_ne!(hash_a, hash_c);
+    }
+
+    #[test]
+    fn test_list_pending_changes_filters_and_pages() {
+        let (env, admin, contract_id) = setup();
+
+        for fee in 1..=5 {
+            schedule(&env, &contract_id, &admin, Some(fee), None).unwrap();
+        }
+        cancel(&env, &contract_id, &admin, 1).unwrap();
+
+        let page = env.as_contract(&contract_id, || {
+            list_pending_changes(&env, Some(ChangeStatus::Pending), 0, 2)
+        });
+        assert_eq!(page.changes.len(), 2);
+        assert_eq!(page.changes.get(0).unwrap().id, 0);
+        assert_eq!(page.changes.get(1).unwrap().id, 2);
+        assert_eq!(page.next_cursor, Some(3));
+
+        let page = env.as_contract(&contract_id, || {
+            list_pending_changes(&env, Some(ChangeStatus::Pending), 3, 10)
+        });
+        assert_eq!(page.changes.len(), 2);
+        assert_eq!(page.next_cursor, None);
+
+        let cancelled = env.as_contract(&contract_id, || {
+            list_pending_changes(&env, Some(ChangeStatus::Cancelled), 0, 10)
+        });
+        assert_eq!(cancelled.changes.len(), 1);
+        assert_eq!(cancelled.changes.get(0).unwrap().id, 1);
+
+        let all = env.as_contract(&contract_id, || list_pending_changes(&env, None, 0, 10));
+        assert_eq!(all.changes.len(), 5);
+    }
+
+    #[test]
+    fn test_set_change_expiry() {
+        let (env, admin, contract_id) = setup();
+
+        env.as_contract(&contract_id, || set_change_expiry(&env, &admin, 60)).unwrap();
+        let change_id = schedule(&env, &contract_id, &admin, Some(2_000_000), None).unwrap();
+        let change = pending(&env, &contract_id, change_id).unwrap();
+        assert_eq!(change.expires_at, change.execute_at + 60);
+
+        assert_eq!(
+            env.as_contract(&contract_id, || set_change_expiry(&env, &admin, 0)),
+            Err(Error::InvalidParameters)
+        );
+        let other = Address::generate(&env);
+        assert_eq!(
+            env.as_contract(&contract_id, || set_change_expiry(&env, &other, 60)),
+            Err(Error::Unauthorized)
+        );
+    }
+
+    #[test]
+    fn test_changes_scheduled_before_upgrade_stay_usable() {
+        use crate::types::{DataKey, LegacyPendingChange, LegacyTimelockConfig};
+
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::TokenFactory, ());
+        let admin = Address::generate(&env);
+        let legacy = |id: u64| LegacyPendingChange {
+            id,
+            change_type: ChangeType::FeeUpdate,
+            scheduled_by: admin.clone(),
+            scheduled_at: 0,
+            execute_at: 3600,
+            executed: false,
+            base_fee: Some(2_000_000),
+            metadata_fee: None,
+            paused: None,
+            treasury: None,
+        };
+        env.as_contract(&contract_id, || {
+            storage::set_admin(&env, &admin);
+            storage::set_base_fee(&env, 1_000_000);
+            storage::set_metadata_fee(&env, 500_000);
+            let store = env.storage();
+            store.instance().set(
+                &DataKey::TimelockConfig,
+                &LegacyTimelockConfig {
+                    delay_seconds: 3600,
+                    enabled: true,
+                },
+            );
+            store.instance().set(&DataKey::NextChangeId, &2_u64);
+            store.persistent().set(&DataKey::PendingChange(0), &legacy(0));
+            store.persistent().set(&DataKey::PendingChange(1), &legacy(1));
+        });
+
+        let config = env.as_contract(&contract_id, || get_timelock_config(&env));
+        assert_eq!((config.delay_seconds, config.expiry_seconds), (3600, DEFAULT_CHANGE_EXPIRY));
+
+        let change = pending(&env, &contract_id, 0).unwrap();
+        assert_eq!(change.status, ChangeStatus::Pending);
+        assert_eq!(change.expires_at, 3600 + DEFAULT_CHANGE_EXPIRY);
+        let fresh = schedule(&env, &contract_id, &admin, Some(2_000_000), None).unwrap();
+        assert_eq!(change.content_hash, pending(&env, &contract_id, fresh).unwrap().content_hash);
+
+        env.ledger().with_mut(|li| li.timestamp = 3600);
+        execute(&env, &contract_id, 0).unwrap();
+        cancel(&env, &contract_id, &admin, 1).unwrap();
+        assert_eq!(pending(&env, &contract_id, 0).unwrap().status, ChangeStatus::Executed);
+        assert_eq!(pending(&env, &contract_id, 1).unwrap().status, ChangeStatus::Cancelled);
+        assert_eq!(env.as_contract(&contract_id, || storage::get_base_fee(&env)), 2_000_000);
+
+        let page = env.as_contract(&contract_id, || {
+            list_pending_changes(&env, None, 0, 10)
+        });
+        assert_eq!(page.changes.len(), 3);
     }
 }
 
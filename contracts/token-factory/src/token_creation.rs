@@ -1,5 +1,8 @@
 use soroban_sdk::{Address, Env, String, Vec};
-use crate::types::{Error, TokenCreationParams, TokenInfo};
+use crate::types::{
+    ComplianceMode, Error, SymbolPolicy, TokenCreationParams, TokenInfo, TransferFeeConfig,
+    PAUSE_CREATE,
+};
 use crate::storage;
 
 /// Validate token creation parameters
@@ -58,6 +61,8 @@ pub fn create_token_internal(
         params.decimals,
         params.initial_supply,
     )?;
+    let normalized_symbol = crate::symbol_registry::normalize_symbol(env, &params.symbol)?;
+    crate::symbol_registry::check_available(env, creator, &normalized_symbol)?;
 
     // Generate token address (placeholder - in production this would deploy actual token contract)
     // For now, we create a deterministic address based on token index
@@ -72,7 +77,7 @@ pub fn create_token_internal(
         decimals: params.decimals,
         total_supply: params.initial_supply,
         initial_supply: params.initial_supply,
-        max_supply: None,
+        max_supply: params.max_supply,
         metadata_uri: params.metadata_uri.clone(),
         created_at: env.ledger().timestamp(),
         total_burned: 0,
@@ -85,6 +90,14 @@ pub fn create_token_internal(
     // Store token info
     storage::set_token_info(env, token_index, &token_info);
     storage::set_token_info_by_address(env, &token_address, &token_info);
+    storage::set_token_index_by_address(env, &token_address, token_index);
+    crate::symbol_registry::register(env, &normalized_symbol, token_index);
+    if params.compliance_mode != ComplianceMode::Open {
+        storage::set_compliance_mode(env, token_index, params.compliance_mode);
+    }
+    if params.transfer_fee.fee_bps > 0 {
+        storage::set_transfer_fee(env, token_index, &params.transfer_fee);
+    }
 
     // Set initial balance for creator
     storage::set_balance(env, token_index, creator, params.initial_supply);
@@ -114,8 +127,8 @@ pub fn create_token(
     metadata_uri: Option<String>,
     fee_payment: i128,
 ) -> Result<Address, Error> {
-    // Check if paused
-    if storage::is_paused(env) {
+    // Check if token creation is paused
+    if storage::is_operation_paused(env, PAUSE_CREATE) {
         return Err(Error::ContractPaused);
     }
 
@@ -139,6 +152,8 @@ pub fn create_token(
         initial_supply,
         max_supply: None,
         metadata_uri,
+        compliance_mode: ComplianceMode::Open,
+        transfer_fee: TransferFeeConfig::none(env),
     };
 
     // Create token
@@ -185,8 +200,8 @@ pub fn batch_create_tokens(
     tokens: Vec<TokenCreationParams>,
     total_fee_payment: i128,
 ) -> Result<Vec<Address>, Error> {
-    // Check if paused
-    if storage::is_paused(env) {
+    // Check if token creation is paused
+    if storage::is_operation_paused(env, PAUSE_CREATE) {
         return Err(Error::ContractPaused);
     }
 
@@ -200,6 +215,8 @@ pub fn batch_create_tokens(
 
     // Phase 1: Validate all tokens before any state changes (atomic semantics)
     let mut total_required_fee = 0i128;
+    let first_come = storage::get_symbol_policy(env) == SymbolPolicy::FirstCome;
+    let mut batch_symbols: Vec<String> = Vec::new(env);
     for token in tokens.iter() {
         // Validate each token's parameters
         validate_token_params(
@@ -208,6 +225,16 @@ pub fn batch_create_tokens(
             token.decimals,
             token.initial_supply,
         )?;
+        crate::mint::validate_max_supply_at_creation(token.initial_supply, token.max_supply)?;
+        crate::transfer::validate_fee_config(&token.transfer_fee)?;
+
+        // Symbol must be available, including against earlier tokens in this batch
+        let normalized_symbol = crate::symbol_registry::normalize_symbol(env, &token.symbol)?;
+        crate::symbol_registry::check_available(env, &creator, &normalized_symbol)?;
+        if first_come && batch_symbols.contains(&normalized_symbol) {
+            return Err(Error::InvalidTokenParams);
+        }
+        batch_symbols.push_back(normalized_symbol);
 
         // Calculate fee for this token
         let token_fee = calculate_creation_fee(env, token.metadata_uri.is_some());
@@ -370,6 +397,8 @@ mod tests {
             initial_supply: 1_000_000,
             max_supply: None,
             metadata_uri: None,
+            compliance_mode: ComplianceMode::Open,
+            transfer_fee: TransferFeeConfig::none(&env),
         };
         let token_b = TokenCreationParams {
             name: String::from_str(&env, "Beta"),
@@ -378,6 +407,8 @@ mod tests {
             initial_supply: 2_000_000,
             max_supply: None,
             metadata_uri: None,
+            compliance_mode: ComplianceMode::Open,
+            transfer_fee: TransferFeeConfig::none(&env),
         };
 
         let batch = soroban_sdk::vec![&env, token_a, token_b];
@@ -414,6 +445,8 @@ mod tests {
             initial_supply: 1_000_000,
             max_supply: None,
             metadata_uri: None,
+            compliance_mode: ComplianceMode::Open,
+            transfer_fee: TransferFeeConfig::none(&env),
         };
         let invalid = TokenCreationParams {
             name: String::from_str(&env, ""), // invalid -> forces rollback path
@@ -422,6 +455,8 @@ mod tests {
             initial_supply: 1_000_000,
             max_supply: None,
             metadata_uri: None,
+            compliance_mode: ComplianceMode::Open,
+            transfer_fee: TransferFeeConfig::none(&env),
         };
 
         let batch = soroban_sdk::vec![&env, valid, invalid];
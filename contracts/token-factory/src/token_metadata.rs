@@ -0,0 +1,139 @@
+use crate::storage;
+use crate::types::{Error, MetadataVersion, TokenMetadata};
+use soroban_sdk::{xdr::ToXdr, Address, BytesN, Env, Vec};
+
+/// Maximum description length in bytes
+pub const MAX_DESCRIPTION_LEN: u32 = 512;
+/// Maximum length of the website, social links and metadata URI
+pub const MAX_LINK_LEN: u32 = 256;
+/// Maximum number of social links
+pub const MAX_SOCIALS: u32 = 10;
+/// Maximum page size for metadata history queries
+pub const MAX_HISTORY_PAGE_SIZE: u32 = 50;
+
+/// Publish a new metadata version for a token
+///
+/// The caller must pass `metadata.version == current + 1` (1 for the first
+/// update) so concurrent updates cannot silently overwrite each other.
+/// The token's `metadata_uri` is kept in sync with the new record.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `creator` - Token creator address (must authorize)
+/// * `token_index` - Index of the token
+/// * `metadata` - New metadata content
+/// * `fee_payment` - Fee paid (must be >= metadata_fee)
+///
+/// # Returns
+/// * `Ok(content_hash)` - Hash recorded for the new version
+/// * `Err(Error::TokenNotFound)` - Token doesn't exist
+/// * `Err(Error::Unauthorized)` - Caller is not the token creator
+/// * `Err(Error::InsufficientFee)` - Fee payment below metadata_fee
+/// * `Err(Error::InvalidParameters)` - Field too long, too many socials or wrong version
+pub fn update_token_metadata(
+    env: &Env,
+    creator: &Address,
+    token_index: u32,
+    metadata: &TokenMetadata,
+    fee_payment: i128,
+) -> Result<BytesN<32>, Error> {
+    creator.require_auth();
+
+    let mut token_info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+    if token_info.creator != *creator {
+        return Err(Error::Unauthorized);
+    }
+
+    if fee_payment < storage::get_metadata_fee(env) {
+        return Err(Error::InsufficientFee);
+    }
+
+    validate_metadata(metadata)?;
+
+    let current_version = storage::get_token_metadata(env, token_index)
+        .map(|m| m.version)
+        .unwrap_or(0);
+    if metadata.version != current_version + 1 {
+        return Err(Error::InvalidParameters);
+    }
+
+    let content_hash = compute_content_hash(env, metadata);
+
+    storage::set_token_metadata(env, token_index, metadata);
+    storage::set_metadata_version(
+        env,
+        token_index,
+        &MetadataVersion {
+            version: metadata.version,
+            content_hash: content_hash.clone(),
+            updated_by: creator.clone(),
+            updated_at: env.ledger().timestamp(),
+        },
+    );
+
+    // Keep the legacy URI field in sync
+    token_info.metadata_uri = Some(metadata.metadata_uri.clone());
+    storage::update_token_info(env, token_index, &token_info);
+    storage::set_token_info_by_address(env, &token_info.address, &token_info);
+
+    crate::events::emit_token_metadata_updated(env, token_index, metadata.version, &content_hash);
+
+    Ok(content_hash)
+}
+
+/// List metadata versions of a token, oldest first
+///
+/// `cursor` is the first version to return (versions start at 1).
+/// `limit` is capped at 50.
+pub fn get_metadata_history(
+    env: &Env,
+    token_index: u32,
+    cursor: u32,
+    limit: u32,
+) -> Vec<MetadataVersion> {
+    let mut history = Vec::new(env);
+    let latest = storage::get_token_metadata(env, token_index)
+        .map(|m| m.version)
+        .unwrap_or(0);
+    let limit = limit.min(MAX_HISTORY_PAGE_SIZE);
+
+    let mut version = cursor.max(1);
+    while version <= latest && history.len() < limit {
+        if let Some(record) = storage::get_metadata_version(env, token_index, version) {
+            history.push_back(record);
+        }
+        version += 1;
+    }
+
+    history
+}
+
+/// Hash the metadata content (excluding the version counter)
+pub fn compute_content_hash(env: &Env, metadata: &TokenMetadata) -> BytesN<32> {
+    let payload = (
+        metadata.description.clone(),
+        metadata.website.clone(),
+        metadata.logo_hash.clone(),
+        metadata.socials.clone(),
+        metadata.metadata_uri.clone(),
+    );
+    env.crypto().sha256(&payload.to_xdr(env)).into()
+}
+
+fn validate_metadata(metadata: &TokenMetadata) -> Result<(), Error> {
+    if metadata.description.len() > MAX_DESCRIPTION_LEN
+        || metadata.website.len() > MAX_LINK_LEN
+        || metadata.metadata_uri.len() > MAX_LINK_LEN
+        || metadata.socials.len() > MAX_SOCIALS
+    {
+        return Err(Error::InvalidParameters);
+    }
+
+    for social in metadata.socials.iter() {
+        if social.len() > MAX_LINK_LEN {
+            return Err(Error::InvalidParameters);
+        }
+    }
+
+    Ok(())
+}
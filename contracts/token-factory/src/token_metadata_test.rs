@@ -0,0 +1,160 @@
+//! Token Metadata Tests
+//!
+//! Tests for structured, versioned token metadata updates
+
+use soroban_sdk::{testutils::Address as _, vec, Address, BytesN, Env, String, Vec};
+
+use crate::test_helpers::{create_token, TestEnv};
+use crate::types::{Error, TokenMetadata};
+use crate::TokenFactoryClient;
+
+fn setup() -> (Env, TokenFactoryClient<'static>, Address) {
+    let TestEnv { env, client, .. } = TestEnv::initialized();
+
+    let creator = Address::generate(&env);
+    create_token(&client, &creator, "META", 1_000);
+
+    (env, client, creator)
+}
+
+fn metadata(env: &Env, version: u32, uri: &str) -> TokenMetadata {
+    TokenMetadata {
+        description: String::from_str(env, "A token with rich metadata"),
+        website: String::from_str(env, "https://example.com"),
+        logo_hash: BytesN::from_array(env, &[7u8; 32]),
+        socials: vec![env, String::from_str(env, "https://x.com/meta")],
+        metadata_uri: String::from_str(env, uri),
+        version,
+    }
+}
+
+#[test]
+fn test_first_update_creates_version_one() {
+    let (env, client, creator) = setup();
+
+    let hash = client.update_token_metadata(
+        &creator,
+        &0,
+        &metadata(&env, 1, "ipfs://QmFirst"),
+        &50_000_000,
+    );
+
+    assert_eq!(client.get_token_metadata(&0).unwrap().version, 1);
+    let record = client.get_metadata_version(&0, &1).unwrap();
+    assert_eq!(record.content_hash, hash);
+    assert_eq!(record.updated_by, creator);
+    assert_eq!(
+        client.get_token_info(&0).metadata_uri,
+        Some(String::from_str(&env, "ipfs://QmFirst"))
+    );
+}
+
+#[test]
+fn test_update_fixes_uri_after_legacy_set() {
+    let (env, client, creator) = setup();
+
+    client.set_token_metadata(&creator, &0, &String::from_str(&env, "ipfs://QmTypo"));
+    client.update_token_metadata(
+        &creator,
+        &0,
+        &metadata(&env, 1, "ipfs://QmFixed"),
+        &50_000_000,
+    );
+
+    assert_eq!(
+        client.get_token_info(&0).metadata_uri,
+        Some(String::from_str(&env, "ipfs://QmFixed"))
+    );
+}
+
+#[test]
+fn test_version_must_increment_by_one() {
+    let (env, client, creator) = setup();
+
+    assert_eq!(
+        client.try_update_token_metadata(
+            &creator,
+            &0,
+            &metadata(&env, 2, "ipfs://Qm"),
+            &50_000_000
+        ),
+        Err(Ok(Error::InvalidParameters))
+    );
+
+    client.update_token_metadata(&creator, &0, &metadata(&env, 1, "ipfs://Qm1"), &50_000_000);
+    assert_eq!(
+        client.try_update_token_metadata(
+            &creator,
+            &0,
+            &metadata(&env, 1, "ipfs://Qm"),
+            &50_000_000
+        ),
+        Err(Ok(Error::InvalidParameters))
+    );
+    client.update_token_metadata(&creator, &0, &metadata(&env, 2, "ipfs://Qm2"), &50_000_000);
+}
+
+#[test]
+fn test_history_keeps_every_hash() {
+    let (env, client, creator) = setup();
+
+    let first =
+        client.update_token_metadata(&creator, &0, &metadata(&env, 1, "ipfs://Qm1"), &50_000_000);
+    let second =
+        client.update_token_metadata(&creator, &0, &metadata(&env, 2, "ipfs://Qm2"), &50_000_000);
+    assert_ne!(first, second);
+
+    let history = client.get_metadata_history(&0, &0, &10);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(0).unwrap().content_hash, first);
+    assert_eq!(history.get(1).unwrap().content_hash, second);
+
+    let page = client.get_metadata_history(&0, &2, &10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().version, 2);
+
+    // Updates do not add the token to its creator's index again
+    assert_eq!(client.get_creator_token_count(&creator), 1);
+}
+
+#[test]
+fn test_only_creator_with_fee_can_update() {
+    let (env, client, creator) = setup();
+
+    assert_eq!(
+        client.try_update_token_metadata(
+            &Address::generate(&env),
+            &0,
+            &metadata(&env, 1, "ipfs://Qm"),
+            &50_000_000
+        ),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_update_token_metadata(&creator, &0, &metadata(&env, 1, "ipfs://Qm"), &1),
+        Err(Ok(Error::InsufficientFee))
+    );
+}
+
+#[test]
+fn test_oversized_fields_rejected() {
+    let (env, client, creator) = setup();
+
+    let mut too_many_socials = metadata(&env, 1, "ipfs://Qm");
+    let mut socials = Vec::new(&env);
+    for _ in 0..11 {
+        socials.push_back(String::from_str(&env, "https://x.com/meta"));
+    }
+    too_many_socials.socials = socials;
+    assert_eq!(
+        client.try_update_token_metadata(&creator, &0, &too_many_socials, &50_000_000),
+        Err(Ok(Error::InvalidParameters))
+    );
+
+    let mut long_description = metadata(&env, 1, "ipfs://Qm");
+    long_description.description = String::from_str(&env, &"d".repeat(513));
+    assert_eq!(
+        client.try_update_token_metadata(&creator, &0, &long_description, &50_000_000),
+        Err(Ok(Error::InvalidParameters))
+    );
+}
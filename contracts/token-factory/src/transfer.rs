@@ -0,0 +1,215 @@
+use crate::storage;
+use crate::types::{Error, TransferFeeConfig, PAUSE_TRANSFER};
+use soroban_sdk::{Address, Env};
+
+/// Maximum transfer tax: 1000 bps = 10%
+pub const MAX_TRANSFER_FEE_BPS: u32 = 1_000;
+/// Maximum number of fee-exempt addresses per token
+pub const MAX_FEE_EXEMPT: u32 = 20;
+
+const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Validate a transfer tax configuration supplied at token creation
+///
+/// # Returns
+/// * `Ok(())` - Configuration is valid
+/// * `Err(Error::InvalidTokenParams)` - Fee above the cap or too many exempt addresses
+pub fn validate_fee_config(config: &TransferFeeConfig) -> Result<(), Error> {
+    if config.fee_bps > MAX_TRANSFER_FEE_BPS || config.exempt.len() > MAX_FEE_EXEMPT {
+        return Err(Error::InvalidTokenParams);
+    }
+    Ok(())
+}
+
+/// Compute the transfer tax on `amount`
+///
+/// Rounds down: `fee = amount * fee_bps / 10_000`. The recipient gets
+/// `amount - fee`, so no unit is created or lost.
+///
+/// # Returns
+/// * `Ok(fee)` - Fee to route to the fee recipient
+/// * `Err(Error::ArithmeticError)` - Overflow in calculation
+pub fn compute_fee(amount: i128, fee_bps: u32) -> Result<i128, Error> {
+    amount
+        .checked_mul(fee_bps as i128)
+        .map(|scaled| scaled / BPS_DENOMINATOR)
+        .ok_or(Error::ArithmeticError)
+}
+
+/// Transfer tokens between holders
+///
+/// # Returns
+/// * `Ok(())` - Transfer successful
+/// * `Err(Error::ContractPaused)` - Transfers are paused
+/// * `Err(Error::TokenNotFound)` - Token doesn't exist
+/// * `Err(Error::TokenPaused)` - Token is paused
+/// * `Err(Error::InvalidAmount)` - Amount is zero or negative
+/// * `Err(Error::RecipientNotAllowed)` - Recipient fails the token's compliance mode
+/// * `Err(Error::InsufficientBalance)` - Unstaked sender balance is less than amount
+pub fn transfer(
+    env: &Env,
+    from: &Address,
+    token_index: u32,
+    to: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    from.require_auth();
+    transfer_internal(env, from, token_index, to, amount)
+}
+
+/// Set the amount `spender` may move out of `owner`'s balance
+///
+/// Replaces any previous allowance; 0 revokes it.
+///
+/// # Returns
+/// * `Ok(())` - Allowance set
+/// * `Err(Error::TokenNotFound)` - Token doesn't exist
+/// * `Err(Error::InvalidAmount)` - Amount is negative
+pub fn approve(
+    env: &Env,
+    owner: &Address,
+    token_index: u32,
+    spender: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    owner.require_auth();
+
+    storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+
+    if amount < 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    storage::set_allowance(env, token_index, owner, spender, amount);
+
+    crate::events::emit_approve(env, token_index, owner, spender, amount);
+
+    Ok(())
+}
+
+/// Transfer tokens on behalf of `from` using the spender's allowance
+///
+/// The allowance is charged the gross amount, tax included.
+///
+/// # Returns
+/// Same as [`transfer`], plus:
+/// * `Err(Error::InsufficientBalance)` - Allowance is less than amount
+pub fn transfer_from(
+    env: &Env,
+    spender: &Address,
+    token_index: u32,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    spender.require_auth();
+
+    let allowance = storage::get_allowance(env, token_index, from, spender);
+    if amount > allowance {
+        return Err(Error::InsufficientBalance);
+    }
+
+    transfer_internal(env, from, token_index, to, amount)?;
+
+    storage::set_allowance(env, token_index, from, spender, allowance - amount);
+
+    Ok(())
+}
+
+fn transfer_internal(
+    env: &Env,
+    from: &Address,
+    token_index: u32,
+    to: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    if storage::is_operation_paused(env, PAUSE_TRANSFER) {
+        return Err(Error::ContractPaused);
+    }
+
+    let token_info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+
+    if storage::is_token_paused(env, token_index) {
+        return Err(Error::TokenPaused);
+    }
+
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    crate::compliance::require_eligible(env, token_index, to)?;
+
+    let from_balance = storage::get_balance(env, token_index, from);
+    if storage::get_transferable_balance(env, token_index, from) < amount {
+        return Err(Error::InsufficientBalance);
+    }
+
+    // Resolve transfer tax
+    let mut fee = 0i128;
+    let mut fee_recipient = token_info.creator.clone();
+    if let Some(config) = storage::get_transfer_fee(env, token_index) {
+        if let Some(recipient) = config.fee_recipient.clone() {
+            fee_recipient = recipient;
+        }
+        let exempt = config.exempt.contains(from)
+            || config.exempt.contains(to)
+            || *from == fee_recipient
+            || *to == fee_recipient;
+        if !exempt {
+            fee = compute_fee(amount, config.fee_bps)?;
+        }
+    }
+    if fee > 0 {
+        crate::compliance::require_eligible(env, token_index, &fee_recipient)?;
+    }
+    let net = amount - fee;
+
+    // Apply balance changes; self-transfers net out to only the fee
+    storage::set_balance(env, token_index, from, from_balance - amount);
+
+    let to_balance = storage::get_balance(env, token_index, to);
+    let new_to_balance = to_balance.checked_add(net).ok_or(Error::ArithmeticError)?;
+    storage::set_balance(env, token_index, to, new_to_balance);
+
+    if fee > 0 {
+        let recipient_balance = storage::get_balance(env, token_index, &fee_recipient);
+        let new_recipient_balance = recipient_balance
+            .checked_add(fee)
+            .ok_or(Error::ArithmeticError)?;
+        storage::set_balance(env, token_index, &fee_recipient, new_recipient_balance);
+
+        let collected = storage::get_fees_collected(env, token_index)
+            .checked_add(fee)
+            .ok_or(Error::ArithmeticError)?;
+        storage::set_fees_collected(env, token_index, collected);
+    }
+
+    crate::events::emit_transfer(env, token_index, from, to, amount, fee);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compute_fee_rounds_down() {
+        // 2.5% of 999 = 24.975 -> 24
+        assert_eq!(compute_fee(999, 250), Ok(24));
+        // Below one unit of fee -> 0
+        assert_eq!(compute_fee(39, 250), Ok(0));
+    }
+
+    #[test]
+    fn test_compute_fee_exact() {
+        assert_eq!(compute_fee(10_000, 100), Ok(100));
+        assert_eq!(compute_fee(10_000, 0), Ok(0));
+        assert_eq!(compute_fee(10_000, MAX_TRANSFER_FEE_BPS), Ok(1_000));
+    }
+
+    #[test]
+    fn test_compute_fee_overflow() {
+        assert_eq!(compute_fee(i128::MAX, 2), Err(Error::ArithmeticError));
+    }
+}
@@ -0,0 +1,202 @@
+//! Transfer Tax Tests
+//!
+//! Tests for transfer / transfer_from with an optional per-token fee
+
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, Vec};
+
+use crate::test_helpers::{create_tokens, token_params, TestEnv, BASE_FEE};
+use crate::types::{
+    ComplianceMode, Error, HolderList, TokenCreationParams, TransferFeeConfig, PAUSE_TRANSFER,
+};
+use crate::TokenFactoryClient;
+
+fn setup(
+    transfer_fee: impl FnOnce(&Env) -> TransferFeeConfig,
+) -> (Env, TokenFactoryClient<'static>, Address, Address) {
+    let TestEnv {
+        env, client, admin, ..
+    } = TestEnv::initialized();
+
+    let creator = Address::generate(&env);
+    let params = TokenCreationParams {
+        transfer_fee: transfer_fee(&env),
+        ..token_params(&env, "TAX", 1_000_000)
+    };
+    create_tokens(&client, &creator, vec![&env, params]);
+
+    (env, client, admin, creator)
+}
+
+fn fee_config(env: &Env, fee_bps: u32, fee_recipient: Option<Address>) -> TransferFeeConfig {
+    TransferFeeConfig {
+        fee_bps,
+        fee_recipient,
+        exempt: Vec::new(env),
+    }
+}
+
+/// Fund a non-exempt holder so taxed transfers can be exercised
+fn funded_holder(env: &Env, client: &TokenFactoryClient, creator: &Address) -> Address {
+    let holder = Address::generate(env);
+    client.mint(creator, &0, &holder, &100_000);
+    holder
+}
+
+#[test]
+fn test_transfer_without_fee() {
+    let (env, client, _admin, creator) = setup(TransferFeeConfig::none);
+    let to = Address::generate(&env);
+
+    client.transfer(&creator, &0, &to, &1_000);
+
+    assert_eq!(client.get_balance(&0, &to), 1_000);
+    assert_eq!(client.get_balance(&0, &creator), 999_000);
+    assert_eq!(client.get_token_stats(&0).total_fees_collected, 0);
+}
+
+#[test]
+fn test_transfer_tax_to_creator() {
+    let (env, client, _admin, creator) = setup(|env| fee_config(env, 250, None));
+    let holder = funded_holder(&env, &client, &creator);
+    let to = Address::generate(&env);
+
+    client.transfer(&holder, &0, &to, &999);
+
+    // 2.5% of 999 rounds down to 24
+    assert_eq!(client.get_balance(&0, &to), 975);
+    assert_eq!(client.get_balance(&0, &holder), 100_000 - 999);
+    assert_eq!(client.get_balance(&0, &creator), 1_000_000 + 24);
+    assert_eq!(client.get_token_stats(&0).total_fees_collected, 24);
+}
+
+#[test]
+fn test_transfer_tax_to_designated_wallet() {
+    let mut wallet = None;
+    let (env, client, _admin, creator) = setup(|env| {
+        wallet = Some(Address::generate(env));
+        fee_config(env, 100, wallet.clone())
+    });
+    let wallet = wallet.unwrap();
+    let holder = funded_holder(&env, &client, &creator);
+    let to = Address::generate(&env);
+
+    client.transfer(&holder, &0, &to, &10_000);
+
+    assert_eq!(client.get_balance(&0, &wallet), 100);
+    assert_eq!(client.get_balance(&0, &to), 9_900);
+}
+
+#[test]
+fn test_exempt_addresses_are_not_taxed() {
+    let mut exempt = None;
+    let (env, client, _admin, creator) = setup(|env| {
+        let address = Address::generate(env);
+        exempt = Some(address.clone());
+        TransferFeeConfig {
+            fee_bps: 500,
+            fee_recipient: None,
+            exempt: vec![env, address],
+        }
+    });
+    let exempt = exempt.unwrap();
+    let holder = funded_holder(&env, &client, &creator);
+
+    client.transfer(&holder, &0, &exempt, &1_000);
+    assert_eq!(client.get_balance(&0, &exempt), 1_000);
+
+    // The fee recipient (creator) is implicitly exempt
+    let to = Address::generate(&env);
+    client.transfer(&creator, &0, &to, &1_000);
+    assert_eq!(client.get_balance(&0, &to), 1_000);
+    assert_eq!(client.get_token_stats(&0).total_fees_collected, 0);
+}
+
+#[test]
+fn test_fee_above_cap_rejected_at_creation() {
+    let TestEnv { env, client, .. } = TestEnv::initialized();
+
+    let params = TokenCreationParams {
+        transfer_fee: fee_config(&env, 1_001, None),
+        ..token_params(&env, "GRD", 1_000_000)
+    };
+    assert_eq!(
+        client.try_set_metadata(&Address::generate(&env), &vec![&env, params], &BASE_FEE),
+        Err(Ok(Error::InvalidTokenParams))
+    );
+}
+
+#[test]
+fn test_transfer_from_consumes_allowance() {
+    let (env, client, _admin, creator) = setup(|env| fee_config(env, 1_000, None));
+    let holder = funded_holder(&env, &client, &creator);
+    let spender = Address::generate(&env);
+    let to = Address::generate(&env);
+
+    client.approve(&holder, &0, &spender, &5_000);
+    client.transfer_from(&spender, &0, &holder, &to, &2_000);
+
+    assert_eq!(client.get_allowance(&0, &holder, &spender), 3_000);
+    assert_eq!(client.get_balance(&0, &to), 1_800);
+    assert_eq!(client.get_token_stats(&0).total_fees_collected, 200);
+
+    assert_eq!(
+        client.try_transfer_from(&spender, &0, &holder, &to, &3_001),
+        Err(Ok(Error::InsufficientBalance))
+    );
+}
+
+#[test]
+fn test_transfer_insufficient_balance() {
+    let (env, client, _admin, _creator) = setup(TransferFeeConfig::none);
+    let poor = Address::generate(&env);
+
+    assert_eq!(
+        client.try_transfer(&poor, &0, &Address::generate(&env), &1),
+        Err(Ok(Error::InsufficientBalance))
+    );
+}
+
+#[test]
+fn test_transfer_respects_pause_flag_and_compliance() {
+    let (env, client, admin, creator) = setup(TransferFeeConfig::none);
+    let to = Address::generate(&env);
+
+    client.set_pause_flags(&admin, &PAUSE_TRANSFER);
+    assert_eq!(
+        client.try_transfer(&creator, &0, &to, &1),
+        Err(Ok(Error::ContractPaused))
+    );
+    client.set_pause_flags(&admin, &0);
+
+    client.add_holders(&creator, &0, &HolderList::Denylist, &vec![&env, to.clone()]);
+    client.set_compliance_mode(&creator, &0, &ComplianceMode::DenylistOnly);
+    assert_eq!(
+        client.try_transfer(&creator, &0, &to, &1),
+        Err(Ok(Error::RecipientNotAllowed))
+    );
+}
+
+#[test]
+fn test_fee_recipient_must_be_eligible() {
+    let mut wallet = None;
+    let (env, client, _admin, creator) = setup(|env| {
+        wallet = Some(Address::generate(env));
+        fee_config(env, 100, wallet.clone())
+    });
+    let wallet = wallet.unwrap();
+    let holder = funded_holder(&env, &client, &creator);
+    let to = Address::generate(&env);
+
+    client.add_holders(
+        &creator,
+        &0,
+        &HolderList::Denylist,
+        &vec![&env, wallet.clone()],
+    );
+    client.set_compliance_mode(&creator, &0, &ComplianceMode::DenylistOnly);
+    assert_eq!(
+        client.try_transfer(&holder, &0, &to, &10_000),
+        Err(Ok(Error::RecipientNotAllowed))
+    );
+    assert_eq!(client.get_balance(&0, &to), 0);
+}
@@ -1,5 +1,5 @@
 use crate::storage;
-use crate::types::{Error, TreasuryPolicy, WithdrawalPeriod};
+use crate::types::{Error, TreasuryPolicy, WithdrawalPeriod, PAUSE_TREASURY_WITHDRAW};
 use soroban_sdk::{Address, Env};
 
 /// Default daily withdrawal cap (100 XLM in stroops)
@@ -159,6 +159,7 @@ pub fn record_withdrawal(env: &Env, amount: i128) -> Result<(), Error> {
 ///
 /// # Returns
 /// * `Ok(())` - Withdrawal successful
+/// * `Err(Error::ContractPaused)` - Treasury withdrawals are paused
 /// * `Err(Error::Unauthorized)` - Caller is not admin
 /// * `Err(Error::WithdrawalCapExceeded)` - Exceeds daily cap
 /// * `Err(Error::RecipientNotAllowed)` - Recipient not allowed
@@ -171,6 +172,10 @@ pub fn withdraw_fees(
 ) -> Result<(), Error> {
     admin.require_auth();
 
+    if storage::is_operation_paused(env, PAUSE_TREASURY_WITHDRAW) {
+        return Err(Error::ContractPaused);
+    }
+
     // Verify admin
     let current_admin = storage::get_admin(env);
     if *admin != current_admin {
@@ -1,6 +1,6 @@
 #![allow(dead_code)]
 
-use soroban_sdk::{self, contracterror, contracttype, Address, Bytes, BytesN, String, Vec};
+use soroban_sdk::{self, contracterror, contracttype, Address, Bytes, BytesN, Env, String, Vec};
 
 /// Factory state containing administrative configuration
 ///
@@ -23,6 +23,26 @@ pub struct FactoryState {
     pub paused: bool,
 }
 
+/// Pending two-step admin handover offer
+///
+/// Created by `propose_admin` and consumed by `accept_admin`. An offer
+/// can only be accepted until `expires_at` and only while `proposed_by`
+/// is still the admin.
+///
+/// # Fields
+/// * `proposed_admin` - Address that may accept the admin role
+/// * `proposed_by` - Admin that made the offer
+/// * `proposed_at` - Timestamp when the offer was made
+/// * `expires_at` - Last timestamp at which the offer can be accepted
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingAdminOffer {
+    pub proposed_admin: Address,
+    pub proposed_by: Address,
+    pub proposed_at: u64,
+    pub expires_at: u64,
+}
+
 /// Contract metadata for factory identification
 ///
 /// Contains descriptive information about the token factory contract.
@@ -89,6 +109,29 @@ pub struct TokenInfo {
     pub freeze_enabled: bool,
 }
 
+/// Shape of a stream's vesting between `start_time` and `end_time`
+///
+/// Every curve vests nothing at `start_time`, the full amount at
+/// `end_time`, and never decreases in between.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VestingCurve {
+    /// Continuous linear vesting
+    Linear,
+    /// The given number of equal unlocks at regular intervals, the last
+    /// at `end_time`
+    Stepped(u32),
+    /// Equal unlocks on each monthly anniversary of `start_time` (day of
+    /// month clamped to the month's length), the last at `end_time`
+    Monthly,
+    /// Back-loaded vesting proportional to `(elapsed / duration)^n`;
+    /// 2 is quadratic
+    Exponential(u32),
+    /// Linear between `(timestamp, cumulative bps)` breakpoints, with
+    /// `(start_time, 0)` and `(end_time, 10_000)` implied
+    Piecewise(Vec<(u64, u32)>),
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct StreamInfo {
@@ -104,6 +147,26 @@ pub struct StreamInfo {
     pub metadata: Option<String>,
     pub cancelled: bool,
     pub paused: bool,
+    /// Ledger time of the current pause (0 while running)
+    pub paused_at: u64,
+    /// Seconds spent in completed pauses; shifts the schedule later
+    pub total_paused_secs: u64,
+    /// Ledger time the stream was cancelled at (0 while live)
+    pub cancelled_at: u64,
+    /// Vested but unclaimed amount left claimable at cancellation
+    pub settled_amount: i128,
+    /// Unvested amount returned to the creator at cancellation
+    pub refunded_amount: i128,
+    /// Amount already vested at `start_time`, carried over when a top-up
+    /// re-based the schedule (0 for unamended streams)
+    pub vested_base: i128,
+    pub curve: VestingCurve,
+    /// Whether the recipient may hand the stream to another address
+    pub transferable: bool,
+    /// Stream this one was split off from
+    pub parent_id: Option<u64>,
+    /// Streams this one was split into (empty unless split)
+    pub children: Vec<u64>,
 }
 
 #[contracttype]
@@ -115,6 +178,7 @@ pub struct StreamParams {
     pub start_time: u64,
     pub end_time: u64,
     pub cliff_time: u64,
+    pub curve: VestingCurve,
 }
 
 /// Token creation parameters
@@ -127,14 +191,377 @@ pub struct TokenCreationParams {
     pub initial_supply: i128,
     pub max_supply: Option<i128>,
     pub metadata_uri: Option<String>,
+    pub compliance_mode: ComplianceMode,
+    pub transfer_fee: TransferFeeConfig,
+}
+
+/// Per-token transfer tax configuration
+///
+/// # Fields
+/// * `fee_bps` - Fee in basis points of the transferred amount (max 1000 = 10%, 0 = no tax)
+/// * `fee_recipient` - Wallet receiving the fee (`None` = token creator)
+/// * `exempt` - Addresses whose transfers, sent or received, are not taxed (max 20)
+///
+/// The fee is `amount * fee_bps / 10_000` rounded down, so the sender is
+/// never charged more than the configured rate and `fee + net == amount`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferFeeConfig {
+    pub fee_bps: u32,
+    pub fee_recipient: Option<Address>,
+    pub exempt: Vec<Address>,
+}
+
+impl TransferFeeConfig {
+    /// Configuration for an untaxed token
+    pub fn none(env: &Env) -> Self {
+        Self {
+            fee_bps: 0,
+            fee_recipient: None,
+            exempt: Vec::new(env),
+        }
+    }
+}
+
+/// Structured, versioned token metadata
+///
+/// # Fields
+/// * `description` - Free-form project description
+/// * `website` - Project website URL
+/// * `logo_hash` - SHA-256 of the logo image
+/// * `socials` - Social profile links
+/// * `metadata_uri` - Off-chain metadata document (e.g. IPFS)
+/// * `version` - Update counter; must be `current + 1` when updating
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenMetadata {
+    pub description: String,
+    pub website: String,
+    pub logo_hash: BytesN<32>,
+    pub socials: Vec<String>,
+    pub metadata_uri: String,
+    pub version: u32,
+}
+
+/// Historical record of one token metadata version
+///
+/// `content_hash` is the SHA-256 of the XDR-encoded metadata content so
+/// indexers can verify documents they archived for past versions.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MetadataVersion {
+    pub version: u32,
+    pub content_hash: BytesN<32>,
+    pub updated_by: Address,
+    pub updated_at: u64,
+}
+
+/// Holder eligibility policy for a token
+///
+/// * `Open` - Anyone may receive the token
+/// * `AllowlistOnly` - Only holders on the token's allowlist may receive it
+/// * `DenylistOnly` - Anyone except holders on the token's denylist may receive it
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ComplianceMode {
+    Open,
+    AllowlistOnly,
+    DenylistOnly,
+}
+
+/// Creator-managed holder list a compliance entry belongs to
+///
+/// A holder is on at most one list per token; adding it to one list
+/// replaces any entry on the other.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HolderList {
+    Allowlist,
+    Denylist,
+}
+
+/// Symbol uniqueness policy applied at token creation
+///
+/// * `AllowDuplicates` - Any unreserved symbol may be reused; the admin can
+///   mark one token per symbol as verified
+/// * `FirstCome` - A symbol belongs to the first token that registers it
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SymbolPolicy {
+    AllowDuplicates,
+    FirstCome,
+}
+
+/// Merkle airdrop campaign
+///
+/// The creator escrows `total_amount` of the token when the campaign is
+/// created. Recipients claim their leaf with a Merkle proof until
+/// `expires_at`; afterwards the creator can reclaim whatever is left.
+///
+/// # Fields
+/// * `token_index` - Token being distributed
+/// * `creator` - Token creator who funded the campaign
+/// * `merkle_root` - Root over `(index, address, amount)` leaves (see `merkle` module)
+/// * `total_amount` - Amount escrowed at creation
+/// * `claimed_amount` - Amount claimed so far
+/// * `expires_at` - Timestamp after which claims are closed
+/// * `reclaimed` - Whether the creator reclaimed the remainder
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AirdropCampaign {
+    pub token_index: u32,
+    pub creator: Address,
+    pub merkle_root: BytesN<32>,
+    pub total_amount: i128,
+    pub claimed_amount: i128,
+    pub expires_at: u64,
+    pub reclaimed: bool,
+}
+
+/// Value recorded for a snapshot before it was first changed
+///
+/// Written lazily: an account (or the total supply) only gets a
+/// checkpoint when it changes after a snapshot was taken.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Checkpoint {
+    pub snapshot_id: u32,
+    pub value: i128,
+}
+
+/// Pro-rata distribution of a SEP-41 asset to a token's holders
+///
+/// Each holder can claim `amount * balance_at / supply_at` once, where
+/// both values are read at `snapshot_id`. Shares round down; the dust and
+/// unclaimed shares can be swept by the creator after `claim_deadline`.
+///
+/// # Fields
+/// * `token_index` - Token whose holders receive the distribution
+/// * `creator` - Token creator who deposited the asset
+/// * `asset` - SEP-41 asset being distributed
+/// * `amount` - Amount deposited
+/// * `snapshot_id` - Balance snapshot taken at deposit time
+/// * `supply_at` - Token total supply at the snapshot
+/// * `claimed_amount` - Amount claimed so far
+/// * `claim_deadline` - Timestamp after which claims close and sweeping opens
+/// * `swept` - Whether the creator swept the remainder
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Distribution {
+    pub token_index: u32,
+    pub creator: Address,
+    pub asset: Address,
+    pub amount: i128,
+    pub snapshot_id: u32,
+    pub supply_at: i128,
+    pub claimed_amount: i128,
+    pub claim_deadline: u64,
+    pub swept: bool,
+}
+
+/// Staking pool rewarding holders of a factory token
+///
+/// Rewards are paid in `reward_token_index` (which may be the staked token
+/// itself) and accrue through a reward-per-token accumulator scaled by
+/// `staking::REWARD_PRECISION`.
+///
+/// # Fields
+/// * `token_index` - Token that is staked
+/// * `reward_token_index` - Token rewards are paid in
+/// * `creator` - Creator of the staked token who funds the rewards
+/// * `reward_rate` - Reward units emitted per second across all stakers
+/// * `period_finish` - Timestamp at which the current emission ends
+/// * `last_update` - Last time the accumulator was updated
+/// * `reward_per_token` - Accumulated reward per staked unit (scaled)
+/// * `total_staked` - Amount currently staked in the pool
+/// * `reward_reserve` - Funded rewards not paid out yet
+/// * `reward_owed` - Part of the reserve allocated to stakers and not
+///   claimed yet
+/// * `lockup_seconds` - Lockup applied from each stake
+/// * `early_exit_penalty_bps` - Penalty on unstaking before the lockup ends
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakingPool {
+    pub token_index: u32,
+    pub reward_token_index: u32,
+    pub creator: Address,
+    pub reward_rate: i128,
+    pub period_finish: u64,
+    pub last_update: u64,
+    pub reward_per_token: i128,
+    pub total_staked: i128,
+    pub reward_reserve: i128,
+    pub reward_owed: i128,
+    pub lockup_seconds: u64,
+    pub early_exit_penalty_bps: u32,
+}
+
+/// Open-ended stream paying `rate_per_second` from a sender's deposit
+///
+/// Debt owed to the recipient accrues continuously:
+/// `snapshot_debt + rate_per_second * (now - snapshot_time)`. The part of
+/// the debt covered by `balance` can be withdrawn; anything beyond it is
+/// uncovered debt that the next deposit pays off first.
+///
+/// # Fields
+/// * `balance` - Deposited funds not yet withdrawn or refunded
+/// * `snapshot_debt` - Debt accrued up to `snapshot_time`
+/// * `snapshot_time` - Last time the rate or balance changed
+/// * `withdrawn` - Total paid out to the recipient
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FlowStream {
+    pub sender: Address,
+    pub recipient: Address,
+    pub token_index: u32,
+    pub rate_per_second: i128,
+    pub balance: i128,
+    pub snapshot_debt: i128,
+    pub snapshot_time: u64,
+    pub withdrawn: i128,
+}
+
+/// Payroll pool: one funded balance streamed to many members
+///
+/// Members are paid either a fixed per-second rate or a share, by weight,
+/// of the pool's `weighted_rate`.
+///
+/// # Fields
+/// * `creator` - Funds the pool and manages its members
+/// * `token_index` - Token the pool pays in
+/// * `balance` - Funds held and not yet withdrawn or refunded
+/// * `fixed_rate_total` - Sum of the fixed member rates
+/// * `weighted_rate` - Per-second amount shared between weighted members
+/// * `total_weight` - Sum of the member weights
+/// * `reward_per_weight` - Accrued amount per weight unit (scaled)
+/// * `weight_paid_total` - Sum of each member's weight times their
+///   `reward_per_weight_paid`
+/// * `snapshot_debt` - Unwithdrawn amount owed to members at `snapshot_time`,
+///   leaving out weighted accrual members have not been settled for
+/// * `snapshot_time` - Last time the pool was settled
+/// * `member_count` - Members with a rate, a weight or an unpaid balance
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayrollPool {
+    pub creator: Address,
+    pub token_index: u32,
+    pub balance: i128,
+    pub fixed_rate_total: i128,
+    pub weighted_rate: i128,
+    pub total_weight: u32,
+    pub reward_per_weight: i128,
+    pub weight_paid_total: i128,
+    pub snapshot_debt: i128,
+    pub snapshot_time: u64,
+    pub member_count: u32,
+}
+
+/// A member's position in a payroll pool
+///
+/// # Fields
+/// * `rate_per_second` - Fixed rate (0 for a weighted member)
+/// * `weight` - Share of the pool's weighted rate (0 for a fixed-rate member)
+/// * `accrued` - Settled amount not withdrawn yet
+/// * `reward_per_weight_paid` - Pool accumulator at the last settlement
+/// * `snapshot_time` - Last time the member was settled
+/// * `withdrawn` - Total paid out to the member
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolMember {
+    pub rate_per_second: i128,
+    pub weight: u32,
+    pub accrued: i128,
+    pub reward_per_weight_paid: i128,
+    pub snapshot_time: u64,
+    pub withdrawn: i128,
+}
+
+/// Funding state of a payroll pool
+///
+/// # Fields
+/// * `balance` - Funds held by the pool
+/// * `total_debt` - Amount owed to members and not withdrawn yet
+/// * `outflow_rate` - Amount owed per second across all members
+/// * `runway_secs` - Seconds until the balance stops covering the debt
+///   (None while nothing accrues, 0 when already insolvent)
+/// * `depletion_time` - Ledger time the runway ends (None while nothing accrues)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolSolvency {
+    pub balance: i128,
+    pub total_debt: i128,
+    pub outflow_rate: i128,
+    pub runway_secs: Option<u64>,
+    pub depletion_time: Option<u64>,
+}
+
+/// A recipient's consent to claims pushed on their behalf
+///
+/// Pushed claims always pay the recipient; the keeper only pays the fee.
+///
+/// # Fields
+/// * `operator` - Address allowed to push claims (None for none)
+/// * `auto_claim` - Let any address push claims
+/// * `min_interval` - Minimum seconds between pushed claims on the same
+///   stream or vault (0 for no limit)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimDelegation {
+    pub operator: Option<Address>,
+    pub auto_claim: bool,
+    pub min_interval: u64,
+}
+
+/// A staker's position in a staking pool
+///
+/// # Fields
+/// * `amount` - Amount staked
+/// * `reward_per_token_paid` - Pool accumulator at the last update
+/// * `rewards` - Rewards accrued and not claimed yet
+/// * `locked_until` - Unstaking before this timestamp pays the early-exit penalty
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakePosition {
+    pub amount: i128,
+    pub reward_per_token_paid: i128,
+    pub rewards: i128,
+    pub locked_until: u64,
+}
+
+/// Delegated minter allowance for a token
+///
+/// # Fields
+/// * `remaining` - Amount the minter may still mint
+/// * `expires_at` - Timestamp after which the role lapses (0 = never)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MinterAllowance {
+    pub remaining: i128,
+    pub expires_at: u64,
 }
 
 /// Timelock configuration
+///
+/// # Fields
+/// * `delay_seconds` - Minimum wait between scheduling and execution
+/// * `enabled` - Whether the timelock has been initialized
+/// * `expiry_seconds` - Window after `execute_at` during which a change
+///   may still be executed; afterwards it is reported as `Expired`
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct TimelockConfig {
     pub delay_seconds: u64,
     pub enabled: bool,
+    pub expiry_seconds: u64,
+}
+
+/// Timelock configuration as stored under `DataKey::TimelockConfig` before
+/// change expiry was added
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyTimelockConfig {
+    pub delay_seconds: u64,
+    pub enabled: bool,
 }
 
 /// Governance configuration
@@ -182,6 +609,8 @@ pub struct TokenStats {
     pub is_paused: bool,
     pub clawback_enabled: bool,
     pub freeze_enabled: bool,
+    pub total_fees_collected: i128, // cumulative transfer tax collected
+    pub holder_count: u32,          // addresses with a non-zero balance
 }
 
 /// Batch fee update structure for Phase 2 optimization
@@ -208,6 +637,22 @@ pub struct FeeUpdate {
     pub metadata_fee: Option<i128>,
 }
 
+// Per-operation pause flags (bitmask stored under `DataKey::PauseFlags`)
+pub const PAUSE_CREATE: u32 = 1 << 0;
+pub const PAUSE_MINT: u32 = 1 << 1;
+pub const PAUSE_BURN: u32 = 1 << 2;
+pub const PAUSE_TRANSFER: u32 = 1 << 3;
+pub const PAUSE_STREAM_CREATE: u32 = 1 << 4;
+pub const PAUSE_STREAM_CLAIM: u32 = 1 << 5;
+pub const PAUSE_VAULT_CREATE: u32 = 1 << 6;
+pub const PAUSE_VAULT_CLAIM: u32 = 1 << 7;
+pub const PAUSE_VAULT_CANCEL: u32 = 1 << 8;
+pub const PAUSE_TREASURY_WITHDRAW: u32 = 1 << 9;
+/// Creator controls over holders: freezing and clawback settings
+pub const PAUSE_TOKEN_CONTROLS: u32 = 1 << 10;
+/// Every operation flag set; equivalent to the legacy global pause
+pub const PAUSE_ALL: u32 = (1 << 11) - 1;
+
 /// Storage keys for contract data
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -223,8 +668,11 @@ pub enum DataKey {
     TokenPaused(u32),
     TotalBurned(u32),
     TokenByAddress(Address),
-    Paused,
+    PauseFlags,
+    /// Legacy timelock configuration, superseded by
+    /// `ExtDataKey::TimelockSettings`
     TimelockConfig,
+    /// Legacy pending change, superseded by `ExtDataKey::TimelockChange`
     PendingChange(u64),
     NextChangeId,
     CreatorTokens(Address),
@@ -240,7 +688,6 @@ pub enum DataKey {
     StreamCount,
     Stream(u32),
     StreamByCreator(Address, u32),
-    TokenStreams(u32),
     TokenStreamCount(u32),
     NextStreamId,
     GovernanceConfig,
@@ -251,7 +698,108 @@ pub enum DataKey {
     OwnerVaultCount(Address),
     VaultByCreator(Address, u32),
     CreatorVaultCount(Address),
+    /// Legacy pending admin address, superseded by `ExtDataKey::AdminOffer`
     PendingAdmin,
+    TokenIndexByAddress(Address),
+    ComplianceMode(u32),
+    HolderListEntry(u32, Address),
+    TransferFee(u32),
+    TransferFeesCollected(u32),
+    Allowance(u32, Address, Address),
+    TokenMetadata(u32),
+    MetadataVersion(u32, u32),
+}
+
+/// Additional storage keys
+///
+/// `DataKey` has reached the 50-variant limit of `#[contracttype]` enums,
+/// so newer features store their data under this enum.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ExtDataKey {
+    SymbolPolicy,
+    /// Pending two-step admin handover offer
+    AdminOffer,
+    /// Legacy global pause bit, superseded by `DataKey::PauseFlags`
+    ///
+    /// Encodes the same as the `DataKey::Paused` it replaced, so contracts
+    /// paused before the upgrade can still be read.
+    Paused,
+    /// Allowance of a delegated minter `(token_index, minter)`
+    Minter(u32, Address),
+    /// Set once a token's supply is permanently finalized
+    SupplyFinalized(u32),
+    /// First token registered under a normalized symbol
+    SymbolOwner(String),
+    /// Token the admin verified as canonical for a normalized symbol
+    VerifiedSymbol(String),
+    ReservedSymbol(String),
+    AirdropCount,
+    Airdrop(u32),
+    /// Claimed bitmap word `(campaign_id, leaf_index / 64)`
+    AirdropClaimed(u32, u32),
+    HolderCount(u32),
+    /// Holder stored at a position of a token's holder index
+    HolderAt(u32, u32),
+    HolderPosition(u32, Address),
+    /// Latest snapshot id of a token (0 = none taken)
+    SnapshotId(u32),
+    BalanceCheckpointCount(u32, Address),
+    BalanceCheckpoint(u32, Address, u32),
+    SupplyCheckpointCount(u32),
+    SupplyCheckpoint(u32, u32),
+    DistributionCount,
+    Distribution(u32),
+    DistributionClaimed(u32, Address),
+    /// Amount of a token held in contract escrow rather than by holders
+    EscrowedSupply(u32),
+    StakingPoolCount,
+    StakingPool(u32),
+    StakePosition(u32, Address),
+    /// Amount of a token a holder has staked across all pools
+    StakedBalance(u32, Address),
+    /// Delay between announcing and activating clawback on a token
+    ClawbackNoticePeriod,
+    /// Timestamp from which clawback is active for a token
+    ClawbackActiveFrom(u32),
+    /// Number of clawback records for a token
+    ClawbackLogCount(u32),
+    /// Clawback record of a token by sequence number
+    ClawbackLog(u32, u32),
+    /// Number of amendments made to a stream
+    StreamAmendmentCount(u64),
+    /// Stream amendment by sequence number
+    StreamAmendment(u64, u32),
+    FlowStreamCount,
+    FlowStream(u64),
+    /// Number of streams in a recipient's stream index
+    RecipientStreamCount(Address),
+    /// Stream id stored at a position of a recipient's stream index
+    RecipientStreamAt(Address, u32),
+    /// Position of a stream in its recipient's stream index
+    RecipientStreamPosition(u64),
+    /// Number of streams created by an address
+    CreatorStreamCount(Address),
+    /// Stream id stored at a position of a creator's stream index
+    CreatorStreamAt(Address, u32),
+    /// Stream id stored at a position of a token's stream index
+    TokenStreamAt(u32, u32),
+    /// Pushed-claim consent of a recipient
+    ClaimDelegation(Address),
+    /// Ledger time of the last pushed claim on a stream
+    StreamLastPushedClaim(u64),
+    /// Ledger time of the last pushed claim on a vault
+    VaultLastPushedClaim(u64),
+    PayrollPoolCount,
+    PayrollPool(u64),
+    PoolMember(u64, Address),
+    /// Timelock delay and change expiry window
+    ///
+    /// Named apart from `DataKey::TimelockConfig`, which encodes the same
+    /// as a variant of the same name and holds the legacy shape.
+    TimelockSettings,
+    /// Timelocked change by id
+    TimelockChange(u64),
 }
 
 #[contracterror]
@@ -352,6 +900,20 @@ pub enum ChangeType {
     TreasuryUpdate,
 }
 
+/// Lifecycle status of a timelocked change
+///
+/// Changes are never removed from storage; they move from `Pending` to
+/// exactly one terminal status. `Expired` is derived on read once the
+/// ledger time passes `expires_at` and is persisted by `expire_change`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ChangeStatus {
+    Pending,
+    Executed,
+    Cancelled,
+    Expired,
+}
+
 /// Pending change awaiting timelock expiry
 ///
 /// Represents a scheduled change that cannot be executed
@@ -363,7 +925,10 @@ pub enum ChangeType {
 /// * `scheduled_by` - Admin who scheduled the change
 /// * `scheduled_at` - Timestamp when change was scheduled
 /// * `execute_at` - Timestamp when change can be executed
+/// * `expires_at` - Last timestamp at which the change can be executed
 /// * `executed` - Whether the change has been executed
+/// * `status` - Current lifecycle status
+/// * `content_hash` - sha256 of the change payload (type and new values)
 /// * `base_fee` - New base fee (for FeeUpdate)
 /// * `metadata_fee` - New metadata fee (for FeeUpdate)
 /// * `paused` - New pause state (for PauseUpdate)
@@ -376,13 +941,48 @@ pub struct PendingChange {
     pub scheduled_by: Address,
     pub scheduled_at: u64,
     pub execute_at: u64,
+    pub expires_at: u64,
     pub executed: bool,
+    pub status: ChangeStatus,
+    pub content_hash: BytesN<32>,
     pub base_fee: Option<i128>,
     pub metadata_fee: Option<i128>,
     pub paused: Option<bool>,
     pub treasury: Option<Address>,
 }
 
+/// Pending change as stored under `DataKey::PendingChange` before expiry,
+/// status and content hashing were added
+///
+/// Cancelled changes used to be removed, so a legacy change is either
+/// pending or executed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyPendingChange {
+    pub id: u64,
+    pub change_type: ChangeType,
+    pub scheduled_by: Address,
+    pub scheduled_at: u64,
+    pub execute_at: u64,
+    pub executed: bool,
+    pub base_fee: Option<i128>,
+    pub metadata_fee: Option<i128>,
+    pub paused: Option<bool>,
+    pub treasury: Option<Address>,
+}
+
+/// Paginated pending-change result
+///
+/// # Fields
+/// * `changes` - Changes in ascending id order matching the status filter
+/// * `next_cursor` - Change id to resume scanning from (None = end reached)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingChangesPage {
+    pub changes: Vec<PendingChange>,
+    pub next_cursor: Option<u64>,
+}
+
 /// Governance proposal
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -417,17 +1017,96 @@ pub struct PaginationCursor {
     pub next_index: u32,
 }
 
-/// Paginated token result
+/// Paginated stream id result
 ///
-/// Contains a page of tokens and a cursor for fetching the next page.
+/// Contains a page of stream ids and a cursor for fetching the next page.
 ///
 /// # Fields
-/// * `tokens` - Vector of token info for this page
-/// * `cursor` - Cursor for next page (None = no more results)
+/// * `stream_ids` - Stream ids for this page
+/// * `next_cursor` - Cursor for next page (None = no more results)
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct StreamPage {
-    pub token_indices: Vec<u32>,
+    pub stream_ids: Vec<u64>,
+    pub next_cursor: Option<u32>,
+}
+
+/// Lifecycle state of a stream, used to filter stream listings
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StreamStatus {
+    Active,
+    Paused,
+    Cancelled,
+    /// Everything owed to the recipient has been claimed
+    Completed,
+    /// Closed by dividing it into child streams
+    Split,
+}
+
+/// Page of streams returned by the recipient, sender and token listings
+///
+/// `next_cursor` is the index position to resume from. With a status
+/// filter a page may hold fewer than `limit` streams before the end.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamInfoPage {
+    pub streams: Vec<StreamInfo>,
+    pub next_cursor: Option<u32>,
+}
+
+/// Page of token holders returned by `get_holders`
+///
+/// Holder positions shift when an address drops to a zero balance, so a
+/// page taken while balances change may skip or repeat a holder.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HolderPage {
+    pub holders: Vec<(Address, i128)>,
+    pub next_cursor: Option<u32>,
+}
+
+/// Audit record of a clawback (admin burn from a holder)
+///
+/// `reason_code` is an off-chain reference (e.g. a court order or
+/// compliance case category); 0 is reserved and rejected.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClawbackRecord {
+    pub holder: Address,
+    pub amount: i128,
+    pub actor: Address,
+    pub reason_code: u32,
+    pub timestamp: u64,
+}
+
+/// Page of clawback records returned by `get_clawback_log`, oldest first
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClawbackPage {
+    pub records: Vec<ClawbackRecord>,
+    pub next_cursor: Option<u32>,
+}
+
+/// Change to a stream's funding or end time made by `top_up_stream`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamAmendment {
+    pub additional_amount: i128,
+    pub previous_end_time: u64,
+    pub new_end_time: u64,
+    /// Amount vested when the schedule was re-based
+    pub vested_amount: i128,
+    /// Whether the change slowed vesting and needed the recipient's consent
+    pub recipient_consented: bool,
+    pub timestamp: u64,
+}
+
+/// Page of stream amendments returned by `get_stream_amendments`, oldest first
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamAmendmentPage {
+    pub amendments: Vec<StreamAmendment>,
     pub next_cursor: Option<u32>,
 }
 
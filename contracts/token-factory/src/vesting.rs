@@ -1,5 +1,16 @@
 use soroban_sdk::contracterror;
 
+use crate::types::VestingCurve;
+
+/// Highest exponent accepted by `VestingCurve::Exponential`
+pub const MAX_EXPONENT: u32 = 4;
+
+/// Most breakpoints accepted by `VestingCurve::Piecewise`
+pub const MAX_BREAKPOINTS: u32 = 16;
+
+const BPS_DENOMINATOR: u32 = 10_000;
+const SECONDS_PER_DAY: u64 = 86_400;
+
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum VestingError {  
@@ -66,10 +77,219 @@ pub fn vested_amount(
 }
 
 
+/// Check that `curve` is well formed for a schedule from `start_timestamp`
+/// to `end_timestamp`.
+///
+/// - `Stepped` needs at least one step
+/// - `Exponential` takes exponents 1 to `MAX_EXPONENT`
+/// - `Piecewise` takes up to `MAX_BREAKPOINTS` breakpoints with strictly
+///   increasing timestamps inside the schedule and non-decreasing
+///   cumulative bps of at most 10 000
+pub fn validate_curve(
+    curve: &VestingCurve,
+    start_timestamp: u64,
+    end_timestamp: u64,
+) -> Result<(), VestingError> {
+    match curve {
+        VestingCurve::Linear | VestingCurve::Monthly => Ok(()),
+        VestingCurve::Stepped(steps) => {
+            if *steps == 0 {
+                return Err(VestingError::InvalidSchedule);
+            }
+            Ok(())
+        }
+        VestingCurve::Exponential(exponent) => {
+            if *exponent == 0 || *exponent > MAX_EXPONENT {
+                return Err(VestingError::InvalidSchedule);
+            }
+            Ok(())
+        }
+        VestingCurve::Piecewise(points) => {
+            if points.len() > MAX_BREAKPOINTS {
+                return Err(VestingError::InvalidSchedule);
+            }
+            let (mut prev_time, mut prev_bps) = (start_timestamp, 0);
+            for (time, bps) in points.iter() {
+                if time <= prev_time || time >= end_timestamp {
+                    return Err(VestingError::InvalidSchedule);
+                }
+                if bps < prev_bps || bps > BPS_DENOMINATOR {
+                    return Err(VestingError::InvalidSchedule);
+                }
+                prev_time = time;
+                prev_bps = bps;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Compute the vested amount for a grant following `curve`.
+///
+/// Takes the same parameters and has the same guarantees as
+/// [`vested_amount`]: 0 up to `start_timestamp`, `total_grant` from
+/// `end_timestamp`, and never decreasing in between. `curve` is expected
+/// to have passed [`validate_curve`] for the same schedule.
+pub fn curve_vested_amount(
+    total_grant: i128,
+    start_timestamp: u64,
+    end_timestamp: u64,
+    query_timestamp: u64,
+    curve: &VestingCurve,
+) -> Result<i128, VestingError> {
+    if total_grant < 0 {
+        return Err(VestingError::InvalidGrant);
+    }
+
+    if end_timestamp <= start_timestamp {
+        return Err(VestingError::InvalidSchedule);
+    }
+
+    if query_timestamp <= start_timestamp {
+        return Ok(0);
+    }
+
+    if query_timestamp >= end_timestamp {
+        return Ok(total_grant);
+    }
+
+    // Every curve but the exponential one vests total * numerator / denominator
+    let (numerator, denominator) = match curve {
+        VestingCurve::Linear => {
+            return vested_amount(total_grant, start_timestamp, end_timestamp, query_timestamp)
+        }
+        VestingCurve::Stepped(steps) => {
+            let duration = (end_timestamp - start_timestamp) as u128;
+            let elapsed = (query_timestamp - start_timestamp) as u128;
+            let steps = *steps as u128;
+            if steps == 0 {
+                return Err(VestingError::InvalidSchedule);
+            }
+            (elapsed * steps / duration, steps)
+        }
+        VestingCurve::Monthly => {
+            let months = months_elapsed(start_timestamp, end_timestamp);
+            // A final partial month unlocks at end_timestamp
+            let total_months = if add_months(start_timestamp, months) == Some(end_timestamp) {
+                months
+            } else {
+                months + 1
+            };
+            (
+                months_elapsed(start_timestamp, query_timestamp) as u128,
+                total_months as u128,
+            )
+        }
+        VestingCurve::Exponential(exponent) => {
+            let duration = (end_timestamp - start_timestamp) as u128;
+            let elapsed = (query_timestamp - start_timestamp) as u128;
+            // Apply the fraction once per power, rounding down each time
+            let mut vested = total_grant as u128;
+            for _ in 0..*exponent {
+                vested = vested
+                    .checked_mul(elapsed)
+                    .ok_or(VestingError::Overflow)?
+                    / duration;
+            }
+            return Ok(vested as i128);
+        }
+        VestingCurve::Piecewise(points) => {
+            let (mut prev_time, mut prev_bps) = (start_timestamp, 0u32);
+            let mut segment = (end_timestamp, BPS_DENOMINATOR);
+            for (time, bps) in points.iter() {
+                if query_timestamp < time {
+                    segment = (time, bps);
+                    break;
+                }
+                prev_time = time;
+                prev_bps = bps;
+            }
+            let (next_time, next_bps) = segment;
+            if next_bps < prev_bps || next_time <= prev_time {
+                return Err(VestingError::InvalidSchedule);
+            }
+
+            // bps reached at the query time, scaled by the segment length
+            let span = (next_time - prev_time) as u128;
+            let scaled_bps = prev_bps as u128 * span
+                + (next_bps - prev_bps) as u128 * (query_timestamp - prev_time) as u128;
+            (scaled_bps, BPS_DENOMINATOR as u128 * span)
+        }
+    };
+
+    let vested = (total_grant as u128)
+        .checked_mul(numerator)
+        .ok_or(VestingError::Overflow)?
+        / denominator;
+    Ok(vested as i128)
+}
+
+/// Number of monthly anniversaries of `start` in `(start, timestamp]`
+fn months_elapsed(start: u64, timestamp: u64) -> u64 {
+    if timestamp <= start {
+        return 0;
+    }
+    let (start_year, start_month, _) = civil_from_days(start / SECONDS_PER_DAY);
+    let (year, month, _) = civil_from_days(timestamp / SECONDS_PER_DAY);
+    let mut months = (year * 12 + month as u64) - (start_year * 12 + start_month as u64);
+    while months > 0 && add_months(start, months).is_none_or(|at| at > timestamp) {
+        months -= 1;
+    }
+    months
+}
+
+/// `timestamp` moved forward by `months` calendar months, keeping the time
+/// of day and clamping the day to the length of the target month
+fn add_months(timestamp: u64, months: u64) -> Option<u64> {
+    let (year, month, day) = civil_from_days(timestamp / SECONDS_PER_DAY);
+    let month_index = (year * 12 + (month as u64 - 1)).checked_add(months)?;
+    let (year, month) = (month_index / 12, (month_index % 12) as u32 + 1);
+    let day = day.min(days_in_month(year, month));
+    days_from_civil(year, month, day)
+        .checked_mul(SECONDS_PER_DAY)?
+        .checked_add(timestamp % SECONDS_PER_DAY)
+}
+
+fn days_in_month(year: u64, month: u32) -> u32 {
+    let leap = year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400));
+    match month {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Civil date `(year, month, day)` of a day count since 1970-01-01
+fn civil_from_days(days: u64) -> (u64, u32, u32) {
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + u64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Day count since 1970-01-01 of a civil date (year 1970 or later)
+fn days_from_civil(year: u64, month: u32, day: u32) -> u64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year / 400;
+    let yoe = year - era * 400;
+    let mp = if month > 2 { month - 3 } else { month + 9 } as u64;
+    let doy = (153 * mp + 2) / 5 + day as u64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use proptest::prelude::*;
+    use soroban_sdk::{vec, Env};
 
     const GRANT: i128 = 1_000_000_000_000; // 1 trillion units
     const START: u64 = 1_000_000_000;      // some epoch
@@ -154,6 +374,93 @@ mod tests {
         );
     }
 
+    // ── Vesting curves ──────────────────────────────────────────────────────
+
+    #[test]
+    fn stepped_unlocks_in_equal_steps() {
+        let curve = VestingCurve::Stepped(4);
+        assert_eq!(curve_vested_amount(1_000, 100, 500, 199, &curve).unwrap(), 0);
+        assert_eq!(curve_vested_amount(1_000, 100, 500, 200, &curve).unwrap(), 250);
+        assert_eq!(curve_vested_amount(1_000, 100, 500, 499, &curve).unwrap(), 750);
+        assert_eq!(curve_vested_amount(1_000, 100, 500, 500, &curve).unwrap(), 1_000);
+    }
+
+    #[test]
+    fn monthly_follows_calendar_months() {
+        // 2024-01-31 to 2024-04-30: anniversaries clamp to Feb 29 and Apr 30
+        let (jan_31, feb_29) = (1_706_659_200, 1_709_164_800);
+        let (mar_31, apr_30) = (1_711_843_200, 1_714_435_200);
+        let curve = VestingCurve::Monthly;
+        let vested = |at| curve_vested_amount(300, jan_31, apr_30, at, &curve).unwrap();
+        assert_eq!(vested(feb_29 - 1), 0);
+        assert_eq!(vested(feb_29), 100);
+        assert_eq!(vested(mar_31 - 1), 100);
+        assert_eq!(vested(mar_31), 200);
+        assert_eq!(vested(apr_30 - 1), 200);
+        assert_eq!(vested(apr_30), 300);
+
+        // A trailing partial month unlocks at the end
+        assert_eq!(curve_vested_amount(300, jan_31, mar_31 + 3_600, mar_31, &curve).unwrap(), 200);
+    }
+
+    #[test]
+    fn civil_date_round_trip() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+        assert_eq!(days_from_civil(2024, 2, 29), 19_782);
+        for days in (0..200_000).step_by(37) {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+    }
+
+    #[test]
+    fn exponential_back_loads_vesting() {
+        let quadratic = VestingCurve::Exponential(2);
+        assert_eq!(curve_vested_amount(10_000, 0, 100, 50, &quadratic).unwrap(), 2_500);
+        let cubic = VestingCurve::Exponential(3);
+        assert_eq!(curve_vested_amount(10_000, 0, 100, 50, &cubic).unwrap(), 1_250);
+    }
+
+    #[test]
+    fn piecewise_interpolates_between_breakpoints() {
+        let env = Env::default();
+        let curve = VestingCurve::Piecewise(vec![&env, (150, 5_000)]);
+        assert_eq!(validate_curve(&curve, 100, 300), Ok(()));
+        assert_eq!(curve_vested_amount(1_000, 100, 300, 125, &curve).unwrap(), 250);
+        assert_eq!(curve_vested_amount(1_000, 100, 300, 150, &curve).unwrap(), 500);
+        assert_eq!(curve_vested_amount(1_000, 100, 300, 225, &curve).unwrap(), 750);
+    }
+
+    #[test]
+    fn malformed_curves_are_rejected() {
+        let env = Env::default();
+        let invalid = [
+            VestingCurve::Stepped(0),
+            VestingCurve::Exponential(0),
+            VestingCurve::Exponential(MAX_EXPONENT + 1),
+            // Breakpoints must be strictly inside the schedule
+            VestingCurve::Piecewise(vec![&env, (100, 10)]),
+            VestingCurve::Piecewise(vec![&env, (300, 10)]),
+            VestingCurve::Piecewise(vec![&env, (150, 10), (150, 20)]),
+            // Cumulative bps may not fall or exceed 10 000
+            VestingCurve::Piecewise(vec![&env, (150, 20), (200, 10)]),
+            VestingCurve::Piecewise(vec![&env, (150, 10_001)]),
+        ];
+        for curve in invalid.iter() {
+            assert_eq!(validate_curve(curve, 100, 300), Err(VestingError::InvalidSchedule));
+        }
+
+        let mut points = soroban_sdk::Vec::new(&env);
+        for i in 0..=MAX_BREAKPOINTS {
+            points.push_back((101 + i as u64, 0));
+        }
+        assert_eq!(
+            validate_curve(&VestingCurve::Piecewise(points), 100, 300),
+            Err(VestingError::InvalidSchedule)
+        );
+    }
+
     // ── Property tests ──────────────────────────────────────────────────────
 
     proptest! {
@@ -228,5 +535,52 @@ mod tests {
                 "linearity failed: v={v}, expected={expected}, k={k}, grant={grant}, duration={duration}"
             );
         }
+
+        /// PROPERTY: every curve is bounded, monotonic and exact at both ends
+        #[test]
+        fn prop_curves_bounded_monotonic_exact(
+            grant in 0i128..=i64::MAX as i128,
+            start in 0u64..=2_000_000_000u64,
+            kind in 0u8..5,
+            param in 1u32..=48,
+            segments in proptest::collection::vec((1u64..=2_592_000, 0u32..=2_500), 0..=16),
+            tail in 1u64..=2_592_000,
+            offset_a in any::<u64>(),
+            offset_b in any::<u64>(),
+        ) {
+            let env = Env::default();
+
+            // Piecewise breakpoints are laid out by the segments; the
+            // schedule ends `tail` seconds after the last one
+            let mut points = soroban_sdk::Vec::new(&env);
+            let (mut time, mut bps) = (start, 0u32);
+            for (dt, dbps) in segments.iter() {
+                time += dt;
+                bps = (bps + dbps).min(10_000);
+                points.push_back((time, bps));
+            }
+            let end = time + tail;
+
+            let curve = match kind {
+                0 => VestingCurve::Linear,
+                1 => VestingCurve::Stepped(param),
+                2 => VestingCurve::Monthly,
+                3 => VestingCurve::Exponential(1 + param % MAX_EXPONENT),
+                _ => VestingCurve::Piecewise(points),
+            };
+            prop_assert_eq!(validate_curve(&curve, start, end), Ok(()));
+
+            let vested = |at: u64| curve_vested_amount(grant, start, end, at, &curve).unwrap();
+            prop_assert_eq!(vested(start), 0);
+            prop_assert_eq!(vested(end), grant);
+            prop_assert_eq!(vested(end + tail), grant);
+
+            let span = end - start + tail;
+            let (a, b) = (start + offset_a % span, start + offset_b % span);
+            let (t1, t2) = if a <= b { (a, b) } else { (b, a) };
+            let (v1, v2) = (vested(t1), vested(t2));
+            prop_assert!(v1 >= 0 && v2 <= grant, "out of bounds: {v1}, {v2}");
+            prop_assert!(v1 <= v2, "monotonicity violated: v({t1})={v1} > v({t2})={v2}");
+        }
     }
-}
\ No newline at end of file
+}